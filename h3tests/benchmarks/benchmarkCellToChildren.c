@@ -28,11 +28,11 @@ if (cellToChildrenSize(hex, 14, &outSz)) {
 }
 H3Index *out = calloc(outSz, sizeof(H3Index));
 
-BENCHMARK(cellToChildren1, 10000, { cellToChildren(hex, 10, out); });
-BENCHMARK(cellToChildren2, 10000, { cellToChildren(hex, 11, out); });
-BENCHMARK(cellToChildren3, 10000, { cellToChildren(hex, 12, out); });
-BENCHMARK(cellToChildren4, 10000, { cellToChildren(hex, 13, out); });
-BENCHMARK(cellToChildren5, 10000, { cellToChildren(hex, 14, out); });
+BENCHMARK(cellToChildren1, 10000, { cellToChildren(hex, 10, out, outSz); });
+BENCHMARK(cellToChildren2, 10000, { cellToChildren(hex, 11, out, outSz); });
+BENCHMARK(cellToChildren3, 10000, { cellToChildren(hex, 12, out, outSz); });
+BENCHMARK(cellToChildren4, 10000, { cellToChildren(hex, 13, out, outSz); });
+BENCHMARK(cellToChildren5, 10000, { cellToChildren(hex, 14, out, outSz); });
 
 free(out);
 
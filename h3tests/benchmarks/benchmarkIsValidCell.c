@@ -35,7 +35,7 @@ CellArray pentagonSetup(int parentRes, int childRes, int nullEvery) {
 
     cellToChildrenSize(p, childRes, &(ca.N));
     ca.cells = calloc(ca.N, sizeof(H3Index));
-    cellToChildren(p, childRes, ca.cells);
+    cellToChildren(p, childRes, ca.cells, ca.N);
 
     if (nullEvery > 0) {
         // Note: `isValidCell` should return False on H3_NULL values.
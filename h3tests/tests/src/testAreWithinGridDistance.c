@@ -0,0 +1,53 @@
+/** @file
+ * @brief tests H3 function `areWithinGridDistance`
+ *
+ *  usage: `testAreWithinGridDistance`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(areWithinGridDistance) {
+    H3Index sf = 0x89283080ddbffff;
+
+    TEST(neighbors) {
+        H3Index ring[6];
+        t_assertSuccess(gridRingUnsafe(sf, 1, ring));
+        int within = -1;
+        t_assertSuccess(areWithinGridDistance(sf, ring[0], 1, &within));
+        t_assert(within == 1, "neighbor is within 1");
+        t_assertSuccess(areWithinGridDistance(sf, ring[0], 0, &within));
+        t_assert(within == 0, "neighbor is not within 0");
+        t_assertSuccess(areWithinGridDistance(sf, sf, 0, &within));
+        t_assert(within == 1, "cell is within 0 of itself");
+    }
+
+    TEST(withinRange) {
+        H3Index ring[18];
+        t_assertSuccess(gridRingUnsafe(sf, 3, ring));
+        int within = -1;
+        t_assertSuccess(areWithinGridDistance(sf, ring[5], 3, &within));
+        t_assert(within == 1, "ring 3 cell is within 3");
+        t_assertSuccess(areWithinGridDistance(sf, ring[5], 2, &within));
+        t_assert(within == 0, "ring 3 cell is not within 2");
+    }
+
+    TEST(farPair) {
+        // Cells on opposite sides of the world.
+        LatLng other = {-0.659966917655, 1.0};
+        H3Index far;
+        t_assertSuccess(latLngToCell(&other, 9, &far));
+        int within = -1;
+        t_assertSuccess(areWithinGridDistance(sf, far, 100, &within));
+        t_assert(within == 0, "far cell is out of reach");
+    }
+
+    TEST(invalidCell) {
+        int within;
+        t_assert(areWithinGridDistance(sf, 0x7fffffffffffffff, 1, &within) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
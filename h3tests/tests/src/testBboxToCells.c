@@ -0,0 +1,102 @@
+/** @file
+ * @brief tests H3 functions `bboxToCells` and `maxBboxToCellsSize`
+ *
+ *  usage: `testBboxToCells`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int64_t countCellsInBbox(double north, double south, double east,
+                                double west, int res) {
+    int64_t size;
+    t_assertSuccess(
+        maxBboxToCellsSize(north, south, east, west, res, 0, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    t_assertSuccess(bboxToCells(north, south, east, west, res, 0, cells));
+
+    int64_t count = 0;
+    for (int64_t i = 0; i < size; i++) {
+        if (cells[i] == H3_NULL) {
+            continue;
+        }
+        count++;
+
+        LatLng center;
+        t_assertSuccess(cellToLatLng(cells[i], &center));
+        double lat = radsToDegs(center.lat);
+        double lng = radsToDegs(center.lng);
+        t_assert(lat >= south && lat <= north, "center in latitude range");
+        if (east >= west) {
+            t_assert(lng >= west && lng <= east, "center in longitude range");
+        } else {
+            t_assert(lng >= west || lng <= east,
+                     "center in transmeridian longitude range");
+        }
+    }
+    free(cells);
+    return count;
+}
+
+SUITE(bboxToCells) {
+    TEST(smallBbox) {
+        // Around San Francisco.
+        int64_t count = countCellsInBbox(37.8, 37.7, -122.4, -122.5, 7);
+        t_assert(count > 0, "found cells in the bbox");
+    }
+
+    TEST(transmeridianBbox) {
+        int64_t count = countCellsInBbox(1, -1, -179, 179, 5);
+        t_assert(count > 0, "found cells in the bbox");
+
+        // Same box, but on the other side of the world.
+        int64_t other = countCellsInBbox(1, -1, 1, -1, 5);
+        t_assert(llabs(count - other) < other / 10,
+                 "similar count than a box of the same size");
+    }
+
+    TEST(wideBbox) {
+        // 340 degrees wide, not crossing the antimeridian.
+        int64_t wide = countCellsInBbox(1, -1, 170, -170, 4);
+        // The 20 degrees left out, across the antimeridian.
+        int64_t strip = countCellsInBbox(1, -1, -170, 170, 4);
+        t_assert(strip > 0, "found cells in the strip");
+        t_assert(wide > 10 * strip, "wide box isn't read as the strip");
+    }
+
+    TEST(wideTransmeridianBbox) {
+        // 340 degrees wide, crossing the antimeridian.
+        int64_t wide = countCellsInBbox(1, -1, -10, 10, 4);
+        // The 20 degrees left out, around the prime meridian.
+        int64_t strip = countCellsInBbox(1, -1, 10, -10, 4);
+        t_assert(strip > 0, "found cells in the strip");
+        t_assert(wide > 10 * strip, "wide box isn't read as the strip");
+    }
+
+    TEST(invalidArgs) {
+        int64_t size;
+        t_assert(maxBboxToCellsSize(91, 0, 1, 0, 5, 0, &size) ==
+                     E_LATLNG_DOMAIN,
+                 "invalid latitude is rejected");
+        t_assert(maxBboxToCellsSize(1, 0, 181, 0, 5, 0, &size) ==
+                     E_LATLNG_DOMAIN,
+                 "invalid longitude is rejected");
+        t_assert(maxBboxToCellsSize(NAN, 0, 1, 0, 5, 0, &size) ==
+                     E_LATLNG_DOMAIN,
+                 "NaN is rejected");
+        t_assert(maxBboxToCellsSize(0, 1, 1, 0, 5, 0, &size) == E_DOMAIN,
+                 "north below south is rejected");
+        t_assert(maxBboxToCellsSize(1, 0, 1, 0, 16, 0, &size) == E_RES_DOMAIN,
+                 "invalid resolution is rejected");
+        t_assert(maxBboxToCellsSize(1, 0, 1, 0, 5, 1, &size) ==
+                     E_OPTION_INVALID,
+                 "invalid flags are rejected");
+        t_assert(bboxToCells(1, 0, 1, 0, 5, 1, NULL) == E_OPTION_INVALID,
+                 "invalid flags are rejected");
+        t_assert(bboxToCells(0, 1, 1, 0, 5, 0, NULL) == E_DOMAIN,
+                 "north below south is rejected");
+    }
+}
@@ -0,0 +1,74 @@
+/** @file
+ * @brief tests H3 function `binPointsToCells`
+ *
+ *  usage: `testBinPointsToCells`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(binPointsToCells) {
+    TEST(cluster) {
+        // A tight cluster in San Francisco, and a few scattered points.
+        LatLng points[] = {{37.7749, -122.4194},  {37.77491, -122.41941},
+                           {37.77489, -122.41939}, {37.7749, -122.41942},
+                           {37.77492, -122.4194},  {48.8566, 2.3522},
+                           {-33.8688, 151.2093},  {35.6762, 139.6503}};
+        int n = sizeof(points) / sizeof(points[0]);
+        H3Index cells[8];
+        int64_t counts[8];
+        int64_t numBins = 0;
+        t_assertSuccess(
+            binPointsToCells(points, n, 9, cells, counts, 8, &numBins));
+        t_assert(numBins == 4, "one bin per distinct cell");
+
+        H3Index cluster;
+        LatLng center;
+        latLngSetDegs(&center, points[0].lat, points[0].lng);
+        t_assertSuccess(latLngToCell(&center, 9, &cluster));
+        t_assert(cells[0] == cluster, "bins in order of first appearance");
+        t_assert(counts[0] == 5, "dominant bin");
+
+        int64_t total = 0;
+        for (int64_t i = 0; i < numBins; i++) {
+            total += counts[i];
+            if (i > 0) {
+                t_assert(counts[i] == 1, "scattered points");
+            }
+        }
+        t_assert(total == n, "every point is binned");
+    }
+
+    TEST(invalidPointsSkipped) {
+        LatLng points[] = {{10, 20}, {NAN, 0}, {95, 0}, {10, 20}};
+        H3Index cells[4];
+        int64_t counts[4];
+        int64_t numBins = 0;
+        t_assertSuccess(
+            binPointsToCells(points, 4, 5, cells, counts, 4, &numBins));
+        t_assert(numBins == 1, "invalid points are skipped");
+        t_assert(counts[0] == 2, "valid points are counted");
+    }
+
+    TEST(errors) {
+        LatLng points[] = {{10, 20}, {-10, -20}};
+        H3Index cells[2];
+        int64_t counts[2];
+        int64_t numBins = 0;
+        t_assert(binPointsToCells(points, 2, 5, cells, counts, 1,
+                                  &numBins) == E_MEMORY_BOUNDS,
+                 "too many bins");
+        t_assert(binPointsToCells(points, -1, 5, cells, counts, 2,
+                                  &numBins) == E_DOMAIN,
+                 "negative size");
+        t_assert(binPointsToCells(points, 2, 16, cells, counts, 2,
+                                  &numBins) == E_RES_DOMAIN,
+                 "invalid resolution");
+        t_assertSuccess(
+            binPointsToCells(NULL, 0, 5, NULL, NULL, 0, &numBins));
+        t_assert(numBins == 0, "no point, no bin");
+    }
+}
@@ -0,0 +1,72 @@
+/** @file
+ * @brief tests H3 function `canonicalVertex`
+ *
+ *  usage: `testCanonicalVertex`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+/** Builds the (possibly non-canonical) vertex `num` as seen from `cell`. */
+static H3Index rawVertex(H3Index cell, int num) {
+    H3Index vertex = cell & ~(0xFULL << 59) & ~(0x7ULL << 56);
+    return vertex | (4ULL << 59) | ((H3Index)num << 56);
+}
+
+static void canonicalVertex_assertions(H3Index cell) {
+    int numVerts = isPentagon(cell) ? 5 : 6;
+    for (int i = 0; i < numVerts; i++) {
+        H3Index expected, canonical;
+        t_assertSuccess(cellToVertex(cell, i, &expected));
+        t_assertSuccess(canonicalVertex(rawVertex(cell, i), &canonical));
+        t_assert(canonical == expected, "cell version is canonicalized");
+
+        // The owner version of the vertex is already canonical.
+        t_assertSuccess(canonicalVertex(expected, &canonical));
+        t_assert(canonical == expected, "canonical vertex is unchanged");
+    }
+}
+
+SUITE(canonicalVertex) {
+    TEST(sharedVertex) {
+        H3Index a = 0x8928308280fffff;
+        H3Index b = 0x8928308280bffff;
+        int shared = 0;
+        for (int i = 0; i < 6; i++) {
+            for (int j = 0; j < 6; j++) {
+                H3Index va, vb;
+                t_assertSuccess(cellToVertex(a, i, &va));
+                t_assertSuccess(cellToVertex(b, j, &vb));
+                if (va != vb) {
+                    continue;
+                }
+                shared++;
+                H3Index ca, cb;
+                t_assertSuccess(canonicalVertex(rawVertex(a, i), &ca));
+                t_assertSuccess(canonicalVertex(rawVertex(b, j), &cb));
+                t_assert(ca == cb, "shared vertex canonicalizes the same");
+                t_assert(ca == va, "canonical vertex matches cellToVertex");
+            }
+        }
+        t_assert(shared == 2, "neighbors share two vertexes");
+    }
+
+    TEST(allCells) {
+        iterateAllIndexesAtRes(0, canonicalVertex_assertions);
+        iterateAllIndexesAtRes(1, canonicalVertex_assertions);
+    }
+
+    TEST(invalidVertex) {
+        H3Index out;
+        t_assert(canonicalVertex(0x8928308280fffff, &out) == E_VERTEX_INVALID,
+                 "cell is not a vertex");
+        t_assert(canonicalVertex(H3_NULL, &out) == E_VERTEX_INVALID,
+                 "null is not a vertex");
+        t_assert(canonicalVertex(rawVertex(0x8009fffffffffff, 5), &out) ==
+                     E_VERTEX_INVALID,
+                 "pentagon has no vertex 5");
+    }
+}
@@ -0,0 +1,75 @@
+/** @file
+ * @brief tests H3 function `cellAdjacencyType`
+ *
+ *  usage: `testCellAdjacencyType`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+/** Checks every pair of cells in the disk against areNeighborCells. */
+static void assertDisk(H3Index origin) {
+    H3Index disk[19] = {0};
+    t_assertSuccess(gridDisk(origin, 2, disk));
+    for (int i = 0; i < 19; i++) {
+        for (int j = 0; j < 19; j++) {
+            if (disk[i] == H3_NULL || disk[j] == H3_NULL) {
+                continue;
+            }
+            int type = -1;
+            int isNeighbor = -1;
+            t_assertSuccess(cellAdjacencyType(disk[i], disk[j], &type));
+            t_assertSuccess(areNeighborCells(disk[i], disk[j], &isNeighbor));
+            t_assert(type == isNeighbor, "edge adjacency is neighborhood");
+        }
+    }
+}
+
+SUITE(cellAdjacencyType) {
+    TEST(edgeAdjacent) {
+        int type = -1;
+        t_assertSuccess(
+            cellAdjacencyType(0x8928308280fffff, 0x8928308280bffff, &type));
+        t_assert(type == 1, "neighbors share an edge");
+    }
+
+    TEST(aroundVertex) {
+        // Cells sharing a vertex all share an edge with each other: there is
+        // no vertex-only adjacency within a resolution.
+        H3Index cells[] = {0x8928308280fffff, 0x8009fffffffffff};
+        for (int c = 0; c < 2; c++) {
+            H3Index vertex, owner;
+            t_assertSuccess(cellToVertex(cells[c], 0, &vertex));
+            t_assertSuccess(vertexToOwnerCell(vertex, &owner));
+            if (owner != cells[c]) {
+                int type = -1;
+                t_assertSuccess(cellAdjacencyType(cells[c], owner, &type));
+                t_assert(type == 1, "vertex owner shares an edge");
+            }
+            assertDisk(cells[c]);
+        }
+    }
+
+    TEST(distant) {
+        H3Index ring[18] = {0};
+        t_assertSuccess(gridRingUnsafe(0x8928308280fffff, 3, ring));
+        int type = -1;
+        t_assertSuccess(cellAdjacencyType(0x8928308280fffff, ring[0], &type));
+        t_assert(type == 0, "distant cells aren't adjacent");
+        t_assertSuccess(
+            cellAdjacencyType(0x8928308280fffff, 0x8928308280fffff, &type));
+        t_assert(type == 0, "a cell isn't adjacent to itself");
+    }
+
+    TEST(invalid) {
+        int type;
+        t_assert(cellAdjacencyType(0x8928308280fffff, 0x85283473fffffff,
+                                   &type) == E_RES_MISMATCH,
+                 "resolution mismatch");
+        t_assert(cellAdjacencyType(0, 0x8928308280fffff, &type) ==
+                     E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
@@ -0,0 +1,46 @@
+/** @file
+ * @brief tests H3 function `cellAntipode`
+ *
+ *  usage: `testCellAntipode`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellAntipode) {
+    TEST(twiceIsNearOriginal) {
+        H3Index cells[] = {0x85283473fffffff, 0x8009fffffffffff,
+                           0x89283082803ffff, 0x8f2830828052d25};
+        for (size_t i = 0; i < sizeof(cells) / sizeof(cells[0]); i++) {
+            H3Index antipode = 0;
+            H3Index back = 0;
+            int64_t distance = -1;
+            t_assertSuccess(cellAntipode(cells[i], &antipode));
+            t_assert(getResolution(antipode) == getResolution(cells[i]),
+                     "same resolution");
+            t_assertSuccess(cellAntipode(antipode, &back));
+            t_assertSuccess(gridDistance(cells[i], back, &distance));
+            t_assert(distance <= 2, "back within two rings of the original");
+        }
+    }
+
+    TEST(oppositeSide) {
+        H3Index antipode = 0;
+        LatLng a = {0};
+        LatLng b = {0};
+        t_assertSuccess(cellAntipode(0x85283473fffffff, &antipode));
+        t_assertSuccess(cellToLatLng(0x85283473fffffff, &a));
+        t_assertSuccess(cellToLatLng(antipode, &b));
+        t_assert(greatCircleDistanceRads(&a, &b) > M_PI - 0.01,
+                 "antipode is on the other side of the globe");
+    }
+
+    TEST(invalid) {
+        H3Index antipode = 0;
+        t_assert(cellAntipode(0, &antipode) == E_CELL_INVALID,
+                 "invalid cell fails");
+    }
+}
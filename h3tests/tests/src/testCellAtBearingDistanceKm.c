@@ -0,0 +1,54 @@
+/** @file
+ * @brief tests H3 function `cellAtBearingDistanceKm`
+ *
+ *  usage: `testCellAtBearingDistanceKm`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define START 0x872830828ffffff
+
+SUITE(cellAtBearingDistanceKm) {
+    TEST(zeroDistance) {
+        H3Index out = 0;
+        H3Index centerChild = 0;
+        t_assertSuccess(cellAtBearingDistanceKm(START, 42, 0, 7, &out));
+        t_assert(out == START, "zero distance returns the start cell");
+        t_assertSuccess(cellAtBearingDistanceKm(START, 42, 0, 9, &out));
+        t_assertSuccess(cellToCenterChild(START, 9, &centerChild));
+        t_assert(out == centerChild, "finer res returns the center child");
+    }
+
+    TEST(projection) {
+        H3Index out = 0;
+        LatLng from = {0};
+        LatLng to = {0};
+        double bearing = -1;
+        t_assertSuccess(cellAtBearingDistanceKm(START, 90, 100, 7, &out));
+        t_assertSuccess(cellToLatLng(START, &from));
+        t_assertSuccess(cellToLatLng(out, &to));
+        t_assert(fabs(greatCircleDistanceKm(&from, &to) - 100) < 3,
+                 "lands about 100km away");
+        t_assertSuccess(cellToCellBearingDegs(START, out, &bearing));
+        t_assert(fabs(bearing - 90) < 2, "lands to the east");
+    }
+
+    TEST(invalid) {
+        H3Index out = 0;
+        t_assert(cellAtBearingDistanceKm(0, 0, 1, 7, &out) ==
+                     E_CELL_INVALID,
+                 "invalid start fails");
+        t_assert(cellAtBearingDistanceKm(START, 0, 1, 16, &out) ==
+                     E_RES_DOMAIN,
+                 "invalid resolution fails");
+        t_assert(cellAtBearingDistanceKm(START, 0, -1, 7, &out) == E_DOMAIN,
+                 "negative distance fails");
+        t_assert(cellAtBearingDistanceKm(START, NAN, 1, 7, &out) ==
+                     E_DOMAIN,
+                 "non-finite bearing fails");
+    }
+}
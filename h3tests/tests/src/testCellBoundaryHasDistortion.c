@@ -0,0 +1,50 @@
+/** @file
+ * @brief tests H3 function `cellBoundaryHasDistortion`
+ *
+ *  usage: `testCellBoundaryHasDistortion`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static int distortedCount = 0;
+
+static void cellBoundaryHasDistortion_assertions(H3Index cell) {
+    CellBoundary boundary;
+    int hasDistortion = -1;
+    t_assertSuccess(cellToBoundary(cell, &boundary));
+    t_assertSuccess(cellBoundaryHasDistortion(cell, &hasDistortion));
+
+    int base = isPentagon(cell) ? 5 : 6;
+    t_assert(hasDistortion == (boundary.numVerts > base),
+             "flag matches the number of vertices");
+    if (!isResClassIII(cell)) {
+        t_assert(!hasDistortion, "no distortion on Class II");
+    }
+    distortedCount += hasDistortion;
+}
+
+SUITE(cellBoundaryHasDistortion) {
+    TEST(classIII) {
+        distortedCount = 0;
+        iterateAllIndexesAtRes(1, cellBoundaryHasDistortion_assertions);
+        t_assert(distortedCount > 0, "some Class III cells are distorted");
+    }
+
+    TEST(classII) {
+        distortedCount = 0;
+        iterateAllIndexesAtRes(0, cellBoundaryHasDistortion_assertions);
+        iterateAllIndexesAtRes(2, cellBoundaryHasDistortion_assertions);
+        t_assert(distortedCount == 0, "no Class II cell is distorted");
+    }
+
+    TEST(invalidCell) {
+        int hasDistortion;
+        t_assert(cellBoundaryHasDistortion(0, &hasDistortion) ==
+                     E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
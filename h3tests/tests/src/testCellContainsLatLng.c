@@ -0,0 +1,53 @@
+/** @file
+ * @brief tests H3 function `cellContainsLatLng`
+ *
+ *  usage: `testCellContainsLatLng`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellContainsLatLng) {
+    // San Francisco, in degrees.
+    LatLng sf = {37.7749, -122.4194};
+    H3Index sfCell;
+    LatLng sfRads = {degsToRads(sf.lat), degsToRads(sf.lng)};
+    t_assertSuccess(latLngToCell(&sfRads, 9, &sfCell));
+
+    TEST(inside) {
+        int contained = -1;
+        t_assertSuccess(cellContainsLatLng(sfCell, &sf, &contained));
+        t_assert(contained == 1, "point is in its cell");
+    }
+
+    TEST(neighbor) {
+        H3Index disk[7] = {0};
+        t_assertSuccess(gridDisk(sfCell, 1, disk));
+        for (int i = 0; i < 7; i++) {
+            if (disk[i] == sfCell) {
+                continue;
+            }
+            int contained = -1;
+            t_assertSuccess(cellContainsLatLng(disk[i], &sf, &contained));
+            t_assert(contained == 0, "point is not in a neighbor cell");
+        }
+    }
+
+    TEST(invalidArgs) {
+        int contained;
+        t_assert(cellContainsLatLng(0x7fffffffffffffff, &sf, &contained) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+        LatLng invalid = {NAN, 0};
+        t_assert(cellContainsLatLng(sfCell, &invalid, &contained) ==
+                     E_FAILED,
+                 "non-finite point is rejected");
+        LatLng offGlobe = {100, 0};
+        t_assert(cellContainsLatLng(sfCell, &offGlobe, &contained) ==
+                     E_LATLNG_DOMAIN,
+                 "point off the globe is rejected");
+    }
+}
@@ -0,0 +1,43 @@
+/** @file
+ * @brief tests H3 function `cellDecode`
+ *
+ *  usage: `testCellDecode`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void cellDecode_assertions(H3Index h) {
+    CellDecoded decoded;
+    t_assertSuccess(cellDecode(h, &decoded));
+
+    LatLng center;
+    t_assertSuccess(cellToLatLng(h, &center));
+    t_assert(fabs(decoded.center.lat - radsToDegs(center.lat)) < 1e-12,
+             "latitude matches");
+    t_assert(fabs(decoded.center.lng - radsToDegs(center.lng)) < 1e-12,
+             "longitude matches");
+    t_assert(decoded.resolution == getResolution(h), "resolution matches");
+    t_assert(decoded.baseCell == getBaseCellNumber(h), "base cell matches");
+    t_assert(decoded.isPentagon == isPentagon(h), "pentagon flag matches");
+}
+
+SUITE(cellDecode) {
+    TEST(matchesIndividualFunctions) {
+        iterateAllIndexesAtRes(0, cellDecode_assertions);
+        iterateAllIndexesAtRes(1, cellDecode_assertions);
+        iterateAllIndexesAtRes(2, cellDecode_assertions);
+    }
+
+    TEST(invalidCell) {
+        CellDecoded decoded;
+        t_assert(cellDecode(0x7fffffffffffffff, &decoded) == E_CELL_INVALID,
+                 "invalid cell is rejected");
+        t_assert(cellDecode(H3_NULL, &decoded) == E_CELL_INVALID,
+                 "null cell is rejected");
+    }
+}
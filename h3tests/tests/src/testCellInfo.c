@@ -0,0 +1,43 @@
+/** @file
+ * @brief tests H3 function `cellInfo`
+ *
+ *  usage: `testCellInfo`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void cellInfo_assertions(H3Index h) {
+    CellInfo info;
+    t_assertSuccess(cellInfo(h, &info));
+    t_assert(info.resolution == getResolution(h), "resolution matches");
+    t_assert(info.baseCell == getBaseCellNumber(h), "base cell matches");
+    t_assert(info.isPentagon == isPentagon(h), "pentagon flag matches");
+}
+
+SUITE(cellInfo) {
+    TEST(matchesIndividualFunctions) {
+        iterateAllIndexesAtRes(0, cellInfo_assertions);
+        iterateAllIndexesAtRes(1, cellInfo_assertions);
+        iterateAllIndexesAtRes(2, cellInfo_assertions);
+    }
+
+    TEST(pentagon) {
+        CellInfo info;
+        t_assertSuccess(cellInfo(0x821c07fffffffff, &info));
+        t_assert(info.resolution == 2, "got expected resolution");
+        t_assert(info.baseCell == 14, "got expected base cell");
+        t_assert(info.isPentagon == 1, "is a pentagon");
+    }
+
+    TEST(invalidCell) {
+        CellInfo info;
+        t_assert(cellInfo(0x7fffffffffffffff, &info) == E_CELL_INVALID,
+                 "invalid cell is rejected");
+        t_assert(cellInfo(H3_NULL, &info) == E_CELL_INVALID,
+                 "null cell is rejected");
+    }
+}
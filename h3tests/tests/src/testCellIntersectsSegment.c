@@ -0,0 +1,103 @@
+/** @file
+ * @brief tests H3 function `cellIntersectsSegment`
+ *
+ *  usage: `testCellIntersectsSegment`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static const H3Index sf = 0x8928308280fffff;
+
+static LatLng centerDegs(H3Index cell) {
+    LatLng center;
+    t_assertSuccess(cellToLatLng(cell, &center));
+    center.lat = radsToDegs(center.lat);
+    center.lng = radsToDegs(center.lng);
+    return center;
+}
+
+static int intersects(LatLng a, LatLng b) {
+    int out;
+    t_assertSuccess(cellIntersectsSegment(sf, &a, &b, &out));
+    return out;
+}
+
+SUITE(cellIntersectsSegment) {
+    TEST(crossing) {
+        LatLng center = centerDegs(sf);
+        LatLng far = {center.lat + 1, center.lng};
+        t_assert(intersects(center, far), "segment leaving the cell");
+        t_assert(intersects(far, center), "segment entering the cell");
+
+        // Both endpoints are outside, but the segment goes through the cell.
+        LatLng west = {center.lat, center.lng - 0.01};
+        LatLng east = {center.lat, center.lng + 0.01};
+        t_assert(intersects(west, east), "segment through the cell");
+    }
+
+    TEST(interior) {
+        LatLng center = centerDegs(sf);
+        LatLng near = {center.lat + 0.0001, center.lng + 0.0001};
+        t_assert(intersects(center, near), "segment inside the cell");
+        t_assert(intersects(center, center), "degenerate segment");
+    }
+
+    TEST(disjoint) {
+        LatLng center = centerDegs(sf);
+        LatLng a = {center.lat + 1, center.lng};
+        LatLng b = {center.lat + 1, center.lng + 0.5};
+        t_assert(!intersects(a, b), "segment far from the cell");
+
+        // Same great circle as a crossing segment, but stops before the cell.
+        LatLng west = {center.lat, center.lng - 0.02};
+        LatLng before = {center.lat, center.lng - 0.01};
+        t_assert(!intersects(west, before), "segment stops short");
+    }
+
+    TEST(nearParallel) {
+        // Short segment just outside a res 15 cell, parallel to one of its
+        // edges.
+        LatLng origin = {0.659966917655, -2.1364398519396};
+        H3Index cell;
+        t_assertSuccess(latLngToCell(&origin, 15, &cell));
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(cell, &boundary));
+        LatLng center = centerDegs(cell);
+        LatLng c = {radsToDegs(boundary.verts[0].lat),
+                    radsToDegs(boundary.verts[0].lng)};
+        LatLng d = {radsToDegs(boundary.verts[1].lat),
+                    radsToDegs(boundary.verts[1].lng)};
+        // Shift the middle half of the edge outward, by a tenth of the
+        // distance between the cell center and the edge.
+        double dLat = ((c.lat + d.lat) / 2 - center.lat) / 10;
+        double dLng = ((c.lng + d.lng) / 2 - center.lng) / 10;
+        LatLng a = {c.lat + (d.lat - c.lat) / 4 + dLat,
+                    c.lng + (d.lng - c.lng) / 4 + dLng};
+        LatLng b = {c.lat + 3 * (d.lat - c.lat) / 4 + dLat,
+                    c.lng + 3 * (d.lng - c.lng) / 4 + dLng};
+        int out;
+        t_assertSuccess(cellIntersectsSegment(cell, &a, &b, &out));
+        t_assert(!out, "parallel segment outside the cell");
+    }
+
+    TEST(invalid) {
+        LatLng center = centerDegs(sf);
+        LatLng bad = {NAN, 0};
+        int out;
+        t_assert(cellIntersectsSegment(0x7fffffffffffffff, &center, &center,
+                                       &out) == E_CELL_INVALID,
+                 "invalid cell is rejected");
+        t_assert(cellIntersectsSegment(sf, &center, &bad, &out) == E_FAILED,
+                 "non-finite endpoint is rejected");
+        LatLng offGlobe = {100, 0};
+        t_assert(cellIntersectsSegment(sf, &offGlobe, &center, &out) ==
+                     E_LATLNG_DOMAIN,
+                 "endpoint off the globe is rejected");
+        t_assert(cellIntersectsSegment(sf, NULL, &center, &out) == E_FAILED,
+                 "missing endpoint is rejected");
+    }
+}
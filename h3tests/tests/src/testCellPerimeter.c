@@ -0,0 +1,71 @@
+/** @file
+ * @brief tests H3 functions `cellPerimeterKm`, `cellPerimeterM` and
+ * `cellPerimeterRads`
+ *
+ *  usage: `testCellPerimeter`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellPerimeter) {
+    TEST(hexagon) {
+        H3Index sf = 0x89283080ddbffff;
+        H3Index edges[6];
+        t_assertSuccess(originToDirectedEdges(sf, edges));
+        double edgesLength = 0;
+        for (int i = 0; i < 6; i++) {
+            double length;
+            t_assertSuccess(edgeLengthM(edges[i], &length));
+            edgesLength += length;
+        }
+
+        double perimeter;
+        t_assertSuccess(cellPerimeterM(sf, &perimeter));
+        t_assert(fabs(perimeter - edgesLength) / edgesLength < 1e-9,
+                 "perimeter is the sum of the edge lengths");
+
+        double avgEdgeLength;
+        t_assertSuccess(getHexagonEdgeLengthAvgM(9, &avgEdgeLength));
+        t_assert(fabs(perimeter - 6 * avgEdgeLength) / perimeter < 0.2,
+                 "perimeter is roughly 6 times the edge length");
+    }
+
+    TEST(units) {
+        H3Index sf = 0x89283080ddbffff;
+        double km, m, rads;
+        t_assertSuccess(cellPerimeterKm(sf, &km));
+        t_assertSuccess(cellPerimeterM(sf, &m));
+        t_assertSuccess(cellPerimeterRads(sf, &rads));
+        t_assert(fabs(km * 1000 - m) < 1e-6, "km and m match");
+        t_assert(fabs(rads * 6371.007180918475 - km) < 1e-9,
+                 "rads and km match");
+    }
+
+    TEST(classIII) {
+        // Class III pentagon, with distortion vertices.
+        H3Index pentagon = 0x811c3ffffffffff;
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(pentagon, &boundary));
+        double expected = 0;
+        for (int i = 0; i < boundary.numVerts; i++) {
+            int next = (i + 1) % boundary.numVerts;
+            expected += greatCircleDistanceRads(&boundary.verts[i],
+                                                &boundary.verts[next]);
+        }
+        double perimeter;
+        t_assertSuccess(cellPerimeterRads(pentagon, &perimeter));
+        t_assert(fabs(perimeter - expected) < 1e-12,
+                 "perimeter follows the boundary");
+    }
+
+    TEST(invalidCell) {
+        double perimeter;
+        t_assert(cellPerimeterM(0x7fffffffffffffff, &perimeter) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
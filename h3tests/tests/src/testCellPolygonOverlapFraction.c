@@ -0,0 +1,170 @@
+/** @file
+ * @brief tests H3 function `cellPolygonOverlapFraction`
+ *
+ *  usage: `testCellPolygonOverlapFraction`
+ */
+
+#include <math.h>
+#include <stdbool.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static const H3Index cell = 0x8928308280fffff;
+static GeoLoop noHoles[1];
+
+static double overlapWith(H3Index h, LatLng *verts, int numVerts) {
+    GeoPolygon polygon = {.geoloop = {.numVerts = numVerts, .verts = verts},
+                          .holes = noHoles};
+    double fraction = -1;
+    t_assertSuccess(cellPolygonOverlapFraction(h, &polygon, &fraction));
+    return fraction;
+}
+
+static double overlap(LatLng *verts, int numVerts) {
+    return overlapWith(cell, verts, numVerts);
+}
+
+SUITE(cellPolygonOverlapFraction) {
+    LatLng c;
+    t_assertSuccess(cellToLatLng(cell, &c));
+    double d = 0.001;  // Much larger than a res 9 cell.
+
+    TEST(halfCovered) {
+        // Split the cell along the meridian of its center.
+        LatLng west[] = {{c.lat - d, c.lng - d},
+                         {c.lat - d, c.lng},
+                         {c.lat + d, c.lng},
+                         {c.lat + d, c.lng - d}};
+        LatLng east[] = {{c.lat - d, c.lng},
+                         {c.lat - d, c.lng + d},
+                         {c.lat + d, c.lng + d},
+                         {c.lat + d, c.lng}};
+        double westFraction = overlap(west, 4);
+        double eastFraction = overlap(east, 4);
+        t_assert(fabs(westFraction - 0.5) < 0.05, "about half on the west");
+        t_assert(fabs(eastFraction - 0.5) < 0.05, "about half on the east");
+        t_assert(fabs(westFraction + eastFraction - 1) < 1e-9,
+                 "halves are complementary");
+
+        // Winding doesn't matter.
+        LatLng reversed[] = {west[3], west[2], west[1], west[0]};
+        t_assert(fabs(overlap(reversed, 4) - westFraction) < 1e-12,
+                 "same fraction clockwise");
+    }
+
+    TEST(fullyInside) {
+        LatLng square[] = {{c.lat - d, c.lng - d},
+                           {c.lat - d, c.lng + d},
+                           {c.lat + d, c.lng + d},
+                           {c.lat + d, c.lng - d}};
+        t_assert(overlap(square, 4) == 1, "cell fully covered");
+    }
+
+    TEST(fullyOutside) {
+        LatLng square[] = {{c.lat + d, c.lng + d},
+                           {c.lat + d, c.lng + 2 * d},
+                           {c.lat + 2 * d, c.lng + 2 * d},
+                           {c.lat + 2 * d, c.lng + d}};
+        t_assert(overlap(square, 4) == 0, "cell not covered");
+
+        // Bounding boxes overlap, but the cell is outside the triangle.
+        LatLng triangle[] = {{c.lat - d, c.lng + d},
+                             {c.lat + d, c.lng + d},
+                             {c.lat + d, c.lng - d / 2}};
+        t_assert(overlap(triangle, 3) == 0, "cell outside the triangle");
+    }
+
+    TEST(insideCell) {
+        // A tiny square around the center, within the cell.
+        double e = 0.000002;
+        LatLng square[] = {{c.lat - e, c.lng - e},
+                           {c.lat - e, c.lng + e},
+                           {c.lat + e, c.lng + e},
+                           {c.lat + e, c.lng - e}};
+        double fraction = overlap(square, 4);
+        t_assert(fraction > 0 && fraction < 0.1, "small part of the cell");
+    }
+
+    TEST(hole) {
+        LatLng outer[] = {{c.lat - d, c.lng - d},
+                          {c.lat - d, c.lng + d},
+                          {c.lat + d, c.lng + d},
+                          {c.lat + d, c.lng - d}};
+        LatLng inner[] = {{c.lat - d / 2, c.lng - d / 2},
+                          {c.lat + d / 2, c.lng - d / 2},
+                          {c.lat + d / 2, c.lng},
+                          {c.lat - d / 2, c.lng}};
+        GeoLoop hole = {.numVerts = 4, .verts = inner};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = outer},
+                              .numHoles = 1,
+                              .holes = &hole};
+        double fraction;
+        t_assertSuccess(cellPolygonOverlapFraction(cell, &polygon, &fraction));
+        t_assert(fabs(fraction - 0.5) < 0.05, "hole removes the west half");
+    }
+
+    TEST(transmeridianPolygon) {
+        // A res 9 cell just west of the antimeridian.
+        LatLng origin = {0, M_PI - d / 2};
+        H3Index h;
+        t_assertSuccess(latLngToCell(&origin, 9, &h));
+        LatLng square[] = {{-d, M_PI - d},
+                           {-d, -M_PI + d},
+                           {d, -M_PI + d},
+                           {d, M_PI - d}};
+        t_assert(overlapWith(h, square, 4) == 1, "cell fully covered");
+
+        LatLng east[] = {{-d, -M_PI + d / 2},
+                         {-d, -M_PI + d},
+                         {d, -M_PI + d},
+                         {d, -M_PI + d / 2}};
+        t_assert(overlapWith(h, east, 4) == 0, "cell not covered");
+    }
+
+    TEST(transmeridianCell) {
+        LatLng origin = {0, M_PI};
+        H3Index h;
+        t_assertSuccess(latLngToCell(&origin, 4, &h));
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(h, &boundary));
+        bool west = false, east = false;
+        for (int i = 0; i < boundary.numVerts; i++) {
+            west |= boundary.verts[i].lng > 0;
+            east |= boundary.verts[i].lng < 0;
+        }
+        t_assert(west && east, "cell crosses the antimeridian");
+
+        // Split the cell along the antimeridian.
+        double e = 0.1;  // Much larger than a res 4 cell.
+        LatLng westSide[] = {
+            {-e, M_PI - e}, {-e, M_PI}, {e, M_PI}, {e, M_PI - e}};
+        LatLng eastSide[] = {
+            {-e, -M_PI}, {-e, -M_PI + e}, {e, -M_PI + e}, {e, -M_PI}};
+        double westFraction = overlapWith(h, westSide, 4);
+        double eastFraction = overlapWith(h, eastSide, 4);
+        t_assert(westFraction > 0 && eastFraction > 0, "both sides overlap");
+        t_assert(fabs(westFraction + eastFraction - 1) < 1e-9,
+                 "sides are complementary");
+
+        LatLng square[] = {
+            {-e, M_PI - e}, {-e, -M_PI + e}, {e, -M_PI + e}, {e, M_PI - e}};
+        t_assert(overlapWith(h, square, 4) == 1, "cell fully covered");
+    }
+
+    TEST(invalid) {
+        LatLng square[] = {{c.lat - d, c.lng - d},
+                           {c.lat - d, c.lng + d},
+                           {c.lat + d, c.lng + d},
+                           {c.lat + d, c.lng - d}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = square},
+                              .holes = noHoles};
+        double fraction;
+        t_assert(cellPolygonOverlapFraction(0x7fffffffffffffff, &polygon,
+                                            &fraction) == E_CELL_INVALID,
+                 "invalid cell");
+        t_assert(cellPolygonOverlapFraction(cell, NULL, &fraction) == E_FAILED,
+                 "null polygon");
+    }
+}
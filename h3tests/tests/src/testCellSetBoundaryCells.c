@@ -0,0 +1,65 @@
+/** @file
+ * @brief tests H3 function `cellSetBoundaryCells`
+ *
+ *  usage: `testCellSetBoundaryCells`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int contains(const H3Index *cells, int64_t len, H3Index cell) {
+    for (int64_t i = 0; i < len; i++) {
+        if (cells[i] == cell) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+SUITE(cellSetBoundaryCells) {
+    H3Index sf = 0x8928308280fffff;
+
+    TEST(solidDisk) {
+        H3Index disk[37] = {0};
+        H3Index ring[18] = {0};
+        t_assertSuccess(gridDisk(sf, 3, disk));
+        t_assertSuccess(gridRingUnsafe(sf, 3, ring));
+
+        H3Index out[37] = {0};
+        int64_t count = -1;
+        t_assertSuccess(cellSetBoundaryCells(disk, 37, out, &count));
+        t_assert(count == 18, "only the outer ring");
+        for (int i = 0; i < 18; i++) {
+            t_assert(contains(out, count, ring[i]), "outer ring cell");
+        }
+    }
+
+    TEST(duplicates) {
+        H3Index cells[3] = {sf, sf, sf};
+        H3Index out[3] = {0};
+        int64_t count = -1;
+        t_assertSuccess(cellSetBoundaryCells(cells, 3, out, &count));
+        t_assert(count == 1 && out[0] == sf, "single cell is its boundary");
+    }
+
+    TEST(empty) {
+        int64_t count = -1;
+        t_assertSuccess(cellSetBoundaryCells(NULL, 0, NULL, &count));
+        t_assert(count == 0, "empty set has no boundary");
+    }
+
+    TEST(invalid) {
+        H3Index parent;
+        t_assertSuccess(cellToParent(sf, 8, &parent));
+        H3Index mixed[2] = {parent, sf};
+        H3Index out[2] = {0};
+        int64_t count;
+        t_assert(cellSetBoundaryCells(mixed, 2, out, &count) ==
+                     E_RES_MISMATCH,
+                 "mixed resolutions");
+        t_assert(cellSetBoundaryCells(mixed, -1, out, &count) == E_DOMAIN,
+                 "negative size");
+    }
+}
@@ -0,0 +1,112 @@
+/** @file
+ * @brief tests H3 functions `cellSetUnion`, `cellSetIntersection` and
+ * `cellSetDifference`
+ *
+ *  usage: `testCellSetOps`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int contains(const H3Index *cells, int64_t len, H3Index cell) {
+    for (int64_t i = 0; i < len; i++) {
+        if (cells[i] == cell) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+SUITE(cellSetOps) {
+    H3Index sf = 0x8928308280fffff;
+    H3Index disk[7];
+    t_assertSuccess(gridDisk(sf, 1, disk));
+    H3Index ring[12];
+    int ringLen;
+    t_assertSuccess(gridRing(sf, 2, ring, &ringLen));
+
+    TEST(overlapping) {
+        // Origin and its first 3 neighbors, with a duplicate.
+        H3Index a[5] = {disk[0], disk[1], disk[2], disk[3], disk[1]};
+        // Remaining neighbors plus the origin.
+        H3Index b[4] = {disk[0], disk[4], disk[5], disk[6]};
+        H3Index out[9];
+        int64_t count;
+
+        t_assertSuccess(cellSetUnion(a, 5, b, 4, out, &count));
+        t_assert(count == 7, "union is the whole disk");
+        for (int i = 0; i < 7; i++) {
+            t_assert(contains(out, count, disk[i]), "disk cell in union");
+        }
+
+        t_assertSuccess(cellSetIntersection(a, 5, b, 4, out, &count));
+        t_assert(count == 1 && out[0] == disk[0],
+                 "intersection is the origin");
+
+        t_assertSuccess(cellSetDifference(a, 5, b, 4, out, &count));
+        t_assert(count == 3, "difference removes the origin and duplicates");
+        t_assert(out[0] == disk[1] && out[1] == disk[2] && out[2] == disk[3],
+                 "difference keeps the order of the first set");
+    }
+
+    TEST(disjoint) {
+        H3Index out[19];
+        int64_t count;
+
+        t_assertSuccess(cellSetUnion(disk, 7, ring, ringLen, out, &count));
+        t_assert(count == 7 + ringLen, "union keeps every cell");
+
+        t_assertSuccess(
+            cellSetIntersection(disk, 7, ring, ringLen, out, &count));
+        t_assert(count == 0, "intersection is empty");
+
+        t_assertSuccess(
+            cellSetDifference(disk, 7, ring, ringLen, out, &count));
+        t_assert(count == 7, "difference is the first set");
+    }
+
+    TEST(identical) {
+        H3Index out[14];
+        int64_t count;
+
+        t_assertSuccess(cellSetUnion(disk, 7, disk, 7, out, &count));
+        t_assert(count == 7, "union is the set");
+
+        t_assertSuccess(cellSetIntersection(disk, 7, disk, 7, out, &count));
+        t_assert(count == 7, "intersection is the set");
+
+        t_assertSuccess(cellSetDifference(disk, 7, disk, 7, out, &count));
+        t_assert(count == 0, "difference is empty");
+    }
+
+    TEST(empty) {
+        H3Index out[7];
+        int64_t count;
+
+        t_assertSuccess(cellSetUnion(NULL, 0, disk, 7, out, &count));
+        t_assert(count == 7, "union with empty set");
+
+        t_assertSuccess(cellSetIntersection(disk, 7, NULL, 0, NULL, &count));
+        t_assert(count == 0, "intersection with empty set");
+    }
+
+    TEST(invalid) {
+        H3Index parent;
+        t_assertSuccess(cellToParent(sf, 8, &parent));
+        H3Index mixed[2] = {sf, parent};
+        H3Index invalid[1] = {0x7fffffffffffffff};
+        H3Index out[9];
+        int64_t count;
+
+        t_assert(cellSetUnion(disk, 7, mixed, 2, out, &count) ==
+                     E_RES_MISMATCH,
+                 "mixed resolutions are rejected");
+        t_assert(cellSetUnion(disk, 7, invalid, 1, out, &count) ==
+                     E_CELL_INVALID,
+                 "invalid cells are rejected");
+        t_assert(cellSetUnion(disk, -1, disk, 7, out, &count) == E_DOMAIN,
+                 "negative sizes are rejected");
+    }
+}
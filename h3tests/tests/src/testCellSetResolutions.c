@@ -0,0 +1,39 @@
+/** @file
+ * @brief tests H3 function `cellSetResolutions`
+ *
+ *  usage: `testCellSetResolutions`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellSetResolutions) {
+    TEST(mixed) {
+        // Res 7, res 5, invalid, res 7 again.
+        H3Index cells[4] = {0, 0x85283473fffffff, 0x7fffffffffffffff, 0};
+        t_assertSuccess(cellToCenterChild(0x85283473fffffff, 7, &cells[0]));
+        t_assertSuccess(cellToCenterChild(0x8528340bfffffff, 7, &cells[3]));
+
+        int present[16] = {0};
+        int count = -1;
+        t_assertSuccess(cellSetResolutions(cells, 4, present, &count));
+        t_assert(count == 2, "two resolutions");
+        t_assert(present[0] == 5 && present[1] == 7, "sorted resolutions");
+    }
+
+    TEST(empty) {
+        int present[16] = {0};
+        int count = -1;
+        t_assertSuccess(cellSetResolutions(NULL, 0, present, &count));
+        t_assert(count == 0, "no resolution");
+    }
+
+    TEST(negativeSize) {
+        int present[16] = {0};
+        int count = -1;
+        t_assert(cellSetResolutions(NULL, -1, present, &count) == E_DOMAIN,
+                 "negative size fails");
+    }
+}
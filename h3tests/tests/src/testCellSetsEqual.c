@@ -0,0 +1,62 @@
+/** @file
+ * @brief tests H3 functions `cellSetsEqual` and `cellSetContains`
+ *
+ *  usage: `testCellSetsEqual`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellSetsEqual) {
+    H3Index sf = 0x8928308280fffff;
+    H3Index disk[7];
+    t_assertSuccess(gridDisk(sf, 1, disk));
+
+    TEST(sameCellsDifferentOrder) {
+        H3Index shuffled[8] = {disk[6], disk[3], disk[0], disk[5],
+                               disk[1], disk[4], disk[2], disk[3]};
+        int out = -1;
+        t_assertSuccess(cellSetsEqual(disk, 7, shuffled, 8, &out));
+        t_assert(out == 1, "order and duplicates are ignored");
+        t_assertSuccess(cellSetContains(disk, 7, shuffled, 8, &out));
+        t_assert(out == 1, "equal sets contain each other");
+        t_assertSuccess(cellSetContains(shuffled, 8, disk, 7, &out));
+        t_assert(out == 1, "equal sets contain each other");
+    }
+
+    TEST(strictSubset) {
+        H3Index subset[3] = {disk[4], disk[0], disk[4]};
+        int out = -1;
+        t_assertSuccess(cellSetsEqual(disk, 7, subset, 3, &out));
+        t_assert(out == 0, "strict subset isn't equal");
+        t_assertSuccess(cellSetContains(disk, 7, subset, 3, &out));
+        t_assert(out == 1, "superset contains the subset");
+        t_assertSuccess(cellSetContains(subset, 3, disk, 7, &out));
+        t_assert(out == 0, "subset doesn't contain the superset");
+    }
+
+    TEST(empty) {
+        int out = -1;
+        t_assertSuccess(cellSetsEqual(NULL, 0, NULL, 0, &out));
+        t_assert(out == 1, "empty sets are equal");
+        t_assertSuccess(cellSetContains(disk, 7, NULL, 0, &out));
+        t_assert(out == 1, "empty set is contained by any set");
+        t_assertSuccess(cellSetContains(NULL, 0, disk, 7, &out));
+        t_assert(out == 0, "empty set contains nothing");
+    }
+
+    TEST(invalid) {
+        H3Index parent;
+        t_assertSuccess(cellToParent(sf, 8, &parent));
+        H3Index mixed[2] = {parent, sf};
+        int out;
+        t_assert(cellSetsEqual(disk, 7, mixed, 2, &out) == E_RES_MISMATCH,
+                 "mixed resolutions");
+        t_assert(cellSetContains(disk, 7, mixed, 2, &out) == E_RES_MISMATCH,
+                 "mixed resolutions");
+        t_assert(cellSetsEqual(disk, -1, disk, 7, &out) == E_DOMAIN,
+                 "negative size");
+    }
+}
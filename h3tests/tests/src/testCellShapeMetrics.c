@@ -0,0 +1,50 @@
+/** @file
+ * @brief tests H3 function `cellShapeMetrics`
+ *
+ *  usage: `testCellShapeMetrics`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+// Isoperimetric quotients of the regular polygons: π/(n·tan(π/n)).
+#define REGULAR_HEXAGON_IQ 0.9068996821
+#define REGULAR_PENTAGON_IQ 0.8648062470
+
+SUITE(cellShapeMetrics) {
+    TEST(hexagon) {
+        ShapeMetrics metrics = {0};
+        double area = 0;
+        double perimeter = 0;
+        t_assertSuccess(cellShapeMetrics(0x88283082e3fffff, &metrics));
+        t_assertSuccess(cellAreaM2(0x88283082e3fffff, &area));
+        t_assertSuccess(cellPerimeterM(0x88283082e3fffff, &perimeter));
+        t_assert(metrics.numVerts == 6, "six vertices");
+        t_assert(metrics.areaM2 == area, "area matches cellAreaM2");
+        t_assert(metrics.perimeterM == perimeter,
+                 "perimeter matches cellPerimeterM");
+        t_assert(fabs(metrics.isoperimetricQuotient - REGULAR_HEXAGON_IQ) <
+                     1e-2,
+                 "quotient close to the regular hexagon");
+    }
+
+    TEST(pentagon) {
+        ShapeMetrics metrics = {0};
+        t_assertSuccess(cellShapeMetrics(0x8009fffffffffff, &metrics));
+        t_assert(metrics.numVerts == 5, "five vertices");
+        t_assert(fabs(metrics.isoperimetricQuotient - REGULAR_PENTAGON_IQ) <
+                     1e-2,
+                 "quotient close to the regular pentagon");
+        t_assert(REGULAR_HEXAGON_IQ - metrics.isoperimetricQuotient > 2e-2,
+                 "pentagon is less compact than a hexagon");
+    }
+
+    TEST(invalid) {
+        ShapeMetrics metrics = {0};
+        t_assert(cellShapeMetrics(0, &metrics) == E_CELL_INVALID,
+                 "invalid cell fails");
+    }
+}
@@ -0,0 +1,95 @@
+/** @file
+ * @brief tests H3 function `cellToBoundaryDensified`
+ *
+ *  usage: `testCellToBoundaryDensified`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void toRadians(const LatLng *degs, LatLng *rads) {
+    latLngSetDegs(rads, degs->lat, degs->lng);
+}
+
+SUITE(cellToBoundaryDensified) {
+    TEST(greatCircle) {
+        // Res 0 edges are long enough to bend noticeably on maps.
+        H3Index cell = 0x8029fffffffffff;
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(cell, &boundary));
+
+        int pointsPerEdge = 8;
+        int cap = boundary.numVerts * (pointsPerEdge + 1);
+        LatLng *points = calloc(cap, sizeof(LatLng));
+        int count = 0;
+        t_assertSuccess(
+            cellToBoundaryDensified(cell, pointsPerEdge, points, cap, &count));
+        t_assert(count == cap, "all the points are written");
+
+        for (int v = 0; v < boundary.numVerts; v++) {
+            LatLng start = boundary.verts[v];
+            LatLng end = boundary.verts[(v + 1) % boundary.numVerts];
+            double edge = greatCircleDistanceRads(&start, &end);
+
+            LatLng vertex;
+            toRadians(&points[v * (pointsPerEdge + 1)], &vertex);
+            t_assert(greatCircleDistanceRads(&vertex, &start) < 1e-12,
+                     "boundary vertex is kept");
+
+            double previous = 0;
+            for (int i = 1; i <= pointsPerEdge; i++) {
+                LatLng point;
+                toRadians(&points[v * (pointsPerEdge + 1) + i], &point);
+                double fromStart = greatCircleDistanceRads(&start, &point);
+                double toEnd = greatCircleDistanceRads(&point, &end);
+                t_assert(fabs(fromStart + toEnd - edge) < 1e-12,
+                         "point lies on the great circle arc");
+                t_assert(fabs(fromStart - edge * i / (pointsPerEdge + 1)) <
+                             1e-12,
+                         "points are evenly spaced");
+                t_assert(fromStart > previous, "points are ordered");
+                previous = fromStart;
+            }
+        }
+        free(points);
+    }
+
+    TEST(noPoints) {
+        H3Index cell = 0x8928308280fffff;
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(cell, &boundary));
+        LatLng points[MAX_CELL_BNDRY_VERTS];
+        int count = 0;
+        t_assertSuccess(cellToBoundaryDensified(
+            cell, 0, points, MAX_CELL_BNDRY_VERTS, &count));
+        t_assert(count == boundary.numVerts, "only the vertexes");
+        for (int v = 0; v < count; v++) {
+            t_assert(fabs(degsToRads(points[v].lat) - boundary.verts[v].lat) <
+                             1e-12 &&
+                         fabs(degsToRads(points[v].lng) -
+                              boundary.verts[v].lng) < 1e-12,
+                     "same vertexes as cellToBoundary");
+        }
+    }
+
+    TEST(invalid) {
+        H3Index cell = 0x8928308280fffff;
+        LatLng points[20];
+        int count = 0;
+        t_assert(cellToBoundaryDensified(cell, 2, points, 17, &count) ==
+                     E_MEMORY_BOUNDS,
+                 "capacity too small");
+        t_assert(cellToBoundaryDensified(cell, -1, points, 20, &count) ==
+                     E_DOMAIN,
+                 "negative points per edge");
+        t_assert(cellToBoundaryDensified(cell, 0x7fffffff, points, 20,
+                                         &count) == E_MEMORY_BOUNDS,
+                 "overflowing size");
+        t_assert(cellToBoundaryDensified(0x7fffffffffffffff, 2, points, 20,
+                                         &count) == E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
@@ -0,0 +1,86 @@
+/** @file
+ * @brief tests H3 function `cellToBoundaryProjected`
+ *
+ *  usage: `testCellToBoundaryProjected`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define CELL 0x85283473fffffff
+
+static int identity(double lat, double lng, double *x, double *y,
+                    void *ctx) {
+    int *calls = ctx;
+    *calls += 1;
+    *x = lng;
+    *y = lat;
+    return 0;
+}
+
+static int failing(double lat, double lng, double *x, double *y,
+                   void *ctx) {
+    (void)lat;
+    (void)lng;
+    (void)ctx;
+    *x = 0;
+    *y = 0;
+    return 1;
+}
+
+SUITE(cellToBoundaryProjected) {
+    TEST(identityProjection) {
+        LatLng verts[MAX_CELL_BNDRY_VERTS] = {0};
+        int numLoopVerts = 0;
+        t_assertSuccess(
+            cellToGeoLoop(CELL, verts, MAX_CELL_BNDRY_VERTS, &numLoopVerts));
+
+        double out[2 * MAX_CELL_BNDRY_VERTS] = {0};
+        int numVerts = 0;
+        int calls = 0;
+        t_assertSuccess(cellToBoundaryProjected(
+            CELL, identity, &calls, out, MAX_CELL_BNDRY_VERTS, &numVerts));
+        t_assert(numVerts == numLoopVerts, "same number of vertices");
+        t_assert(calls == numVerts, "projection called once per vertex");
+        for (int i = 0; i < numVerts; i++) {
+            t_assert(out[2 * i] == verts[i].lng, "x is the longitude");
+            t_assert(out[2 * i + 1] == verts[i].lat, "y is the latitude");
+        }
+    }
+
+    TEST(tooSmall) {
+        double out[2] = {0};
+        int numVerts = 0;
+        int calls = 0;
+        t_assert(cellToBoundaryProjected(CELL, identity, &calls, out, 1,
+                                         &numVerts) == E_MEMORY_BOUNDS,
+                 "small buffer fails");
+        t_assert(numVerts == 6, "vertex count still reported");
+        t_assert(calls == 0, "projection not called");
+    }
+
+    TEST(aborted) {
+        double out[2 * MAX_CELL_BNDRY_VERTS] = {0};
+        int numVerts = 0;
+        t_assert(cellToBoundaryProjected(CELL, failing, NULL, out,
+                                         MAX_CELL_BNDRY_VERTS,
+                                         &numVerts) == E_FAILED,
+                 "nonzero return aborts");
+    }
+
+    TEST(invalid) {
+        double out[2 * MAX_CELL_BNDRY_VERTS] = {0};
+        int numVerts = 0;
+        int calls = 0;
+        t_assert(cellToBoundaryProjected(0, identity, &calls, out,
+                                         MAX_CELL_BNDRY_VERTS,
+                                         &numVerts) == E_CELL_INVALID,
+                 "invalid cell fails");
+        t_assert(cellToBoundaryProjected(CELL, NULL, NULL, out,
+                                         MAX_CELL_BNDRY_VERTS,
+                                         &numVerts) == E_FAILED,
+                 "null projection fails");
+    }
+}
@@ -0,0 +1,44 @@
+/** @file
+ * @brief tests H3 function `cellToBoundaryWinding`
+ *
+ *  usage: `testCellToBoundaryWinding`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void cellToBoundaryWinding_assertions(H3Index h) {
+    CellBoundary expected, ccw, cw;
+    t_assertSuccess(cellToBoundary(h, &expected));
+    t_assertSuccess(cellToBoundaryWinding(h, 0, &ccw));
+    t_assertSuccess(cellToBoundaryWinding(h, 1, &cw));
+
+    t_assert(ccw.numVerts == expected.numVerts, "ccw has the same size");
+    t_assert(cw.numVerts == expected.numVerts, "cw has the same size");
+    for (int i = 0; i < expected.numVerts; i++) {
+        int j = expected.numVerts - 1 - i;
+        t_assert(ccw.verts[i].lat == expected.verts[i].lat &&
+                     ccw.verts[i].lng == expected.verts[i].lng,
+                 "ccw matches cellToBoundary");
+        t_assert(cw.verts[i].lat == expected.verts[j].lat &&
+                     cw.verts[i].lng == expected.verts[j].lng,
+                 "cw is the reverse of ccw");
+    }
+}
+
+SUITE(cellToBoundaryWinding) {
+    TEST(allCells) {
+        iterateAllIndexesAtRes(0, cellToBoundaryWinding_assertions);
+        iterateAllIndexesAtRes(1, cellToBoundaryWinding_assertions);
+    }
+
+    TEST(invalidCell) {
+        CellBoundary boundary;
+        t_assert(cellToBoundaryWinding(0x7fffffffffffffff, 1, &boundary) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
@@ -0,0 +1,51 @@
+/** @file
+ * @brief tests H3 function `cellToCellBearingDegs`
+ *
+ *  usage: `testCellToCellBearingDegs`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static H3Index cellAt(double latDegs, double lngDegs) {
+    LatLng ll = {degsToRads(latDegs), degsToRads(lngDegs)};
+    H3Index cell = 0;
+    t_assertSuccess(latLngToCell(&ll, 7, &cell));
+    return cell;
+}
+
+SUITE(cellToCellBearingDegs) {
+    TEST(cardinal) {
+        H3Index origin = cellAt(0, 10);
+        double bearing = -1;
+
+        t_assertSuccess(
+            cellToCellBearingDegs(origin, cellAt(0, 12), &bearing));
+        t_assert(fabs(bearing - 90) < 2, "due east is near 90");
+
+        t_assertSuccess(
+            cellToCellBearingDegs(origin, cellAt(0, 8), &bearing));
+        t_assert(fabs(bearing - 270) < 2, "due west is near 270");
+
+        t_assertSuccess(
+            cellToCellBearingDegs(origin, cellAt(2, 10), &bearing));
+        t_assert(bearing < 2 || bearing > 358, "due north is near 0");
+
+        t_assertSuccess(
+            cellToCellBearingDegs(origin, cellAt(-2, 10), &bearing));
+        t_assert(fabs(bearing - 180) < 2, "due south is near 180");
+    }
+
+    TEST(invalid) {
+        double bearing = -1;
+        t_assert(cellToCellBearingDegs(0, 0x85283473fffffff, &bearing) ==
+                     E_CELL_INVALID,
+                 "invalid origin fails");
+        t_assert(cellToCellBearingDegs(0x85283473fffffff, 0, &bearing) ==
+                     E_CELL_INVALID,
+                 "invalid destination fails");
+    }
+}
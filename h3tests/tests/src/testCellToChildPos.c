@@ -36,7 +36,7 @@ static void childPos_assertions(H3Index h3) {
             cellToChildrenSize(h3, childRes, &numChildren));
 
         H3Index *children = calloc(numChildren, sizeof(H3Index));
-        t_assertSuccess(cellToChildren(h3, childRes, children));
+        t_assertSuccess(cellToChildren(h3, childRes, children, numChildren));
 
         int64_t childPos;
         H3Index cell;
@@ -99,8 +99,29 @@ SUITE(cellToChildPos) {
         // res is two steps down, so max valid child pos is 48
         t_assert(childPosToCell(48, parent, res, &cell) == E_SUCCESS,
                  "No error for max valid child pos");
-        t_assert(childPosToCell(49, parent, res, &cell) == E_RES_MISMATCH,
+        t_assert(childPosToCell(49, parent, res, &cell) == E_DOMAIN,
                  "error matches expected for childPos greater than max");
+
+        // One past the end, for a pentagon and an hexagon.
+        H3Index pentagon = 0x821c07fffffffff;
+        int64_t numChildren;
+        t_assertSuccess(cellToChildrenSize(pentagon, 4, &numChildren));
+        t_assert(childPosToCell(numChildren - 1, pentagon, 4, &cell) ==
+                     E_SUCCESS,
+                 "No error for last pentagon child");
+        t_assert(childPosToCell(numChildren, pentagon, 4, &cell) == E_DOMAIN,
+                 "error matches expected for one past the last child");
+        t_assertSuccess(cellToChildrenSize(parent, res, &numChildren));
+        t_assert(childPosToCell(numChildren, parent, res, &cell) == E_DOMAIN,
+                 "error matches expected for one past the last child");
+    }
+
+    TEST(childPosToCell_coarser_res_with_valid_pos) {
+        H3Index cell;
+        // random res 8 cell
+        H3Index parent = 0x88283080ddfffff;
+        t_assert(childPosToCell(0, parent, 7, &cell) == E_RES_MISMATCH,
+                 "coarser res is a mismatch, even for a valid position");
     }
 
     TEST(cellToChildPos_invalid_digit) {
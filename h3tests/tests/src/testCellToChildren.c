@@ -73,7 +73,7 @@ static void checkChildren(H3Index h, int res, H3Error expectedError,
         return;
     }
     H3Index *children = calloc(numChildren, sizeof(H3Index));
-    t_assertSuccess(H3_EXPORT(cellToChildren)(h, res, children));
+    t_assertSuccess(H3_EXPORT(cellToChildren)(h, res, children, numChildren));
 
     assertSetsEqual(children, numChildren, expected, numExpected);
 
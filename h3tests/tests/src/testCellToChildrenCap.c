@@ -0,0 +1,50 @@
+/** @file
+ * @brief tests H3 functions `cellToChildren` (capacity check) and
+ * `cellToChildrenUnchecked`
+ *
+ *  usage: `testCellToChildrenCap`
+ */
+
+#include <stdlib.h>
+#include <string.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define PARENT 0x88283080ddfffff
+
+SUITE(cellToChildrenCap) {
+    TEST(smallCap) {
+        // One extra slot, as a canary.
+        H3Index children[7] = {0};
+        t_assert(cellToChildren(PARENT, 9, children, 6) == E_MEMORY_BOUNDS,
+                 "undersized output fails");
+        for (int i = 0; i < 7; i++) {
+            t_assert(children[i] == H3_NULL, "nothing written");
+        }
+        t_assert(cellToChildren(PARENT, 9, children, -1) == E_MEMORY_BOUNDS,
+                 "negative capacity fails");
+    }
+
+    TEST(exactCap) {
+        H3Index children[7] = {0};
+        H3Index unchecked[7] = {0};
+        t_assertSuccess(cellToChildren(PARENT, 9, children, 7));
+        t_assertSuccess(cellToChildrenUnchecked(PARENT, 9, unchecked));
+        t_assert(memcmp(children, unchecked, sizeof(children)) == 0,
+                 "same children as the unchecked variant");
+        for (int i = 0; i < 7; i++) {
+            H3Index parent = 0;
+            t_assertSuccess(cellToParent(children[i], 8, &parent));
+            t_assert(parent == PARENT, "child of the parent");
+        }
+    }
+
+    TEST(invalid) {
+        H3Index children[7] = {0};
+        t_assert(cellToChildren(0, 9, children, 7) == E_CELL_INVALID,
+                 "invalid cell fails");
+        t_assert(cellToChildren(PARENT, 16, children, 7) == E_RES_DOMAIN,
+                 "invalid resolution fails");
+    }
+}
@@ -0,0 +1,46 @@
+/** @file
+ * @brief tests H3 function `cellToChildrenOrdered`
+ *
+ *  usage: `testCellToChildrenOrdered`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void assertOrderedChildren(H3Index parent, int childRes) {
+    int64_t size;
+    t_assertSuccess(cellToChildrenSize(parent, childRes, &size));
+    H3Index *children = calloc(size, sizeof(H3Index));
+    t_assertSuccess(cellToChildrenOrdered(parent, childRes, children));
+
+    for (int64_t p = 0; p < size; p++) {
+        H3Index expected;
+        t_assertSuccess(childPosToCell(p, parent, childRes, &expected));
+        t_assert(children[p] == expected, "child matches its position");
+    }
+    free(children);
+}
+
+SUITE(cellToChildrenOrdered) {
+    TEST(hexagon) {
+        H3Index parent = 0x88283080ddfffff;
+        assertOrderedChildren(parent, 8);
+        assertOrderedChildren(parent, 9);
+        assertOrderedChildren(parent, 11);
+    }
+
+    TEST(pentagon) {
+        H3Index pentagon = 0x821c07fffffffff;
+        assertOrderedChildren(pentagon, 3);
+        assertOrderedChildren(pentagon, 5);
+    }
+
+    TEST(invalidCell) {
+        H3Index children[7];
+        t_assert(cellToChildrenOrdered(0x7fffffffffffffff, 1, children) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
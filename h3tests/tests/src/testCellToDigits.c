@@ -0,0 +1,72 @@
+/** @file
+ * @brief tests H3 function `cellToDigits`
+ *
+ *  usage: `testCellToDigits`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+/** Rebuilds a cell from its base cell and digits. */
+static H3Index buildCell(int baseCell, const int *digits, int res) {
+    // Res 0, base cell 0, unused digits set to 7.
+    H3Index cell = 0x8001fffffffffffULL;
+    cell |= (H3Index)res << 52;
+    cell |= (H3Index)baseCell << 45;
+    for (int r = 1; r <= res; r++) {
+        int offset = (15 - r) * 3;
+        cell &= ~(0x7ULL << offset);
+        cell |= (H3Index)digits[r - 1] << offset;
+    }
+    return cell;
+}
+
+static void cellToDigits_assertions(H3Index cell) {
+    int digits[15] = {0};
+    int count = -1;
+    t_assertSuccess(cellToDigits(cell, digits, &count));
+    t_assert(count == getResolution(cell), "one digit per resolution");
+    for (int i = 0; i < count; i++) {
+        t_assert(digits[i] >= 0 && digits[i] <= 6, "digit in 0-6");
+    }
+    t_assert(buildCell(getBaseCellNumber(cell), digits, count) == cell,
+             "base cell and digits rebuild the cell");
+}
+
+SUITE(cellToDigits) {
+    TEST(knownCell) {
+        // 0x85283473fffffff: base cell 20, digits 0, 6, 4, 3, 4.
+        int digits[15] = {0};
+        int count = -1;
+        t_assertSuccess(cellToDigits(0x85283473fffffff, digits, &count));
+        t_assert(count == 5, "res 5 cell");
+        int expected[5] = {0, 6, 4, 3, 4};
+        for (int i = 0; i < 5; i++) {
+            t_assert(digits[i] == expected[i], "expected digit");
+        }
+    }
+
+    TEST(allCells) {
+        iterateAllIndexesAtRes(0, cellToDigits_assertions);
+        iterateAllIndexesAtRes(1, cellToDigits_assertions);
+        iterateAllIndexesAtRes(2, cellToDigits_assertions);
+        cellToDigits_assertions(0x8f2830828052d25);
+    }
+
+    TEST(invalidCell) {
+        int digits[15] = {0};
+        t_assert(cellToDigits(0, digits, NULL) == E_CELL_INVALID,
+                 "invalid cell");
+    }
+
+    TEST(nullDigits) {
+        int count = -1;
+        t_assert(cellToDigits(0x85283473fffffff, NULL, &count) == E_FAILED,
+                 "null digits");
+        t_assertSuccess(cellToDigits(0x8029fffffffffff, NULL, &count));
+        t_assert(count == 0, "no digits at res 0");
+    }
+}
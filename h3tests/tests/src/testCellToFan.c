@@ -0,0 +1,62 @@
+/** @file
+ * @brief tests H3 function `cellToFan`
+ *
+ *  usage: `testCellToFan`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int sameDegs(LatLng rads, LatLng degs) {
+    return fabs(radsToDegs(rads.lat) - degs.lat) < 1e-9 &&
+           fabs(radsToDegs(rads.lng) - degs.lng) < 1e-9;
+}
+
+static void assertFan(H3Index cell) {
+    LatLng fan[MAX_CELL_BNDRY_VERTS + 1] = {{0}};
+    int count = 0;
+    t_assertSuccess(cellToFan(cell, fan, MAX_CELL_BNDRY_VERTS + 1, &count));
+
+    LatLng center;
+    CellBoundary boundary;
+    t_assertSuccess(cellToLatLng(cell, &center));
+    t_assertSuccess(cellToBoundary(cell, &boundary));
+    t_assert(count == boundary.numVerts + 1, "center and boundary");
+    t_assert(sameDegs(center, fan[0]), "first point is the center");
+    for (int i = 0; i < boundary.numVerts; i++) {
+        t_assert(sameDegs(boundary.verts[i], fan[i + 1]),
+                 "then the boundary");
+    }
+}
+
+SUITE(cellToFan) {
+    TEST(hexagon) { assertFan(0x8928308280fffff); }
+
+    TEST(pentagon) { assertFan(0x8009fffffffffff); }
+
+    TEST(distortion) {
+        // Class III pentagon, whose boundary has distortion vertices.
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(0x81083ffffffffff, &boundary));
+        t_assert(boundary.numVerts > 5, "has distortion vertices");
+        assertFan(0x81083ffffffffff);
+    }
+
+    TEST(memoryBounds) {
+        LatLng fan[7] = {{0}};
+        int count = 0;
+        t_assert(cellToFan(0x8928308280fffff, fan, 6, &count) ==
+                     E_MEMORY_BOUNDS,
+                 "no room for the center");
+        t_assertSuccess(cellToFan(0x8928308280fffff, fan, 7, &count));
+        t_assert(count == 7, "exact capacity");
+    }
+
+    TEST(invalidCell) {
+        LatLng fan[7] = {{0}};
+        t_assert(cellToFan(0, fan, 7, NULL) == E_CELL_INVALID, "invalid cell");
+    }
+}
@@ -0,0 +1,85 @@
+/** @file
+ * @brief tests H3 function `cellToGeoLoop`
+ *
+ *  usage: `testCellToGeoLoop`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellToGeoLoop) {
+    H3Index sf = 0x8928308280fffff;
+
+    TEST(matchesBoundary) {
+        LatLng verts[MAX_CELL_BNDRY_VERTS];
+        int numVerts;
+        t_assertSuccess(
+            cellToGeoLoop(sf, verts, MAX_CELL_BNDRY_VERTS, &numVerts));
+
+        CellBoundary boundary;
+        t_assertSuccess(cellToBoundary(sf, &boundary));
+        t_assert(numVerts == boundary.numVerts, "same number of vertices");
+        for (int i = 0; i < numVerts; i++) {
+            t_assert(fabs(degsToRads(verts[i].lat) - boundary.verts[i].lat) <
+                         1e-12,
+                     "latitude in degrees");
+            t_assert(fabs(degsToRads(verts[i].lng) - boundary.verts[i].lng) <
+                         1e-12,
+                     "longitude in degrees");
+        }
+    }
+
+    TEST(polyfillRoundTrip) {
+        LatLng verts[MAX_CELL_BNDRY_VERTS];
+        int numVerts;
+        t_assertSuccess(
+            cellToGeoLoop(sf, verts, MAX_CELL_BNDRY_VERTS, &numVerts));
+        for (int i = 0; i < numVerts; i++) {
+            verts[i].lat = degsToRads(verts[i].lat);
+            verts[i].lng = degsToRads(verts[i].lng);
+        }
+
+        GeoLoop geoLoop = {.numVerts = numVerts, .verts = verts};
+        GeoLoop noHoles[1];
+        GeoPolygon polygon = {
+            .geoloop = geoLoop, .numHoles = 0, .holes = noHoles};
+        int64_t size;
+        t_assertSuccess(maxPolygonToCellsSize(&polygon, 9, 0, &size));
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        t_assertSuccess(polygonToCells(&polygon, 9, 0, cells));
+
+        int64_t found = 0;
+        for (int64_t i = 0; i < size; i++) {
+            if (cells[i] != H3_NULL) {
+                t_assert(cells[i] == sf, "polyfill gives the cell back");
+                found++;
+            }
+        }
+        t_assert(found == 1, "polyfill gives a single cell");
+        free(cells);
+    }
+
+    TEST(tooSmall) {
+        LatLng verts[5];
+        int numVerts;
+        t_assert(cellToGeoLoop(sf, verts, 5, &numVerts) == E_MEMORY_BOUNDS,
+                 "small buffer is rejected");
+        t_assert(numVerts == 6, "required size is reported");
+
+        H3Index pentagon = 0x821c07fffffffff;
+        t_assertSuccess(cellToGeoLoop(pentagon, verts, 5, &numVerts));
+        t_assert(numVerts == 5, "pentagon fits in 5 vertices");
+    }
+
+    TEST(invalidCell) {
+        LatLng verts[MAX_CELL_BNDRY_VERTS];
+        int numVerts;
+        t_assert(cellToGeoLoop(0x7fffffffffffffff, verts,
+                               MAX_CELL_BNDRY_VERTS,
+                               &numVerts) == E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
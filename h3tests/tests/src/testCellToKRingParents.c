@@ -0,0 +1,72 @@
+/** @file
+ * @brief tests H3 function `cellToKRingParents`
+ *
+ *  usage: `testCellToKRingParents`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define ORIGIN 0x85283473fffffff
+
+SUITE(cellToKRingParents) {
+    TEST(coarseNeighborhood) {
+        H3Index disk[7] = {0};
+        H3Index parents[7] = {0};
+        int64_t count = -1;
+        t_assertSuccess(gridDisk(ORIGIN, 1, disk));
+        t_assertSuccess(cellToKRingParents(ORIGIN, 1, 3, parents, &count));
+        t_assert(count >= 1 && count <= 7, "small set of parents");
+
+        H3Index parent = 0;
+        t_assertSuccess(cellToParent(ORIGIN, 3, &parent));
+        int hasOwnParent = 0;
+        for (int64_t i = 0; i < count; i++) {
+            hasOwnParent |= parents[i] == parent;
+            for (int64_t j = 0; j < i; j++) {
+                t_assert(parents[i] != parents[j], "parents are unique");
+            }
+            int covers = 0;
+            for (int j = 0; j < 7; j++) {
+                H3Index p = 0;
+                t_assertSuccess(cellToParent(disk[j], 3, &p));
+                covers |= p == parents[i];
+            }
+            t_assert(covers, "parent of a neighbor");
+        }
+        t_assert(hasOwnParent, "contains the origin's parent");
+
+        for (int j = 0; j < 7; j++) {
+            H3Index p = 0;
+            int found = 0;
+            t_assertSuccess(cellToParent(disk[j], 3, &p));
+            for (int64_t i = 0; i < count; i++) {
+                found |= parents[i] == p;
+            }
+            t_assert(found, "every neighbor is covered");
+        }
+    }
+
+    TEST(ownResolution) {
+        H3Index parents[7] = {0};
+        int64_t count = -1;
+        t_assertSuccess(cellToKRingParents(ORIGIN, 1, 5, parents, &count));
+        t_assert(count == 7, "same resolution gives the k-ring");
+    }
+
+    TEST(invalid) {
+        H3Index parents[7] = {0};
+        int64_t count = -1;
+        t_assert(cellToKRingParents(ORIGIN, 1, 6, parents, &count) ==
+                     E_RES_MISMATCH,
+                 "finer resolution fails");
+        t_assert(cellToKRingParents(ORIGIN, -1, 3, parents, &count) ==
+                     E_DOMAIN,
+                 "negative k fails");
+        t_assert(cellToKRingParents(0, 1, 3, parents, &count) ==
+                     E_CELL_INVALID,
+                 "invalid cell fails");
+    }
+}
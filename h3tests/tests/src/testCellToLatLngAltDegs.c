@@ -0,0 +1,51 @@
+/** @file
+ * @brief tests H3 function `cellToLatLngAltDegs`
+ *
+ *  usage: `testCellToLatLngAltDegs`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void assertCenter(H3Index h) {
+    LatLng center;
+    t_assertSuccess(cellToLatLng(h, &center));
+    LatLngAlt out;
+    t_assertSuccess(cellToLatLngAltDegs(h, 1234.5, &out));
+    t_assert(fabs(out.lat - radsToDegs(center.lat)) < 1e-12,
+             "latitude matches");
+    t_assert(fabs(out.lng - radsToDegs(center.lng)) < 1e-12,
+             "longitude matches");
+    t_assert(out.alt == 1234.5, "altitude is preserved");
+}
+
+SUITE(cellToLatLngAltDegs) {
+    TEST(center) {
+        iterateAllIndexesAtRes(0, assertCenter);
+        iterateAllIndexesAtRes(1, assertCenter);
+    }
+
+    TEST(altitude) {
+        H3Index h = 0x8928308280fffff;
+        double altitudes[] = {0, -430.5, 8848.86, INFINITY};
+        for (int i = 0; i < 4; i++) {
+            LatLngAlt out;
+            t_assertSuccess(cellToLatLngAltDegs(h, altitudes[i], &out));
+            t_assert(out.alt == altitudes[i], "altitude passed through");
+        }
+        LatLngAlt out;
+        t_assertSuccess(cellToLatLngAltDegs(h, NAN, &out));
+        t_assert(isnan(out.alt), "NaN altitude passed through");
+    }
+
+    TEST(invalid) {
+        LatLngAlt out;
+        t_assert(cellToLatLngAltDegs(0x7fffffffffffffff, 0, &out) ==
+                     E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
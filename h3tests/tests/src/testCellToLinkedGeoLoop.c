@@ -0,0 +1,59 @@
+/** @file
+ * @brief tests H3 functions `cellToLinkedGeoLoop` and `destroyLinkedGeoLoop`
+ *
+ *  usage: `testCellToLinkedGeoLoop`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static int countVerts(const LinkedGeoLoop *loop) {
+    int count = 0;
+    for (LinkedLatLng *coord = loop->first; coord != NULL;
+         coord = coord->next) {
+        count++;
+    }
+    return count;
+}
+
+static void loop_assertions(H3Index h3) {
+    CellBoundary boundary;
+    t_assertSuccess(cellToBoundary(h3, &boundary));
+
+    LinkedGeoLoop loop;
+    t_assertSuccess(cellToLinkedGeoLoop(h3, &loop));
+    t_assert(countVerts(&loop) == boundary.numVerts,
+             "loop length matches the cell vertex count");
+
+    int i = 0;
+    for (LinkedLatLng *coord = loop.first; coord != NULL;
+         coord = coord->next, i++) {
+        t_assert(fabs(coord->vertex.lat -
+                      radsToDegs(boundary.verts[i].lat)) < 1e-9,
+                 "latitude is in degrees");
+        t_assert(fabs(coord->vertex.lng -
+                      radsToDegs(boundary.verts[i].lng)) < 1e-9,
+                 "longitude is in degrees");
+    }
+
+    destroyLinkedGeoLoop(&loop);
+    t_assert(loop.first == NULL && loop.last == NULL, "loop is emptied");
+}
+
+SUITE(cellToLinkedGeoLoop) {
+    TEST(vertexCount) {
+        iterateAllIndexesAtRes(0, loop_assertions);
+        iterateAllIndexesAtRes(1, loop_assertions);
+    }
+
+    TEST(invalidCell) {
+        LinkedGeoLoop loop;
+        t_assert(cellToLinkedGeoLoop(0x7fffffffffffffff, &loop) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
@@ -0,0 +1,50 @@
+/** @file
+ * @brief tests H3 function `cellToNeighborEdges`
+ *
+ *  usage: `testCellToNeighborEdges`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void assertNeighborEdges(H3Index origin, int expectedCount) {
+    H3Index neighbors[6] = {0};
+    H3Index edges[6] = {0};
+    int count;
+    t_assertSuccess(cellToNeighborEdges(origin, neighbors, edges, &count));
+    t_assert(count == expectedCount, "got expected neighbor count");
+
+    for (int i = 0; i < count; i++) {
+        int isNeighbor;
+        t_assertSuccess(areNeighborCells(origin, neighbors[i], &isNeighbor));
+        t_assert(isNeighbor, "neighbor is a neighbor of the origin");
+
+        H3Index edge;
+        t_assertSuccess(cellsToDirectedEdge(origin, neighbors[i], &edge));
+        t_assert(edge == edges[i], "edge matches its neighbor");
+    }
+}
+
+SUITE(cellToNeighborEdges) {
+    TEST(hexagon) {
+        H3Index sf = 0x8928308280fffff;
+        assertNeighborEdges(sf, 6);
+    }
+
+    TEST(pentagon) {
+        H3Index pentagon = 0x821c07fffffffff;
+        t_assert(isPentagon(pentagon), "test cell is a pentagon");
+        assertNeighborEdges(pentagon, 5);
+    }
+
+    TEST(invalidCell) {
+        H3Index neighbors[6] = {0};
+        H3Index edges[6] = {0};
+        int count;
+        t_assert(cellToNeighborEdges(0x7fffffffffffffff, neighbors, edges,
+                                     &count) == E_CELL_INVALID,
+                 "invalid origin is rejected");
+    }
+}
@@ -0,0 +1,69 @@
+/** @file
+ * @brief tests H3 functions `cellToPentagonChildren` and
+ * `cellToHexagonChildren`
+ *
+ *  usage: `testCellToPentagonChildren`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define PENTAGON 0x8009fffffffffff
+#define HEXAGON 0x8001fffffffffff
+
+SUITE(cellToPentagonChildren) {
+    TEST(pentagonSubtree) {
+        H3Index children[1] = {0};
+        int64_t count = -1;
+        H3Index center = 0;
+        t_assertSuccess(cellToCenterChild(PENTAGON, 2, &center));
+        t_assertSuccess(cellToPentagonChildren(PENTAGON, 2, children, &count));
+        t_assert(count == 1, "one pentagon child");
+        t_assert(children[0] == center, "pentagon child is the center child");
+        t_assert(isPentagon(children[0]), "child is a pentagon");
+    }
+
+    TEST(hexagonSubtree) {
+        H3Index children[1] = {0};
+        int64_t count = -1;
+        t_assertSuccess(cellToPentagonChildren(HEXAGON, 2, children, &count));
+        t_assert(count == 0, "no pentagon child under a hexagon");
+    }
+
+    TEST(hexagonChildrenOfPentagon) {
+        int64_t size = 0;
+        t_assertSuccess(cellToChildrenSize(PENTAGON, 2, &size));
+        H3Index *children = calloc(size, sizeof(H3Index));
+        int64_t count = -1;
+        t_assertSuccess(cellToHexagonChildren(PENTAGON, 2, children, &count));
+        t_assert(count == size - 1, "all children but the center one");
+        for (int64_t i = 0; i < count; i++) {
+            t_assert(isValidCell(children[i]), "child is valid");
+            t_assert(!isPentagon(children[i]), "child is a hexagon");
+        }
+        free(children);
+    }
+
+    TEST(hexagonChildrenOfHexagon) {
+        int64_t size = 0;
+        t_assertSuccess(cellToChildrenSize(HEXAGON, 2, &size));
+        H3Index *children = calloc(size, sizeof(H3Index));
+        int64_t count = -1;
+        t_assertSuccess(cellToHexagonChildren(HEXAGON, 2, children, &count));
+        t_assert(count == size, "every child is a hexagon");
+        free(children);
+    }
+
+    TEST(invalid) {
+        H3Index children[1] = {0};
+        int64_t count = -1;
+        t_assert(cellToPentagonChildren(0, 2, children, &count) ==
+                     E_CELL_INVALID,
+                 "invalid cell fails");
+        t_assert(cellToHexagonChildren(PENTAGON, 16, children, &count) ==
+                     E_RES_DOMAIN,
+                 "invalid resolution fails");
+    }
+}
@@ -0,0 +1,88 @@
+/** @file
+ * @brief tests H3 function `cellToVertexByDirection`
+ *
+ *  usage: `testCellToVertexByDirection`
+ */
+
+#include <stdlib.h>
+
+#include "h3Index.h"
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void sharedVertex_assertions(H3Index h3) {
+    H3Index neighbors[6] = {0};
+    H3Index edges[6] = {0};
+    int count;
+    t_assertSuccess(cellToNeighborEdges(h3, neighbors, edges, &count));
+
+    for (int i = 0; i < count; i++) {
+        // Edge direction are numbered from 1 to 6.
+        int direction = H3_GET_RESERVED_BITS(edges[i]) - 1;
+        H3Index vertex;
+        t_assertSuccess(cellToVertexByDirection(h3, direction, &vertex));
+        t_assert(isValidVertex(vertex), "vertex is valid");
+
+        // The neighbor must see the same vertex from one of its directions.
+        int found = 0;
+        for (int j = 0; j < 6; j++) {
+            H3Index other;
+            if (cellToVertexByDirection(neighbors[i], j, &other) ==
+                    E_SUCCESS &&
+                other == vertex) {
+                found++;
+            }
+        }
+        t_assert(found == 1, "neighbor shares the vertex");
+    }
+}
+
+SUITE(cellToVertexByDirection) {
+    TEST(sharedVertex) {
+        iterateAllIndexesAtRes(0, sharedVertex_assertions);
+        iterateAllIndexesAtRes(1, sharedVertex_assertions);
+    }
+
+    TEST(directionMatchesEdgeBoundary) {
+        H3Index origin = 0x8928308280fffff;
+        H3Index edges[6] = {0};
+        t_assertSuccess(originToDirectedEdges(origin, edges));
+
+        for (int i = 0; i < 6; i++) {
+            int direction = H3_GET_RESERVED_BITS(edges[i]) - 1;
+            H3Index vertex;
+            t_assertSuccess(cellToVertexByDirection(origin, direction, &vertex));
+
+            CellBoundary edgeBoundary;
+            t_assertSuccess(directedEdgeToBoundary(edges[i], &edgeBoundary));
+            LatLng coord;
+            t_assertSuccess(vertexToLatLng(vertex, &coord));
+            t_assert(geoAlmostEqual(&coord, &edgeBoundary.verts[0]),
+                     "vertex is the start of the edge");
+        }
+    }
+
+    TEST(invalidDirection) {
+        H3Index origin = 0x8928308280fffff;
+        H3Index vertex;
+        t_assert(cellToVertexByDirection(origin, -1, &vertex) == E_DOMAIN,
+                 "negative direction is rejected");
+        t_assert(cellToVertexByDirection(origin, 6, &vertex) == E_DOMAIN,
+                 "direction too large is rejected");
+    }
+
+    TEST(pentagonDeletedDirection) {
+        H3Index pentagon = 0x821c07fffffffff;
+        H3Index vertex;
+        t_assert(cellToVertexByDirection(pentagon, 0, &vertex) == E_PENTAGON,
+                 "deleted K axis of a pentagon is rejected");
+    }
+
+    TEST(invalidCell) {
+        H3Index vertex;
+        t_assert(cellToVertexByDirection(0x7fffffffffffffff, 1, &vertex) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
@@ -0,0 +1,47 @@
+/** @file
+ * @brief tests H3 function `cellToVertexesWithCoords`
+ *
+ *  usage: `testCellToVertexesWithCoords`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void cellToVertexesWithCoords_assertions(H3Index cell) {
+    H3Index vertexes[6] = {0};
+    H3IndexLatLng out[6];
+    int count = -1;
+    t_assertSuccess(cellToVertexes(cell, vertexes));
+    t_assertSuccess(cellToVertexesWithCoords(cell, out, &count));
+    t_assert(count == (isPentagon(cell) ? 5 : 6), "got expected count");
+
+    for (int i = 0; i < count; i++) {
+        t_assert(out[i].vertex == vertexes[i], "vertex matches");
+
+        LatLng coord;
+        t_assertSuccess(vertexToLatLng(vertexes[i], &coord));
+        t_assert(fabs(out[i].coord.lat - radsToDegs(coord.lat)) < 1e-9,
+                 "latitude matches, in degrees");
+        t_assert(fabs(out[i].coord.lng - radsToDegs(coord.lng)) < 1e-9,
+                 "longitude matches, in degrees");
+    }
+}
+
+SUITE(cellToVertexesWithCoords) {
+    TEST(allCells) {
+        iterateAllIndexesAtRes(0, cellToVertexesWithCoords_assertions);
+        iterateAllIndexesAtRes(1, cellToVertexesWithCoords_assertions);
+    }
+
+    TEST(invalidCell) {
+        H3IndexLatLng out[6];
+        int count;
+        t_assert(cellToVertexesWithCoords(0x7fffffffffffffff, out, &count) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
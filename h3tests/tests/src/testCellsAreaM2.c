@@ -0,0 +1,54 @@
+/** @file
+ * @brief tests H3 function `cellsAreaM2`
+ *
+ *  usage: `testCellsAreaM2`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsAreaM2) {
+    TEST(siblingsMatchParent) {
+        H3Index parent = 0x88283080ddfffff;
+        H3Index children[7];
+        t_assertSuccess(cellToChildren(parent, 9, children, 7));
+
+        double parentArea, childrenArea;
+        int64_t skipped = -1;
+        t_assertSuccess(cellAreaM2(parent, &parentArea));
+        t_assertSuccess(cellsAreaM2(children, 7, &childrenArea, &skipped));
+        t_assert(skipped == 0, "no cell skipped");
+        t_assert(fabs(childrenArea - parentArea) / parentArea < 1e-2,
+                 "children area matches parent area");
+    }
+
+    TEST(skipsInvalidCells) {
+        H3Index cells[] = {0x88283080ddfffff, 0x7fffffffffffffff, H3_NULL};
+        double expected, area;
+        int64_t skipped;
+        t_assertSuccess(cellAreaM2(cells[0], &expected));
+        t_assertSuccess(cellsAreaM2(cells, 3, &area, &skipped));
+        t_assert(area == expected, "only the valid cell is summed");
+        t_assert(skipped == 2, "invalid cells are counted as skipped");
+
+        t_assertSuccess(cellsAreaM2(cells, 3, &area, NULL));
+        t_assert(area == expected, "skipped count is optional");
+    }
+
+    TEST(empty) {
+        double area = -1;
+        int64_t skipped = -1;
+        t_assertSuccess(cellsAreaM2(NULL, 0, &area, &skipped));
+        t_assert(area == 0, "empty set has no area");
+        t_assert(skipped == 0, "empty set has nothing to skip");
+    }
+
+    TEST(negativeSize) {
+        double area;
+        t_assert(cellsAreaM2(NULL, -1, &area, NULL) == E_DOMAIN,
+                 "negative size is rejected");
+    }
+}
@@ -0,0 +1,65 @@
+/** @file
+ * @brief tests H3 function `cellsCentroid`
+ *
+ *  usage: `testCellsCentroid`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+// Check that the centroid of a disk around `center` is in the center cell.
+static void assertDiskCentroid(H3Index center) {
+    H3Index disk[19] = {0};
+    t_assertSuccess(gridDiskUnsafe(center, 2, disk));
+
+    LatLng centroid;
+    int64_t skipped = -1;
+    t_assertSuccess(cellsCentroid(disk, 19, &centroid, &skipped));
+    t_assert(skipped == 0, "no cell skipped");
+
+    LatLng expected;
+    t_assertSuccess(cellToLatLng(center, &expected));
+    LatLng centroidRads = {degsToRads(centroid.lat), degsToRads(centroid.lng)};
+    t_assert(greatCircleDistanceM(&centroidRads, &expected) < 10,
+             "centroid is the center of the center cell");
+}
+
+SUITE(cellsCentroid) {
+    TEST(symmetricCluster) {
+        assertDiskCentroid(0x89283080ddbffff);
+    }
+
+    TEST(antimeridian) {
+        LatLng antimeridian = {0, M_PI};
+        H3Index center;
+        t_assertSuccess(latLngToCell(&antimeridian, 7, &center));
+        assertDiskCentroid(center);
+    }
+
+    TEST(skipsInvalidCells) {
+        H3Index cells[] = {0x89283080ddbffff, 0x7fffffffffffffff};
+        LatLng centroid;
+        int64_t skipped = -1;
+        t_assertSuccess(cellsCentroid(cells, 2, &centroid, &skipped));
+        t_assert(skipped == 1, "invalid cell is skipped");
+
+        LatLng expected;
+        t_assertSuccess(cellToLatLng(cells[0], &expected));
+        t_assert(fabs(degsToRads(centroid.lat) - expected.lat) < 1e-9,
+                 "centroid of a single cell is its center");
+        t_assert(fabs(degsToRads(centroid.lng) - expected.lng) < 1e-9,
+                 "centroid of a single cell is its center");
+    }
+
+    TEST(noValidCell) {
+        H3Index cells[] = {0x7fffffffffffffff};
+        LatLng centroid;
+        t_assert(cellsCentroid(cells, 1, &centroid, NULL) == E_DOMAIN,
+                 "no valid cell is rejected");
+        t_assert(cellsCentroid(NULL, 0, &centroid, NULL) == E_DOMAIN,
+                 "empty set is rejected");
+    }
+}
@@ -0,0 +1,47 @@
+/** @file
+ * @brief tests H3 function `cellsOutlinePerimeterM`
+ *
+ *  usage: `testCellsOutlinePerimeterM`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsOutlinePerimeterM) {
+    TEST(singleCell) {
+        H3Index cells[] = {0x89283082837ffff, 0x8009fffffffffff,
+                           0x85283473fffffff};
+        for (int i = 0; i < 3; i++) {
+            double outline = 0;
+            double perimeter = 0;
+            t_assertSuccess(cellsOutlinePerimeterM(&cells[i], 1, &outline));
+            t_assertSuccess(cellPerimeterM(cells[i], &perimeter));
+            t_assert(fabs(outline - perimeter) < 1e-6 * perimeter,
+                     "outline of a cell is its perimeter");
+        }
+    }
+
+    TEST(disk) {
+        H3Index disk[7] = {0};
+        double outline = 0;
+        double sum = 0;
+        t_assertSuccess(gridDisk(0x89283082837ffff, 1, disk));
+        t_assertSuccess(cellsOutlinePerimeterM(disk, 7, &outline));
+        for (int i = 0; i < 7; i++) {
+            double perimeter = 0;
+            t_assertSuccess(cellPerimeterM(disk[i], &perimeter));
+            sum += perimeter;
+        }
+        t_assert(outline > 0 && outline < sum,
+                 "shared edges are not part of the outline");
+    }
+
+    TEST(empty) {
+        double outline = -1;
+        t_assertSuccess(cellsOutlinePerimeterM(NULL, 0, &outline));
+        t_assert(outline == 0, "empty set has no perimeter");
+    }
+}
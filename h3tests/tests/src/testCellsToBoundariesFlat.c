@@ -0,0 +1,62 @@
+/** @file
+ * @brief tests H3 function `cellsToBoundariesFlat`
+ *
+ *  usage: `testCellsToBoundariesFlat`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsToBoundariesFlat) {
+    H3Index cells[] = {0x85283473fffffff, 0x8009fffffffffff,
+                       0x81083ffffffffff};
+
+    TEST(reconstruct) {
+        double coords[3 * 2 * MAX_CELL_BNDRY_VERTS] = {0};
+        int vertCounts[3] = {0};
+        t_assertSuccess(cellsToBoundariesFlat(
+            cells, 3, coords, 3 * 2 * MAX_CELL_BNDRY_VERTS, vertCounts));
+
+        int offset = 0;
+        for (int i = 0; i < 3; i++) {
+            CellBoundary boundary = {0};
+            t_assertSuccess(cellToBoundary(cells[i], &boundary));
+            t_assert(vertCounts[i] == boundary.numVerts,
+                     "vertex count matches");
+            for (int v = 0; v < boundary.numVerts; v++) {
+                t_assert(coords[offset] ==
+                             radsToDegs(boundary.verts[v].lat),
+                         "latitude matches");
+                t_assert(coords[offset + 1] ==
+                             radsToDegs(boundary.verts[v].lng),
+                         "longitude matches");
+                offset += 2;
+            }
+        }
+    }
+
+    TEST(tooSmall) {
+        double coords[2 * MAX_CELL_BNDRY_VERTS] = {0};
+        int vertCounts[3] = {0};
+        t_assert(cellsToBoundariesFlat(cells, 3, coords,
+                                       2 * MAX_CELL_BNDRY_VERTS,
+                                       vertCounts) == E_MEMORY_BOUNDS,
+                 "small buffer fails");
+    }
+
+    TEST(invalid) {
+        H3Index invalid[] = {0x85283473fffffff, 0};
+        double coords[2 * 2 * MAX_CELL_BNDRY_VERTS] = {0};
+        int vertCounts[2] = {0};
+        t_assert(cellsToBoundariesFlat(invalid, 2, coords,
+                                       2 * 2 * MAX_CELL_BNDRY_VERTS,
+                                       vertCounts) == E_CELL_INVALID,
+                 "invalid cell fails");
+        t_assert(cellsToBoundariesFlat(cells, -1, coords, 0, vertCounts) ==
+                     E_DOMAIN,
+                 "negative size fails");
+        t_assertSuccess(cellsToBoundariesFlat(NULL, 0, NULL, 0, NULL));
+    }
+}
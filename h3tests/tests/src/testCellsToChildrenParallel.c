@@ -0,0 +1,66 @@
+/** @file
+ * @brief tests H3 function `cellsToChildrenParallel`
+ *
+ *  usage: `testCellsToChildrenParallel`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsToChildrenParallel) {
+    // A hexagon and a pentagon.
+    H3Index parents[2] = {0x85283473fffffff, 0x85080003fffffff};
+
+    TEST(matchesSequential) {
+        int childRes = 9;
+        int64_t sizes[2];
+        int64_t offsets[2];
+        int64_t total = 0;
+        for (int i = 0; i < 2; i++) {
+            t_assertSuccess(
+                cellToChildrenSize(parents[i], childRes, &sizes[i]));
+            offsets[i] = total;
+            total += sizes[i];
+        }
+
+        H3Index *parallel = calloc(total, sizeof(H3Index));
+        H3Index *sequential = calloc(total, sizeof(H3Index));
+        t_assertSuccess(
+            cellsToChildrenParallel(parents, 2, childRes, parallel, offsets));
+        for (int i = 0; i < 2; i++) {
+            t_assertSuccess(cellToChildren(parents[i], childRes,
+                                           sequential + offsets[i], sizes[i]));
+        }
+        for (int64_t i = 0; i < total; i++) {
+            t_assert(parallel[i] == sequential[i], "same as sequential fill");
+        }
+        free(sequential);
+        free(parallel);
+    }
+
+    TEST(overlappingOffsets) {
+        H3Index out[14] = {0};
+        int64_t offsets[2] = {0, 6};
+        t_assert(cellsToChildrenParallel(parents, 2, 6, out, offsets) ==
+                     E_DOMAIN,
+                 "children would overlap");
+    }
+
+    TEST(empty) {
+        t_assertSuccess(cellsToChildrenParallel(NULL, 0, 9, NULL, NULL));
+    }
+
+    TEST(invalid) {
+        H3Index out[14] = {0};
+        int64_t offsets[2] = {0, 7};
+        t_assert(cellsToChildrenParallel(parents, 2, 16, out, offsets) ==
+                     E_RES_DOMAIN,
+                 "invalid resolution");
+        H3Index invalid[2] = {parents[0], 0};
+        t_assert(cellsToChildrenParallel(invalid, 2, 6, out, offsets) ==
+                     E_CELL_INVALID,
+                 "invalid parent");
+    }
+}
@@ -0,0 +1,89 @@
+/** @file
+ * @brief tests H3 function `cellsToMesh`
+ *
+ *  usage: `testCellsToMesh`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsToMesh) {
+    TEST(adjacentCells) {
+        H3Index cells[] = {0x8928308280fffff, 0x8928308280bffff};
+        LatLng vertices[12] = {{0}};
+        int indices[14] = {0};
+        int vertCount = 0;
+        int idxCount = 0;
+        t_assertSuccess(cellsToMesh(cells, 2, vertices, 12, &vertCount,
+                                    indices, 14, &idxCount));
+        t_assert(vertCount == 10, "shared edge vertices appear once");
+        t_assert(idxCount == 14, "two hexagons described");
+        t_assert(indices[0] == 6 && indices[7] == 6, "polygon sizes");
+
+        int shared = 0;
+        for (int i = 1; i < 7; i++) {
+            for (int j = 8; j < 14; j++) {
+                shared += indices[i] == indices[j];
+            }
+        }
+        t_assert(shared == 2, "shared edge vertices are referenced twice");
+
+        // Each polygon matches the cell vertexes.
+        for (int c = 0; c < 2; c++) {
+            for (int i = 0; i < 6; i++) {
+                H3Index vertex;
+                LatLng expected;
+                t_assertSuccess(cellToVertex(cells[c], i, &vertex));
+                t_assertSuccess(vertexToLatLng(vertex, &expected));
+                LatLng got = vertices[indices[c * 7 + 1 + i]];
+                t_assert(fabs(radsToDegs(expected.lat) - got.lat) < 1e-9 &&
+                             fabs(radsToDegs(expected.lng) - got.lng) < 1e-9,
+                         "vertex coordinates in degrees");
+            }
+        }
+    }
+
+    TEST(pentagon) {
+        H3Index cells[] = {0x8009fffffffffff};
+        LatLng vertices[5] = {{0}};
+        int indices[6] = {0};
+        int vertCount = 0;
+        int idxCount = 0;
+        t_assertSuccess(cellsToMesh(cells, 1, vertices, 5, &vertCount,
+                                    indices, 6, &idxCount));
+        t_assert(vertCount == 5 && idxCount == 6, "pentagon has 5 vertices");
+        t_assert(indices[0] == 5, "pentagon polygon size");
+    }
+
+    TEST(empty) {
+        int vertCount = -1;
+        int idxCount = -1;
+        t_assertSuccess(
+            cellsToMesh(NULL, 0, NULL, 0, &vertCount, NULL, 0, &idxCount));
+        t_assert(vertCount == 0 && idxCount == 0, "empty mesh");
+    }
+
+    TEST(memoryBounds) {
+        H3Index cells[] = {0x8928308280fffff, 0x8928308280bffff};
+        LatLng vertices[12] = {{0}};
+        int indices[14] = {0};
+        t_assert(cellsToMesh(cells, 2, vertices, 9, NULL, indices, 14,
+                             NULL) == E_MEMORY_BOUNDS,
+                 "vertex buffer too small");
+        t_assert(cellsToMesh(cells, 2, vertices, 12, NULL, indices, 13,
+                             NULL) == E_MEMORY_BOUNDS,
+                 "index buffer too small");
+    }
+
+    TEST(invalidCell) {
+        H3Index cells[] = {0x8928308280fffff, 0x7fffffffffffffff};
+        LatLng vertices[12] = {{0}};
+        int indices[14] = {0};
+        t_assert(cellsToMesh(cells, 2, vertices, 12, NULL, indices, 14,
+                             NULL) == E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
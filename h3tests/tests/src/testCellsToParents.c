@@ -0,0 +1,46 @@
+/** @file
+ * @brief tests H3 function `cellsToParents`
+ *
+ *  usage: `testCellsToParents`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsToParents) {
+    TEST(mixedBatch) {
+        H3Index cells[] = {
+            0x89283080ddbffff,   // res 9
+            0x88283080ddfffff,   // res 8
+            0x85283473fffffff,   // res 5, coarser than the parents
+            0x7fffffffffffffff,  // invalid
+            0x821c07fffffffff,   // res 2 pentagon, coarser than the parents
+            0x8928308280fffff,   // res 9
+        };
+        H3Index parents[6];
+        t_assertSuccess(cellsToParents(cells, 6, 7, parents));
+
+        for (int i = 0; i < 6; i++) {
+            H3Index expected;
+            if (cellToParent(cells[i], 7, &expected) != E_SUCCESS) {
+                expected = H3_NULL;
+            }
+            t_assert(parents[i] == expected, "parent matches cellToParent");
+        }
+        t_assert(parents[2] == H3_NULL, "coarser cell has no parent");
+        t_assert(parents[3] == H3_NULL, "invalid cell has no parent");
+        t_assert(parents[0] != H3_NULL, "finer cell has a parent");
+    }
+
+    TEST(invalidArgs) {
+        H3Index cells[] = {0x89283080ddbffff};
+        H3Index parents[1];
+        t_assert(cellsToParents(cells, 1, 16, parents) == E_RES_DOMAIN,
+                 "invalid resolution is rejected");
+        t_assert(cellsToParents(cells, -1, 7, parents) == E_DOMAIN,
+                 "negative size is rejected");
+        t_assertSuccess(cellsToParents(NULL, 0, 7, NULL));
+    }
+}
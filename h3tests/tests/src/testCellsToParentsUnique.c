@@ -0,0 +1,58 @@
+/** @file
+ * @brief tests H3 function `cellsToParentsUnique`
+ *
+ *  usage: `testCellsToParentsUnique`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(cellsToParentsUnique) {
+    TEST(siblingsCollapse) {
+        H3Index parent = 0x88283080ddfffff;
+        int64_t numChildren;
+        t_assertSuccess(cellToChildrenSize(parent, 10, &numChildren));
+        H3Index *children = calloc(numChildren, sizeof(H3Index));
+        t_assertSuccess(cellToChildren(parent, 10, children, numChildren));
+
+        H3Index *parents = calloc(numChildren, sizeof(H3Index));
+        int64_t count = -1;
+        t_assertSuccess(
+            cellsToParentsUnique(children, numChildren, 8, parents, &count));
+        t_assert(count == 1, "siblings collapse to a single parent");
+        t_assert(parents[0] == parent, "got the expected parent");
+
+        t_assertSuccess(
+            cellsToParentsUnique(children, numChildren, 9, parents, &count));
+        t_assert(count == 7, "got the intermediate parents");
+
+        free(parents);
+        free(children);
+    }
+
+    TEST(skipsInvalidCells) {
+        H3Index cells[] = {0x89283080ddbffff, 0x7fffffffffffffff,
+                           0x85283473fffffff, 0x89283080ddbffff};
+        H3Index parents[4] = {0};
+        int64_t count = -1;
+        t_assertSuccess(cellsToParentsUnique(cells, 4, 7, parents, &count));
+        t_assert(count == 1, "only one valid parent");
+        H3Index expected;
+        t_assertSuccess(cellToParent(cells[0], 7, &expected));
+        t_assert(parents[0] == expected, "got the expected parent");
+    }
+
+    TEST(invalidArgs) {
+        H3Index cells[] = {0x89283080ddbffff};
+        H3Index parents[1];
+        int64_t count;
+        t_assert(cellsToParentsUnique(cells, 1, -1, parents, &count) ==
+                     E_RES_DOMAIN,
+                 "invalid resolution is rejected");
+        t_assert(cellsToParentsUnique(cells, -1, 7, parents, &count) ==
+                     E_DOMAIN,
+                 "negative size is rejected");
+    }
+}
@@ -0,0 +1,80 @@
+/** @file
+ * @brief tests H3 function `cellsToSimplifiedPolygon`
+ *
+ *  usage: `testCellsToSimplifiedPolygon`
+ */
+
+#include <stdlib.h>
+#include <string.h>
+
+#include "h3api.h"
+#include "test.h"
+
+/** Counts the coordinates of a GeoJSON geometry. */
+static int countVertexes(const char *geojson) {
+    int count = 0;
+    for (const char *c = geojson; *c != '\0'; c++) {
+        if (c[0] == '[' && c[1] != '[' && c[1] != ']') {
+            count++;
+        }
+    }
+    return count;
+}
+
+static char *simplify(const H3Index *cells, int n, double tolerance) {
+    size_t needed;
+    t_assert(cellsToSimplifiedPolygon(cells, n, tolerance, NULL, 0,
+                                      &needed) == E_MEMORY_BOUNDS,
+             "empty buffer is too small");
+    char *geojson = calloc(needed, sizeof(char));
+    t_assertSuccess(
+        cellsToSimplifiedPolygon(cells, n, tolerance, geojson, needed, NULL));
+    t_assert(strlen(geojson) + 1 == needed, "reported size is exact");
+    return geojson;
+}
+
+SUITE(cellsToSimplifiedPolygon) {
+    H3Index sf = 0x8928308280fffff;
+    int64_t diskSize;
+    t_assertSuccess(maxGridDiskSize(10, &diskSize));
+    H3Index *disk = calloc(diskSize, sizeof(H3Index));
+    t_assertSuccess(gridDisk(sf, 10, disk));
+
+    TEST(reducesVertexCount) {
+        char *exact = simplify(disk, diskSize, 0);
+        char *simplified = simplify(disk, diskSize, 0.001);
+
+        t_assert(strstr(exact, "\"MultiPolygon\"") != NULL,
+                 "output is a GeoJSON MultiPolygon");
+        t_assert(countVertexes(simplified) < countVertexes(exact),
+                 "tolerance reduces the vertex count");
+        t_assert(countVertexes(simplified) >= 4, "outline is still a ring");
+
+        free(simplified);
+        free(exact);
+    }
+
+    TEST(singleCell) {
+        char *exact = simplify(&sf, 1, 0);
+        // A hexagon ring: 6 vertexes, plus the closing one.
+        t_assert(countVertexes(exact) == 7, "exact outline of a cell");
+        char *simplified = simplify(&sf, 1, 1);
+        t_assert(countVertexes(simplified) == 7,
+                 "ring isn't collapsed by a huge tolerance");
+        free(simplified);
+        free(exact);
+    }
+
+    TEST(invalid) {
+        char buf[64];
+        t_assert(cellsToSimplifiedPolygon(disk, diskSize, -1, buf,
+                                          sizeof(buf), NULL) == E_DOMAIN,
+                 "negative tolerance is rejected");
+        H3Index invalid = 0x7fffffffffffffff;
+        t_assert(cellsToSimplifiedPolygon(&invalid, 1, 0, buf, sizeof(buf),
+                                          NULL) == E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+
+    free(disk);
+}
@@ -0,0 +1,106 @@
+/** @file
+ * @brief tests H3 functions `cellsToChildBitset` and `childBitsetToCells`
+ *
+ *  usage: `testChildBitset`
+ */
+
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void assertRoundTrip(H3Index parent, int childRes) {
+    int64_t size;
+    t_assertSuccess(cellToChildrenSize(parent, childRes, &size));
+    H3Index *children = calloc(size, sizeof(H3Index));
+    t_assertSuccess(cellToChildren(parent, childRes, children, size));
+
+    size_t bitsetCap = (size + 7) / 8;
+    uint8_t *bitset = calloc(bitsetCap, 1);
+    t_assertSuccess(cellsToChildBitset(parent, childRes, children, size,
+                                       bitset, bitsetCap));
+    for (int64_t i = 0; i < size; i++) {
+        t_assert(bitset[i / 8] & (1 << (i % 8)), "child bit is set");
+    }
+
+    H3Index *decoded = calloc(size, sizeof(H3Index));
+    int64_t count = 0;
+    t_assertSuccess(childBitsetToCells(parent, childRes, bitset, bitsetCap,
+                                       decoded, size, &count));
+    t_assert(count == size, "every child is decoded");
+    t_assert(memcmp(children, decoded, size * sizeof(H3Index)) == 0,
+             "children are decoded in order");
+
+    free(decoded);
+    free(bitset);
+    free(children);
+}
+
+SUITE(childBitset) {
+    H3Index parent = 0x872830828ffffff;
+
+    TEST(roundTrip) {
+        assertRoundTrip(parent, 7);
+        assertRoundTrip(parent, 9);
+        assertRoundTrip(0x85080003fffffff, 7);  // Pentagon.
+    }
+
+    TEST(subset) {
+        H3Index children[49];
+        t_assertSuccess(cellToChildren(parent, 9, children, 49));
+        H3Index cells[3] = {children[40], children[2], children[40]};
+        uint8_t bitset[7];
+        memset(bitset, 0xff, sizeof(bitset));
+        t_assertSuccess(
+            cellsToChildBitset(parent, 9, cells, 3, bitset, sizeof(bitset)));
+        t_assert(bitset[0] == 0x04 && bitset[5] == 0x01, "positions set");
+
+        H3Index out[2];
+        int64_t count = 0;
+        t_assertSuccess(childBitsetToCells(parent, 9, bitset, sizeof(bitset),
+                                           out, 2, &count));
+        t_assert(count == 2, "duplicates are ignored");
+        t_assert(out[0] == children[2] && out[1] == children[40],
+                 "decoded in position order");
+
+        t_assert(childBitsetToCells(parent, 9, bitset, sizeof(bitset), out,
+                                    1, &count) == E_MEMORY_BOUNDS,
+                 "output too small");
+    }
+
+    TEST(invalid) {
+        H3Index children[49];
+        t_assertSuccess(cellToChildren(parent, 9, children, 49));
+        uint8_t bitset[7];
+        t_assert(cellsToChildBitset(parent, 9, children, 49, bitset, 6) ==
+                     E_MEMORY_BOUNDS,
+                 "bitset too small");
+        t_assert(cellsToChildBitset(parent, 6, children, 49, bitset, 7) ==
+                     E_RES_MISMATCH,
+                 "child resolution coarser than the parent");
+        t_assert(cellsToChildBitset(parent, 9, children, -1, bitset, 7) ==
+                     E_DOMAIN,
+                 "negative size");
+
+        H3Index neighbors[6];
+        t_assertSuccess(gridRingUnsafe(parent, 1, neighbors));
+        H3Index outside;
+        t_assertSuccess(cellToCenterChild(neighbors[0], 9, &outside));
+        t_assert(cellsToChildBitset(parent, 9, &outside, 1, bitset, 7) ==
+                     E_RES_MISMATCH,
+                 "cell outside the parent");
+        t_assert(cellsToChildBitset(parent, 9, &neighbors[0], 1, bitset, 7) ==
+                     E_RES_MISMATCH,
+                 "cell at another resolution");
+
+        memset(bitset, 0, sizeof(bitset));
+        bitset[6] = 0x02;  // Position 49, past the last child.
+        H3Index out[49];
+        int64_t count;
+        t_assert(childBitsetToCells(parent, 9, bitset, 7, out, 49, &count) ==
+                     E_DOMAIN,
+                 "bit past the last child");
+    }
+}
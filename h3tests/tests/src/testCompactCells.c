@@ -78,7 +78,8 @@ SUITE(compactCells) {
         t_assertSuccess(H3_EXPORT(cellToChildrenSize)(parent, 1, &arrSize));
 
         H3Index *children = calloc(arrSize, sizeof(H3Index));
-        t_assertSuccess(H3_EXPORT(cellToChildren)(parent, 1, children));
+        t_assertSuccess(
+            H3_EXPORT(cellToChildren)(parent, 1, children, arrSize));
 
         H3Index *compressed = calloc(arrSize, sizeof(H3Index));
         t_assertSuccess(H3_EXPORT(compactCells(children, compressed, arrSize)));
@@ -190,7 +191,8 @@ SUITE(compactCells) {
         arrSize++;
         H3Index *children = calloc(arrSize, sizeof(H3Index));
 
-        t_assertSuccess(H3_EXPORT(cellToChildren)(h3, res + 1, children));
+        t_assertSuccess(
+            H3_EXPORT(cellToChildren)(h3, res + 1, children, arrSize));
         // duplicate one index
         children[arrSize - 1] = children[0];
 
@@ -217,7 +219,8 @@ SUITE(compactCells) {
         arrSize++;
         H3Index *children = calloc(arrSize, sizeof(H3Index));
 
-        t_assertSuccess(H3_EXPORT(cellToChildren)(h3, res + 1, children));
+        t_assertSuccess(
+            H3_EXPORT(cellToChildren)(h3, res + 1, children, arrSize));
         // duplicate one index
         t_assertSuccess(
             H3_EXPORT(cellToCenterChild)(h3, res + 1, &children[arrSize - 1]));
@@ -246,7 +249,8 @@ SUITE(compactCells) {
         t_assertSuccess(H3_EXPORT(cellToChildrenSize)(h3, res + 1, &arrSize));
         H3Index *children = calloc(arrSize, sizeof(H3Index));
 
-        t_assertSuccess(H3_EXPORT(cellToChildren)(h3, res + 1, children));
+        t_assertSuccess(
+            H3_EXPORT(cellToChildren)(h3, res + 1, children, arrSize));
         // duplicate one index
         children[arrSize - 1] = children[0];
 
@@ -0,0 +1,54 @@
+/** @file
+ * @brief tests H3 function `compactCellsWithGroups`
+ *
+ *  usage: `testCompactCellsWithGroups`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define PARENT 0x85283473fffffff
+
+SUITE(compactCellsWithGroups) {
+    TEST(siblings) {
+        H3Index cells[7] = {0};
+        H3Index compacted[7] = {0};
+        int groupOf[7] = {-1, -1, -1, -1, -1, -1, -1};
+        int64_t count = -1;
+        t_assertSuccess(cellToChildren(PARENT, 6, cells, 7));
+        t_assertSuccess(
+            compactCellsWithGroups(cells, 7, compacted, groupOf, &count));
+        t_assert(count == 1, "siblings compact into one cell");
+        t_assert(compacted[0] == PARENT, "compacted into the parent");
+        for (int i = 0; i < 7; i++) {
+            t_assert(groupOf[i] == 0, "sibling maps to group 0");
+        }
+    }
+
+    TEST(mixed) {
+        // Seven siblings plus a lone cell from another parent.
+        H3Index cells[8] = {0};
+        H3Index compacted[8] = {0};
+        int groupOf[8] = {0};
+        int64_t count = -1;
+        t_assertSuccess(cellToChildren(PARENT, 6, cells, 7));
+        t_assertSuccess(cellToCenterChild(0x85283477fffffff, 6, &cells[7]));
+        t_assertSuccess(
+            compactCellsWithGroups(cells, 8, compacted, groupOf, &count));
+        t_assert(count == 2, "two compacted cells");
+        for (int i = 0; i < 7; i++) {
+            t_assert(compacted[groupOf[i]] == PARENT,
+                     "siblings map to their parent");
+        }
+        t_assert(compacted[groupOf[7]] == cells[7],
+                 "lone cell maps to itself");
+    }
+
+    TEST(empty) {
+        int64_t count = -1;
+        t_assertSuccess(compactCellsWithGroups(NULL, 0, NULL, NULL, &count));
+        t_assert(count == 0, "empty set");
+    }
+}
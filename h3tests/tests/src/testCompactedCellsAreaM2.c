@@ -0,0 +1,51 @@
+/** @file
+ * @brief tests H3 function `compactedCellsAreaM2`
+ *
+ *  usage: `testCompactedCellsAreaM2`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(compactedCellsAreaM2) {
+    TEST(matchesUncompactedSum) {
+        // A res 5 cell, one of its res 6 children and one of its res 7
+        // grand-children, i.e. a mixed-resolution compacted set.
+        H3Index compacted[3] = {0x85283473fffffff};
+        H3Index children[7];
+        t_assertSuccess(cellToChildren(0x8528340bfffffff, 6, children, 7));
+        compacted[1] = children[0];
+        t_assertSuccess(cellToChildren(children[1], 7, children, 7));
+        compacted[2] = children[0];
+
+        int64_t numUncompacted;
+        t_assertSuccess(uncompactCellsSize(compacted, 3, 7, &numUncompacted));
+        H3Index *uncompacted = calloc(numUncompacted, sizeof(H3Index));
+        t_assertSuccess(
+            uncompactCells(compacted, 3, uncompacted, numUncompacted, 7));
+
+        double expected, area;
+        t_assertSuccess(
+            cellsAreaM2(uncompacted, numUncompacted, &expected, NULL));
+        t_assertSuccess(compactedCellsAreaM2(compacted, 3, &area));
+        t_assert(fabs(area - expected) / expected < 1e-2,
+                 "compacted area matches uncompacted area");
+        free(uncompacted);
+    }
+
+    TEST(empty) {
+        double area = -1;
+        t_assertSuccess(compactedCellsAreaM2(NULL, 0, &area));
+        t_assert(area == 0, "empty set has no area");
+    }
+
+    TEST(invalidCell) {
+        H3Index compacted[] = {0x85283473fffffff, 0x7fffffffffffffff};
+        double area;
+        t_assert(compactedCellsAreaM2(compacted, 2, &area) == E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
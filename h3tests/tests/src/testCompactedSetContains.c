@@ -0,0 +1,71 @@
+/** @file
+ * @brief tests H3 function `compactedSetContains`
+ *
+ *  usage: `testCompactedSetContains`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(compactedSetContains) {
+    H3Index sf = 0x8928308280fffff;
+    H3Index sfParent;
+    t_assertSuccess(cellToParent(sf, 7, &sfParent));
+
+    // A full res 7 cell (compacted) plus a single res 9 cell of a neighbor.
+    H3Index neighbors[6];
+    int numNeighbors;
+    t_assertSuccess(gridRing(sfParent, 1, neighbors, &numNeighbors));
+    H3Index lone, sibling;
+    t_assertSuccess(childPosToCell(0, neighbors[0], 9, &lone));
+    t_assertSuccess(childPosToCell(1, neighbors[0], 9, &sibling));
+    H3Index compacted[2] = {sfParent, lone};
+
+    TEST(coveredChild) {
+        int out;
+        t_assertSuccess(compactedSetContains(compacted, 2, sf, &out));
+        t_assert(out == 1, "child of a compacted cell is covered");
+
+        H3Index child;
+        t_assertSuccess(cellToCenterChild(sfParent, 15, &child));
+        t_assertSuccess(compactedSetContains(compacted, 2, child, &out));
+        t_assert(out == 1, "finest descendant is covered");
+    }
+
+    TEST(exactMatch) {
+        int out;
+        t_assertSuccess(compactedSetContains(compacted, 2, sfParent, &out));
+        t_assert(out == 1, "compacted cell itself is covered");
+        t_assertSuccess(compactedSetContains(compacted, 2, lone, &out));
+        t_assert(out == 1, "lone cell itself is covered");
+    }
+
+    TEST(uncovered) {
+        int out;
+        H3Index grandParent;
+        t_assertSuccess(cellToParent(sf, 6, &grandParent));
+        t_assertSuccess(
+            compactedSetContains(compacted, 2, grandParent, &out));
+        t_assert(out == 0, "ancestor of a compacted cell is not covered");
+
+        t_assertSuccess(compactedSetContains(compacted, 2, sibling, &out));
+        t_assert(out == 0, "sibling of the lone cell is not covered");
+
+        t_assertSuccess(compactedSetContains(NULL, 0, sf, &out));
+        t_assert(out == 0, "empty set covers nothing");
+    }
+
+    TEST(invalid) {
+        int out;
+        t_assert(compactedSetContains(compacted, 2, 0x7fffffffffffffff,
+                                      &out) == E_CELL_INVALID,
+                 "invalid target is rejected");
+        H3Index invalid[1] = {0x7fffffffffffffff};
+        t_assert(compactedSetContains(invalid, 1, sf, &out) == E_CELL_INVALID,
+                 "invalid set is rejected");
+        t_assert(compactedSetContains(compacted, -1, sf, &out) == E_DOMAIN,
+                 "negative size is rejected");
+    }
+}
@@ -0,0 +1,69 @@
+/** @file
+ * @brief tests H3 function `digitsToCell`
+ *
+ *  usage: `testDigitsToCell`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void digitsToCell_assertions(H3Index cell) {
+    int digits[15] = {0};
+    int count = -1;
+    H3Index out = 0;
+    t_assertSuccess(cellToDigits(cell, digits, &count));
+    t_assertSuccess(
+        digitsToCell(getBaseCellNumber(cell), digits, count, &out));
+    t_assert(out == cell, "round-trip through cellToDigits");
+}
+
+SUITE(digitsToCell) {
+    TEST(roundTrip) {
+        iterateAllIndexesAtRes(0, digitsToCell_assertions);
+        iterateAllIndexesAtRes(1, digitsToCell_assertions);
+        iterateAllIndexesAtRes(2, digitsToCell_assertions);
+        digitsToCell_assertions(0x85283473fffffff);
+        digitsToCell_assertions(0x8928308280fffff);
+    }
+
+    TEST(knownCell) {
+        int digits[5] = {0, 6, 4, 3, 4};
+        H3Index out = 0;
+        t_assertSuccess(digitsToCell(20, digits, 5, &out));
+        t_assert(out == 0x85283473fffffff, "expected cell");
+    }
+
+    TEST(deletedPentagonSubsequence) {
+        // Base cell 4 is a pentagon: its K axis (digit 1) subsequence is
+        // deleted.
+        int digits[2] = {0, 1};
+        H3Index out = 0;
+        t_assert(digitsToCell(4, digits, 2, &out) == E_CELL_INVALID,
+                 "deleted subsequence");
+        digits[1] = 2;
+        t_assertSuccess(digitsToCell(4, digits, 2, &out));
+        t_assert(isValidCell(out), "other subsequences are valid");
+    }
+
+    TEST(invalid) {
+        int digits[2] = {0, 7};
+        H3Index out;
+        t_assert(digitsToCell(20, digits, 2, &out) == E_DOMAIN,
+                 "digit out of range");
+        digits[1] = -1;
+        t_assert(digitsToCell(20, digits, 2, &out) == E_DOMAIN,
+                 "negative digit");
+        digits[1] = 0;
+        t_assert(digitsToCell(122, digits, 2, &out) == E_DOMAIN,
+                 "base cell out of range");
+        t_assert(digitsToCell(-1, digits, 2, &out) == E_DOMAIN,
+                 "negative base cell");
+        t_assert(digitsToCell(20, digits, 16, &out) == E_RES_DOMAIN,
+                 "too many digits");
+        t_assertSuccess(digitsToCell(20, NULL, 0, &out));
+        t_assert(out == 0x8029fffffffffff, "base cell 20 at res 0");
+    }
+}
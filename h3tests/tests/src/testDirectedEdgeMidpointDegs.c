@@ -0,0 +1,40 @@
+/** @file
+ * @brief tests H3 function `directedEdgeMidpointDegs`
+ *
+ *  usage: `testDirectedEdgeMidpointDegs`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(directedEdgeMidpointDegs) {
+    TEST(betweenVertices) {
+        H3Index edges[6] = {0};
+        t_assertSuccess(originToDirectedEdges(0x85283473fffffff, edges));
+        for (int i = 0; i < 6; i++) {
+            CellBoundary boundary = {0};
+            LatLng mid = {0};
+            t_assertSuccess(directedEdgeToBoundary(edges[i], &boundary));
+            t_assertSuccess(directedEdgeMidpointDegs(edges[i], &mid));
+
+            LatLng midRads = {degsToRads(mid.lat), degsToRads(mid.lng)};
+            LatLng *a = &boundary.verts[0];
+            LatLng *b = &boundary.verts[boundary.numVerts - 1];
+            double full = greatCircleDistanceRads(a, b);
+            double toA = greatCircleDistanceRads(&midRads, a);
+            double toB = greatCircleDistanceRads(&midRads, b);
+            t_assert(fabs(toA - toB) < 1e-12, "equidistant from endpoints");
+            t_assert(fabs(toA + toB - full) < 1e-12, "on the edge arc");
+        }
+    }
+
+    TEST(invalid) {
+        LatLng mid = {0};
+        t_assert(directedEdgeMidpointDegs(0x85283473fffffff, &mid) ==
+                     E_DIR_EDGE_INVALID,
+                 "cell is not an edge");
+    }
+}
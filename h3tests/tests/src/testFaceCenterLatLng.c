@@ -0,0 +1,47 @@
+/** @file
+ * @brief tests H3 function `faceCenterLatLng`
+ *
+ *  usage: `testFaceCenterLatLng`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(faceCenterLatLng) {
+    TEST(face0) {
+        LatLng center = {0};
+        t_assertSuccess(faceCenterLatLng(0, &center));
+        t_assert(fabs(center.lat - 46.04189432) < 1e-6, "latitude matches");
+        t_assert(fabs(center.lng - 71.52790330) < 1e-6, "longitude matches");
+    }
+
+    TEST(centersLieOnTheirFace) {
+        for (int face = 0; face < 20; face++) {
+            LatLng center = {0};
+            t_assertSuccess(faceCenterLatLng(face, &center));
+
+            LatLng rads = {degsToRads(center.lat), degsToRads(center.lng)};
+            H3Index cell = 0;
+            t_assertSuccess(latLngToCell(&rads, 5, &cell));
+
+            int faces[2] = {-1, -1};
+            int faceCount = 0;
+            t_assertSuccess(maxFaceCount(cell, &faceCount));
+            t_assert(faceCount <= 2, "face count fits in the buffer");
+            t_assertSuccess(getIcosahedronFaces(cell, faces));
+            t_assert(faces[0] == face || faces[1] == face,
+                     "center cell is on the face");
+        }
+    }
+
+    TEST(invalid) {
+        LatLng center = {0};
+        t_assert(faceCenterLatLng(-1, &center) == E_DOMAIN,
+                 "negative face fails");
+        t_assert(faceCenterLatLng(20, &center) == E_DOMAIN,
+                 "face 20 fails");
+    }
+}
@@ -0,0 +1,112 @@
+/** @file
+ * @brief tests H3 functions `geoJsonToCells` and `maxGeoJsonToCellsSize`
+ *
+ *  usage: `testGeoJsonToCells`
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int64_t countCells(const char *geojson, int res) {
+    int64_t size;
+    t_assertSuccess(maxGeoJsonToCellsSize(geojson, res, 0, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    t_assertSuccess(geoJsonToCells(geojson, res, 0, cells));
+
+    int64_t count = 0;
+    for (int64_t i = 0; i < size; i++) {
+        count += cells[i] != H3_NULL;
+    }
+    free(cells);
+    return count;
+}
+
+static bool containsCell(const char *geojson, int res, H3Index cell) {
+    int64_t size;
+    t_assertSuccess(maxGeoJsonToCellsSize(geojson, res, 0, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    t_assertSuccess(geoJsonToCells(geojson, res, 0, cells));
+
+    bool contains = false;
+    for (int64_t i = 0; i < size; i++) {
+        contains |= cells[i] == cell;
+    }
+    free(cells);
+    return contains;
+}
+
+// Writes a GeoJSON triangle, from lng/lat pairs in degrees.
+static void triangle(char *out, size_t size, const double coords[6]) {
+    snprintf(out, size,
+             "{\"type\": \"Polygon\", \"coordinates\": [[[%.17g, %.17g], "
+             "[%.17g, %.17g], [%.17g, %.17g], [%.17g, %.17g]]]}",
+             coords[0], coords[1], coords[2], coords[3], coords[4], coords[5],
+             coords[0], coords[1]);
+}
+
+SUITE(geoJsonToCells) {
+    // Around San Francisco.
+    const char *polygon =
+        "{\"type\": \"Polygon\", \"coordinates\": [[[-122.5, 37.7], [-122.4, "
+        "37.7], [-122.4, 37.8], [-122.5, 37.8], [-122.5, 37.7]]]}";
+    const char *featureCollection =
+        "{\"type\": \"FeatureCollection\", \"features\": [{\"type\": "
+        "\"Feature\", \"properties\": {}, \"geometry\": {\"type\": "
+        "\"Polygon\", \"coordinates\": [[[-122.5, 37.7], [-122.4, 37.7], "
+        "[-122.4, 37.8], [-122.5, 37.8], [-122.5, 37.7]]]}}]}";
+
+    TEST(featureCollection) {
+        int64_t expected = countCells(polygon, 7);
+        t_assert(expected > 0, "found cells in the polygon");
+        t_assert(countCells(featureCollection, 7) == expected,
+                 "feature collection matches its polygon");
+    }
+
+    TEST(nonPolygonal) {
+        int64_t size;
+        const char *point = "{\"type\": \"Point\", \"coordinates\": [0, 0]}";
+        t_assert(maxGeoJsonToCellsSize(point, 7, 0, &size) == E_OPTION_INVALID,
+                 "point is rejected");
+        t_assert(geoJsonToCells(point, 7, 0, NULL) == E_OPTION_INVALID,
+                 "point is rejected");
+    }
+
+    TEST(malformed) {
+        int64_t size;
+        t_assert(maxGeoJsonToCellsSize("{\"type\":", 7, 0, &size) == E_FAILED,
+                 "malformed JSON is rejected");
+        t_assert(maxGeoJsonToCellsSize(NULL, 7, 0, &size) == E_FAILED,
+                 "NULL is rejected");
+    }
+
+    TEST(invalidArgs) {
+        int64_t size;
+        t_assert(maxGeoJsonToCellsSize(polygon, 16, 0, &size) == E_RES_DOMAIN,
+                 "invalid resolution is rejected");
+        t_assert(maxGeoJsonToCellsSize(polygon, 7, 1, &size) ==
+                     E_OPTION_INVALID,
+                 "invalid flags are rejected");
+    }
+
+    TEST(boundaryTie) {
+        // The cell center lies on the diagonal shared by two triangles.
+        H3Index origin = 0x8928308280fffff;
+        LatLng center;
+        t_assertSuccess(cellToLatLng(origin, &center));
+        double lat = radsToDegs(center.lat);
+        double lng = radsToDegs(center.lng);
+        double d = 0.01;
+        char geojson[256];
+
+        double below[] = {lng - d, lat - d, lng + d, lat - d, lng + d, lat + d};
+        triangle(geojson, sizeof(geojson), below);
+        t_assert(containsCell(geojson, 9, origin), "center on diagonal");
+
+        double above[] = {lng - d, lat - d, lng + d, lat + d, lng - d, lat + d};
+        triangle(geojson, sizeof(geojson), above);
+        t_assert(containsCell(geojson, 9, origin), "center on diagonal");
+    }
+}
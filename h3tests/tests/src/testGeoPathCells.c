@@ -0,0 +1,69 @@
+/** @file
+ * @brief tests H3 function `geoPathCells`
+ *
+ *  usage: `testGeoPathCells`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(geoPathCells) {
+    TEST(endpoints) {
+        LatLng a = {0};
+        LatLng b = {0};
+        latLngSetDegs(&a, 48.8566, 2.3522);
+        latLngSetDegs(&b, 51.5074, -0.1278);
+        H3Index start, end;
+        t_assertSuccess(latLngToCell(&a, 6, &start));
+        t_assertSuccess(latLngToCell(&b, 6, &end));
+
+        H3Index path[256] = {0};
+        int count = 0;
+        t_assertSuccess(geoPathCells(start, end, 6, path, 256, &count));
+        t_assert(count > 2, "path has waypoints");
+        t_assert(path[0] == start, "path starts at the start cell");
+        t_assert(path[count - 1] == end, "path ends at the end cell");
+        for (int i = 0; i < count; i++) {
+            t_assert(getResolution(path[i]) == 6, "path cells at res");
+            t_assert(i == 0 || path[i] != path[i - 1], "no consecutive dups");
+        }
+
+        // Coarser path, from the same cells.
+        int coarseCount = 0;
+        t_assertSuccess(geoPathCells(start, end, 4, path, 256, &coarseCount));
+        t_assert(coarseCount < count, "coarser path is shorter");
+        t_assert(getResolution(path[0]) == 4, "coarse path at res");
+    }
+
+    TEST(sameCell) {
+        H3Index cell = 0x85283473fffffff;
+        H3Index path[4] = {0};
+        int count = 0;
+        t_assertSuccess(geoPathCells(cell, cell, 5, path, 4, &count));
+        t_assert(count == 1 && path[0] == cell, "path of a single cell");
+    }
+
+    TEST(memoryBounds) {
+        H3Index path[2] = {0};
+        int count = 0;
+        t_assert(geoPathCells(0x85283473fffffff, 0x8528342bfffffff, 9, path,
+                              2, &count) == E_MEMORY_BOUNDS,
+                 "output too small");
+        t_assert(geoPathCells(0x85283473fffffff, 0x8528342bfffffff, 9, NULL,
+                              0, &count) == E_MEMORY_BOUNDS,
+                 "no output");
+    }
+
+    TEST(invalid) {
+        H3Index path[4] = {0};
+        int count = 0;
+        t_assert(geoPathCells(0, 0x85283473fffffff, 5, path, 4, &count) ==
+                     E_CELL_INVALID,
+                 "invalid start");
+        t_assert(geoPathCells(0x85283473fffffff, 0x85283473fffffff, 16, path,
+                              4, &count) == E_RES_DOMAIN,
+                 "invalid resolution");
+    }
+}
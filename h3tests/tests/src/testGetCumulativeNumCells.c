@@ -0,0 +1,39 @@
+/** @file
+ * @brief tests H3 function `getCumulativeNumCells`
+ *
+ *  usage: `testGetCumulativeNumCells`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(getCumulativeNumCells) {
+    TEST(sumOfNumCells) {
+        int64_t expected = 0;
+        for (int res = 0; res <= 15; res++) {
+            int64_t count = 0;
+            t_assertSuccess(getNumCells(res, &count));
+            expected += count;
+
+            int64_t cumulative = 0;
+            t_assertSuccess(getCumulativeNumCells(res, &cumulative));
+            t_assert(cumulative == expected, "sum of getNumCells");
+        }
+    }
+
+    TEST(res0) {
+        int64_t cumulative = 0;
+        t_assertSuccess(getCumulativeNumCells(0, &cumulative));
+        t_assert(cumulative == 122, "122 base cells");
+    }
+
+    TEST(invalidRes) {
+        int64_t cumulative = 0;
+        t_assert(getCumulativeNumCells(-1, &cumulative) == E_RES_DOMAIN,
+                 "negative resolution");
+        t_assert(getCumulativeNumCells(16, &cumulative) == E_RES_DOMAIN,
+                 "resolution too fine");
+    }
+}
@@ -0,0 +1,48 @@
+/** @file
+ * @brief tests H3 function `getPentagonBaseCells`
+ *
+ *  usage: `testGetPentagonBaseCells`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(getPentagonBaseCells) {
+    TEST(twelvePentagons) {
+        t_assert(pentagonCount() == 12, "12 pentagons");
+        int baseCells[12] = {0};
+        t_assertSuccess(getPentagonBaseCells(baseCells));
+
+        H3Index res0Cells[122] = {0};
+        t_assertSuccess(getRes0Cells(res0Cells));
+        for (int i = 0; i < 12; i++) {
+            t_assert(baseCells[i] >= 0 && baseCells[i] < res0CellCount(),
+                     "valid base cell number");
+            t_assert(getBaseCellNumber(res0Cells[baseCells[i]]) ==
+                         baseCells[i],
+                     "res 0 cells are ordered by base cell");
+            t_assert(isPentagon(res0Cells[baseCells[i]]),
+                     "base cell is a pentagon");
+            for (int j = 0; j < i; j++) {
+                t_assert(baseCells[i] != baseCells[j], "no duplicates");
+            }
+        }
+    }
+
+    TEST(matchesGetPentagons) {
+        int baseCells[12] = {0};
+        H3Index pentagons[12] = {0};
+        t_assertSuccess(getPentagonBaseCells(baseCells));
+        t_assertSuccess(getPentagons(9, pentagons));
+        for (int i = 0; i < 12; i++) {
+            t_assert(getBaseCellNumber(pentagons[i]) == baseCells[i],
+                     "same pentagons as getPentagons");
+        }
+    }
+
+    TEST(null) {
+        t_assert(getPentagonBaseCells(NULL) == E_FAILED, "null output");
+    }
+}
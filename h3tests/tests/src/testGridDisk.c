@@ -475,4 +475,16 @@ SUITE(gridDisk) {
         t_assert(sz == max,
                  "maxGridDiskSize of INT32_MAX produces valid result");
     }
+
+    TEST(maxGridDiskSize_nearOverflow) {
+        int64_t sz;
+        int64_t max;
+        t_assertSuccess(getNumCells(15, &max));
+        // Capped at the number of res 15 cells, even where 3k(k+1)+1 would
+        // overflow a signed 64-bit integer (from k = 1753413056).
+        for (int k = 1753413056 - 10; k < 1753413056 + 10; k++) {
+            t_assertSuccess(maxGridDiskSize(k, &sz));
+            t_assert(sz == max, "size is capped instead of overflowing");
+        }
+    }
 }
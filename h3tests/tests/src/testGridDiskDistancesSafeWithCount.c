@@ -0,0 +1,81 @@
+/** @file
+ * @brief tests H3 function `gridDiskDistancesSafeWithCount`
+ *
+ *  usage: `testGridDiskDistancesSafeWithCount`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDiskDistancesSafeWithCount) {
+    TEST(hexagon) {
+        H3Index sf = 0x8928308280fffff;
+        H3Index cells[7];
+        int distances[7];
+        int64_t count;
+        t_assertSuccess(
+            gridDiskDistancesSafeWithCount(sf, 1, cells, distances, &count));
+        t_assert(count == 7, "every slot is used");
+        for (int i = 0; i < count; i++) {
+            t_assert(cells[i] != H3_NULL, "cell is written");
+            t_assert(distances[i] == (cells[i] == sf ? 0 : 1),
+                     "distance is written");
+        }
+    }
+
+    TEST(pentagonTail) {
+        H3Index pentagon = 0x821c07fffffffff;
+        int64_t size;
+        t_assertSuccess(maxGridDiskSize(2, &size));
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        int *distances = calloc(size, sizeof(int));
+        // Garbage in, to make sure the tail is overwritten.
+        for (int64_t i = 0; i < size; i++) {
+            cells[i] = 0x42;
+            distances[i] = 42;
+        }
+
+        int64_t count;
+        t_assertSuccess(gridDiskDistancesSafeWithCount(pentagon, 2, cells,
+                                                       distances, &count));
+        t_assert(count == 16, "pentagon disk is smaller");
+        for (int64_t i = 0; i < count; i++) {
+            t_assert(isValidCell(cells[i]), "cell is written");
+            t_assert(distances[i] >= 0 && distances[i] <= 2,
+                     "distance is written");
+        }
+        for (int64_t i = count; i < size; i++) {
+            t_assert(cells[i] == H3_NULL, "tail cell is H3_NULL");
+            t_assert(distances[i] == -1, "tail distance is -1");
+        }
+
+        free(distances);
+        free(cells);
+    }
+
+    TEST(nullCount) {
+        H3Index pentagon = 0x821c07fffffffff;
+        H3Index cells[7];
+        int distances[7];
+        t_assertSuccess(
+            gridDiskDistancesSafe(pentagon, 1, cells, distances));
+        t_assert(cells[6] == H3_NULL, "tail cell is H3_NULL");
+        t_assert(distances[6] == -1, "tail distance is -1");
+    }
+
+    TEST(invalid) {
+        H3Index cells[7];
+        int distances[7];
+        int64_t count;
+        t_assert(gridDiskDistancesSafeWithCount(0x7fffffffffffffff, 1, cells,
+                                                distances,
+                                                &count) == E_CELL_INVALID,
+                 "invalid origin is rejected");
+        t_assert(gridDiskDistancesSafeWithCount(0x8928308280fffff, -1, cells,
+                                                distances,
+                                                &count) == E_DOMAIN,
+                 "negative k is rejected");
+    }
+}
@@ -0,0 +1,62 @@
+/** @file
+ * @brief tests H3 function `gridDiskDistancesSorted`
+ *
+ *  usage: `testGridDiskDistancesSorted`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void assertSorted(H3Index origin, int k) {
+    int64_t size = 0;
+    t_assertSuccess(maxGridDiskSize(k, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    int *distances = calloc(size, sizeof(int));
+    int count = -1;
+    t_assertSuccess(
+        gridDiskDistancesSorted(origin, k, cells, distances, &count));
+    t_assert(count > 0 && count <= size, "count within bounds");
+    t_assert(cells[0] == origin && distances[0] == 0, "origin comes first");
+    for (int i = 0; i < count; i++) {
+        int64_t distance = -1;
+        t_assertSuccess(gridDistance(origin, cells[i], &distance));
+        t_assert(distance == distances[i], "distance matches gridDistance");
+        t_assert(distances[i] <= k, "distance within k");
+        if (i > 0) {
+            t_assert(distances[i - 1] <= distances[i],
+                     "distances are non-decreasing");
+        }
+    }
+    free(cells);
+    free(distances);
+}
+
+SUITE(gridDiskDistancesSorted) {
+    TEST(hexagon) {
+        int64_t size = 0;
+        H3Index cells[19] = {0};
+        int distances[19] = {0};
+        int count = -1;
+        t_assertSuccess(maxGridDiskSize(2, &size));
+        t_assertSuccess(gridDiskDistancesSorted(0x85283473fffffff, 2, cells,
+                                                distances, &count));
+        t_assert(count == size, "full disk around a hexagon");
+        assertSorted(0x85283473fffffff, 4);
+    }
+
+    TEST(pentagon) { assertSorted(0x821c07fffffffff, 3); }
+
+    TEST(invalid) {
+        H3Index cells[1] = {0};
+        int distances[1] = {0};
+        int count = -1;
+        t_assert(gridDiskDistancesSorted(0x85283473fffffff, -1, cells,
+                                         distances, &count) == E_DOMAIN,
+                 "negative k fails");
+        t_assert(gridDiskDistancesSorted(0, 0, cells, distances, &count) ==
+                     E_CELL_INVALID,
+                 "invalid origin fails");
+    }
+}
@@ -0,0 +1,69 @@
+/** @file
+ * @brief tests H3 function `gridDiskGeoDistancesKm`
+ *
+ *  usage: `testGridDiskGeoDistancesKm`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDiskGeoDistancesKm) {
+    TEST(hexagon) {
+        H3Index origin = 0x8928308280fffff;
+        H3Index cells[37] = {0};
+        double distances[37] = {0};
+        int count = 0;
+        t_assertSuccess(
+            gridDiskGeoDistancesKm(origin, 3, cells, distances, &count));
+        t_assert(count == 37, "dense disk");
+        t_assert(cells[0] == origin, "origin first");
+        t_assert(distances[0] == 0, "origin is at distance 0");
+
+        LatLng center;
+        t_assertSuccess(cellToLatLng(origin, &center));
+        double sums[4] = {0};
+        int counts[4] = {0};
+        for (int i = 0; i < count; i++) {
+            LatLng ll;
+            int64_t ring;
+            t_assertSuccess(cellToLatLng(cells[i], &ll));
+            t_assertSuccess(gridDistance(origin, cells[i], &ring));
+            t_assert(fabs(greatCircleDistanceKm(&center, &ll) -
+                          distances[i]) < 1e-9,
+                     "great-circle distance from the origin");
+            sums[ring] += distances[i];
+            counts[ring]++;
+        }
+        for (int ring = 1; ring < 4; ring++) {
+            t_assert(sums[ring] / counts[ring] >
+                         sums[ring - 1] / counts[ring - 1],
+                     "distances increase with ring");
+        }
+    }
+
+    TEST(pentagon) {
+        H3Index cells[19] = {0};
+        double distances[19] = {0};
+        int count = 0;
+        t_assertSuccess(gridDiskGeoDistancesKm(0x8009fffffffffff, 2, cells,
+                                               distances, &count));
+        t_assert(count == 16, "dense disk around a pentagon");
+        for (int i = 1; i < count; i++) {
+            t_assert(distances[i] > 0, "only the origin is at distance 0");
+        }
+    }
+
+    TEST(invalid) {
+        H3Index cells[1];
+        double distances[1];
+        t_assert(gridDiskGeoDistancesKm(0x8928308280fffff, -1, cells,
+                                        distances, NULL) == E_DOMAIN,
+                 "negative k");
+        t_assert(gridDiskGeoDistancesKm(0, 0, cells, distances, NULL) ==
+                     E_CELL_INVALID,
+                 "invalid origin");
+    }
+}
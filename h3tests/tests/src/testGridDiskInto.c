@@ -0,0 +1,65 @@
+/** @file
+ * @brief tests H3 functions `diskScratchNew`, `gridDiskInto`,
+ * `diskScratchCells` and `diskScratchFree`
+ *
+ *  usage: `testGridDiskInto`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+// Checks that the scratch holds exactly the non-zero cells of gridDisk.
+static void assertSameDisk(H3Index origin, const H3Index *cells, int count) {
+    H3Index disk[19] = {0};
+    int expected = 0;
+    t_assertSuccess(gridDisk(origin, 2, disk));
+    for (int i = 0; i < 19; i++) {
+        if (disk[i] == H3_NULL) {
+            continue;
+        }
+        expected++;
+        int found = 0;
+        for (int j = 0; j < count; j++) {
+            found |= cells[j] == disk[i];
+        }
+        t_assert(found, "disk cell is in the scratch");
+    }
+    t_assert(count == expected, "same number of cells");
+}
+
+SUITE(gridDiskInto) {
+    TEST(twoQueries) {
+        H3DiskScratch *scratch = NULL;
+        t_assertSuccess(diskScratchNew(2, &scratch));
+
+        int count = 0;
+        t_assertSuccess(gridDiskInto(scratch, 0x85283473fffffff, &count));
+        t_assert(count == 19, "full disk around a hexagon");
+        assertSameDisk(0x85283473fffffff, diskScratchCells(scratch), count);
+
+        t_assertSuccess(gridDiskInto(scratch, 0x8009fffffffffff, &count));
+        t_assert(count < 19, "smaller disk around a pentagon");
+        assertSameDisk(0x8009fffffffffff, diskScratchCells(scratch), count);
+
+        diskScratchFree(scratch);
+    }
+
+    TEST(invalid) {
+        H3DiskScratch *scratch = NULL;
+        int count = 0;
+        t_assert(diskScratchNew(-1, &scratch) == E_DOMAIN,
+                 "negative k fails");
+        t_assert(diskScratchNew(0x7fffffff, &scratch) == E_MEMORY_ALLOC,
+                 "too large disk fails");
+        t_assertSuccess(diskScratchNew(1, &scratch));
+        t_assert(gridDiskInto(scratch, 0, &count) == E_CELL_INVALID,
+                 "invalid origin fails");
+        t_assert(gridDiskInto(NULL, 0x85283473fffffff, &count) == E_FAILED,
+                 "null scratch fails");
+        t_assert(diskScratchCells(NULL) == NULL, "null scratch has no cells");
+        diskScratchFree(scratch);
+        diskScratchFree(NULL);
+    }
+}
@@ -0,0 +1,73 @@
+/** @file
+ * @brief tests H3 function `gridDiskSpiral`
+ *
+ *  usage: `testGridDiskSpiral`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDiskSpiral) {
+    TEST(hexagon) {
+        H3Index sf = 0x8928308280fffff;
+        H3Index out[37];
+        int count;
+        t_assertSuccess(gridDiskSpiral(sf, 3, out, &count));
+        t_assert(count == 37, "got the whole disk");
+        t_assert(out[0] == sf, "spiral starts at the origin");
+
+        // Ring r spans [3r(r-1)+1, 3r(r+1)+1).
+        for (int ring = 1; ring <= 3; ring++) {
+            int start = 3 * ring * (ring - 1) + 1;
+            int end = 3 * ring * (ring + 1) + 1;
+            for (int i = start; i < end; i++) {
+                int64_t distance;
+                t_assertSuccess(gridDistance(sf, out[i], &distance));
+                t_assert(distance == ring, "cell is in the expected ring");
+
+                // Rings are walked around, one neighbor after the other.
+                H3Index next = out[i + 1 == end ? start : i + 1];
+                int isNeighbor;
+                t_assertSuccess(areNeighborCells(out[i], next, &isNeighbor));
+                t_assert(isNeighbor, "consecutive cells are neighbors");
+            }
+        }
+
+        H3Index again[37];
+        t_assertSuccess(gridDiskSpiral(sf, 3, again, &count));
+        for (int i = 0; i < count; i++) {
+            t_assert(out[i] == again[i], "traversal is deterministic");
+        }
+    }
+
+    TEST(pentagon) {
+        H3Index pentagon = 0x821c07fffffffff;
+        H3Index out[19];
+        int count;
+        t_assertSuccess(gridDiskSpiral(pentagon, 2, out, &count));
+        t_assert(count == 16, "pentagon leaves gaps");
+
+        int previous = 0;
+        for (int i = 0; i < count; i++) {
+            int distance;
+            t_assertSuccess(
+                gridDistanceBounded(pentagon, out[i], 2, &distance));
+            t_assert(distance >= previous, "cells sorted by distance");
+            previous = distance;
+        }
+        t_assert(previous == 2, "last ring is reached");
+    }
+
+    TEST(invalid) {
+        H3Index out[7];
+        int count;
+        t_assert(gridDiskSpiral(0x7fffffffffffffff, 1, out, &count) ==
+                     E_CELL_INVALID,
+                 "invalid origin is rejected");
+        t_assert(gridDiskSpiral(0x8928308280fffff, -1, out, &count) ==
+                     E_DOMAIN,
+                 "negative k is rejected");
+    }
+}
@@ -0,0 +1,55 @@
+/** @file
+ * @brief tests H3 function `gridDisksUnsafeWithOffsets`
+ *
+ *  usage: `testGridDisksUnsafeWithOffsets`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDisksUnsafeWithOffsets) {
+    H3Index origins[] = {0x89283080ddbffff, 0x89283080c37ffff,
+                         0x89283080c27ffff};
+
+    TEST(offsetsDelimitBlocks) {
+        int k = 2;
+        int64_t diskSize;
+        t_assertSuccess(maxGridDiskSize(k, &diskSize));
+        H3Index *out = calloc(3 * diskSize, sizeof(H3Index));
+        int offsets[4] = {-1, -1, -1, -1};
+        t_assertSuccess(
+            gridDisksUnsafeWithOffsets(origins, 3, k, out, offsets));
+
+        t_assert(offsets[0] == 0, "first block starts at 0");
+        t_assert(offsets[3] == 3 * diskSize, "last offset is the total");
+        H3Index *disk = calloc(diskSize, sizeof(H3Index));
+        for (int i = 0; i < 3; i++) {
+            t_assert(offsets[i + 1] - offsets[i] == diskSize,
+                     "block has the size of a disk");
+            t_assert(out[offsets[i]] == origins[i],
+                     "block starts with its origin");
+
+            t_assertSuccess(gridDiskUnsafe(origins[i], k, disk));
+            for (int j = 0; j < diskSize; j++) {
+                t_assert(out[offsets[i] + j] == disk[j],
+                         "block matches the origin's disk");
+            }
+        }
+        free(disk);
+        free(out);
+    }
+
+    TEST(nullOffsets) {
+        H3Index out[21] = {0};
+        t_assertSuccess(gridDisksUnsafeWithOffsets(origins, 3, 1, out, NULL));
+        t_assert(out[7] == origins[1], "output is still written");
+    }
+
+    TEST(empty) {
+        int offsets[1] = {-1};
+        t_assertSuccess(gridDisksUnsafeWithOffsets(NULL, 0, 1, NULL, offsets));
+        t_assert(offsets[0] == 0, "total is zero");
+    }
+}
@@ -0,0 +1,75 @@
+/** @file
+ * @brief tests H3 function `gridDistanceApprox`
+ *
+ *  usage: `testGridDistanceApprox`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDistanceApprox) {
+    TEST(closePair) {
+        H3Index origin = 0x8928308280fffff;
+        H3Index disk[19] = {0};
+        t_assertSuccess(gridDisk(origin, 2, disk));
+        for (int i = 0; i < 19; i++) {
+            if (disk[i] == H3_NULL) {
+                continue;
+            }
+            int64_t exact = -1;
+            int64_t approx = -1;
+            int isExact = 0;
+            t_assertSuccess(gridDistance(origin, disk[i], &exact));
+            t_assertSuccess(
+                gridDistanceApprox(origin, disk[i], &approx, &isExact));
+            t_assert(isExact == 1, "close pair is exact");
+            t_assert(approx == exact, "matches gridDistance");
+        }
+    }
+
+    TEST(farPair) {
+        LatLng a = {0};
+        LatLng b = {0};
+        latLngSetDegs(&a, 45, 10);
+        latLngSetDegs(&b, -30, 150);
+        H3Index origin, target;
+        t_assertSuccess(latLngToCell(&a, 5, &origin));
+        t_assertSuccess(latLngToCell(&b, 5, &target));
+
+        int64_t distance = -1;
+        t_assert(gridDistance(origin, target, &distance) != E_SUCCESS,
+                 "exact distance can't be computed");
+
+        int64_t approx = -1;
+        int isExact = -1;
+        t_assertSuccess(gridDistanceApprox(origin, target, &approx, &isExact));
+        t_assert(isExact == 0, "far pair is approximate");
+
+        LatLng ca, cb;
+        double edge;
+        t_assertSuccess(cellToLatLng(origin, &ca));
+        t_assertSuccess(cellToLatLng(target, &cb));
+        t_assertSuccess(getHexagonEdgeLengthAvgM(5, &edge));
+        double expected = greatCircleDistanceM(&ca, &cb) / (sqrt(3) * edge);
+        t_assert(fabs(approx - expected) <= 1, "estimate from distance");
+
+        t_assertSuccess(gridDistanceApprox(origin, target, &approx, NULL));
+    }
+
+    TEST(resolutionMismatch) {
+        int64_t approx;
+        t_assert(gridDistanceApprox(0x832830fffffffff, 0x822837fffffffff,
+                                    &approx, NULL) == E_RES_MISMATCH,
+                 "resolution mismatch");
+    }
+
+    TEST(invalidCell) {
+        int64_t approx;
+        t_assert(gridDistanceApprox(0x8928308280fffff, 0, &approx, NULL) ==
+                     E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
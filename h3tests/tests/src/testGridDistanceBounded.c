@@ -0,0 +1,71 @@
+/** @file
+ * @brief tests H3 function `gridDistanceBounded`
+ *
+ *  usage: `testGridDistanceBounded`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDistanceBounded) {
+    H3Index sf = 0x8928308280fffff;
+    H3Index ring[12];
+    int ringLen;
+    t_assertSuccess(gridRing(sf, 2, ring, &ringLen));
+
+    TEST(inRange) {
+        int distance;
+        t_assertSuccess(gridDistanceBounded(sf, sf, 0, &distance));
+        t_assert(distance == 0, "origin is at distance 0");
+
+        for (int i = 0; i < ringLen; i++) {
+            t_assertSuccess(gridDistanceBounded(sf, ring[i], 2, &distance));
+            t_assert(distance == 2, "ring cell is at distance 2");
+            t_assertSuccess(gridDistanceBounded(sf, ring[i], 5, &distance));
+            t_assert(distance == 2, "larger bound gives the same distance");
+        }
+    }
+
+    TEST(outOfRange) {
+        int distance;
+        t_assertSuccess(gridDistanceBounded(sf, ring[0], 1, &distance));
+        t_assert(distance == -1, "ring cell is beyond k = 1");
+
+        // Other side of the world.
+        H3Index far = 0x89dc1b4a8a7ffff;
+        t_assertSuccess(gridDistanceBounded(sf, far, 3, &distance));
+        t_assert(distance == -1, "far cell is out of range");
+    }
+
+    TEST(pentagon) {
+        H3Index pentagon = 0x821c07fffffffff;
+        H3Index disk[19] = {0};
+        int distances[19] = {0};
+        t_assertSuccess(gridDiskDistances(pentagon, 2, disk, distances));
+        for (int i = 0; i < 19; i++) {
+            if (disk[i] == H3_NULL) {
+                continue;
+            }
+            int distance;
+            t_assertSuccess(
+                gridDistanceBounded(pentagon, disk[i], 2, &distance));
+            t_assert(distance == distances[i], "distance around pentagon");
+        }
+    }
+
+    TEST(invalid) {
+        int distance;
+        H3Index parent;
+        t_assertSuccess(cellToParent(sf, 8, &parent));
+        t_assert(gridDistanceBounded(sf, parent, 2, &distance) ==
+                     E_RES_MISMATCH,
+                 "resolution mismatch is rejected");
+        t_assert(gridDistanceBounded(sf, sf, -1, &distance) == E_DOMAIN,
+                 "negative k is rejected");
+        t_assert(gridDistanceBounded(sf, 0x7fffffffffffffff, 2, &distance) ==
+                     E_CELL_INVALID,
+                 "invalid target is rejected");
+    }
+}
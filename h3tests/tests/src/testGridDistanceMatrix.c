@@ -0,0 +1,58 @@
+/** @file
+ * @brief tests H3 function `gridDistanceMatrix`
+ *
+ *  usage: `testGridDistanceMatrix`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridDistanceMatrix) {
+    H3Index origin = 0x8928308280fffff;
+
+    TEST(collinear) {
+        // Three cells along the I axis, 3 steps apart.
+        CoordIJ ij;
+        t_assertSuccess(cellToLocalIj(origin, origin, 0, &ij));
+        H3Index cells[3];
+        for (int i = 0; i < 3; i++) {
+            CoordIJ step = {.i = ij.i + 3 * i, .j = ij.j};
+            t_assertSuccess(localIjToCell(origin, &step, 0, &cells[i]));
+        }
+
+        int64_t matrix[9];
+        t_assertSuccess(gridDistanceMatrix(cells, 3, matrix));
+        for (int i = 0; i < 3; i++) {
+            t_assert(matrix[i * 3 + i] == 0, "zero diagonal");
+            for (int j = 0; j < 3; j++) {
+                t_assert(matrix[i * 3 + j] == matrix[j * 3 + i], "symmetric");
+                int64_t distance;
+                t_assertSuccess(gridDistance(cells[i], cells[j], &distance));
+                t_assert(matrix[i * 3 + j] == distance, "same as gridDistance");
+            }
+        }
+        t_assert(matrix[0 * 3 + 1] == 3, "neighboring steps");
+        t_assert(matrix[0 * 3 + 2] == 6, "distances add up along the line");
+    }
+
+    TEST(uncomputable) {
+        // Different resolutions have no grid distance.
+        H3Index cells[2] = {origin, 0x8828308281fffff};
+        int64_t matrix[4];
+        t_assertSuccess(gridDistanceMatrix(cells, 2, matrix));
+        t_assert(matrix[0] == 0 && matrix[3] == 0, "zero diagonal");
+        t_assert(matrix[1] == -1 && matrix[2] == -1, "uncomputable distance");
+    }
+
+    TEST(invalid) {
+        H3Index cells[2] = {origin, 0x7fffffffffffffff};
+        int64_t matrix[4];
+        t_assert(gridDistanceMatrix(cells, 2, matrix) == E_CELL_INVALID,
+                 "invalid cell");
+        t_assert(gridDistanceMatrix(cells, -1, matrix) == E_DOMAIN,
+                 "negative size");
+        t_assertSuccess(gridDistanceMatrix(NULL, 0, NULL));
+    }
+}
@@ -0,0 +1,69 @@
+/** @file
+ * @brief tests H3 function `gridFrontier`
+ *
+ *  usage: `testGridFrontier`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define PENTAGON 0x821c07fffffffff
+
+static int contains(const H3Index *cells, int64_t n, H3Index cell) {
+    for (int64_t i = 0; i < n; i++) {
+        if (cells[i] == cell) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+SUITE(gridFrontier) {
+    TEST(pentagonAdjacent) {
+        H3Index ring[6] = {0};
+        int ringLen = 0;
+        t_assert(isPentagon(PENTAGON), "origin neighbors a pentagon");
+        t_assertSuccess(gridRing(PENTAGON, 1, ring, &ringLen));
+        H3Index origin = ring[0];
+
+        for (int k = 0; k <= 4; k++) {
+            int64_t size = 0;
+            t_assertSuccess(maxGridDiskSize(k, &size));
+            H3Index *outer = calloc(size, sizeof(H3Index));
+            H3Index *inner = calloc(size, sizeof(H3Index));
+            H3Index *frontier = calloc(size, sizeof(H3Index));
+            int count = -1;
+            t_assertSuccess(gridDisk(origin, k, outer));
+            if (k > 0) {
+                t_assertSuccess(gridDisk(origin, k - 1, inner));
+            }
+            t_assertSuccess(gridFrontier(origin, k, frontier, &count));
+
+            int expected = 0;
+            for (int64_t i = 0; i < size; i++) {
+                if (outer[i] == H3_NULL || contains(inner, size, outer[i])) {
+                    continue;
+                }
+                expected++;
+                t_assert(contains(frontier, count, outer[i]),
+                         "disk difference cell is in the frontier");
+            }
+            t_assert(count == expected, "frontier matches disk difference");
+
+            free(outer);
+            free(inner);
+            free(frontier);
+        }
+    }
+
+    TEST(invalid) {
+        H3Index out[1] = {0};
+        int count = 0;
+        t_assert(gridFrontier(PENTAGON, -1, out, &count) == E_DOMAIN,
+                 "negative k fails");
+        t_assert(gridFrontier(0, 1, out, &count) == E_CELL_INVALID,
+                 "invalid origin fails");
+    }
+}
@@ -0,0 +1,68 @@
+/** @file
+ * @brief tests H3 function `gridPathCellsBetweenRes`
+ *
+ *  usage: `testGridPathCellsBetweenRes`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(gridPathCellsBetweenRes) {
+    H3Index sf = 0x8928308280fffff;
+    H3Index start;
+    t_assertSuccess(cellToParent(sf, 8, &start));
+    H3Index ring[18];
+    int ringLen;
+    H3Index sf7;
+    t_assertSuccess(cellToParent(sf, 7, &sf7));
+    t_assertSuccess(gridRing(sf7, 3, ring, &ringLen));
+    H3Index end = ring[0];
+
+    TEST(finerStart) {
+        int64_t count;
+        t_assertSuccess(gridPathCellsBetweenRes(start, end, NULL, &count));
+        t_assert(count == 4, "got expected path length");
+
+        H3Index *path = calloc(count, sizeof(H3Index));
+        t_assertSuccess(gridPathCellsBetweenRes(start, end, path, &count));
+        t_assert(path[0] == sf7, "path starts at the rolled up start");
+        t_assert(path[count - 1] == end, "path ends at the end");
+        for (int64_t i = 0; i < count; i++) {
+            t_assert(getResolution(path[i]) == 7, "path is at res 7");
+            if (i > 0) {
+                int isNeighbor;
+                t_assertSuccess(
+                    areNeighborCells(path[i - 1], path[i], &isNeighbor));
+                t_assert(isNeighbor, "path cells are neighbors");
+            }
+        }
+        free(path);
+    }
+
+    TEST(finerEnd) {
+        int64_t count;
+        t_assertSuccess(gridPathCellsBetweenRes(end, start, NULL, &count));
+        t_assert(count == 4, "got expected path length");
+        H3Index path[4];
+        t_assertSuccess(gridPathCellsBetweenRes(end, start, path, &count));
+        t_assert(path[0] == end && path[3] == sf7,
+                 "endpoints are rolled up to res 7");
+    }
+
+    TEST(sameRes) {
+        int64_t expected;
+        t_assertSuccess(gridPathCellsSize(sf7, end, &expected));
+        int64_t count;
+        t_assertSuccess(gridPathCellsBetweenRes(sf7, end, NULL, &count));
+        t_assert(count == expected, "same as gridPathCellsSize");
+    }
+
+    TEST(invalid) {
+        int64_t count;
+        t_assert(gridPathCellsBetweenRes(0x7fffffffffffffff, end, NULL,
+                                         &count) == E_CELL_INVALID,
+                 "invalid start is rejected");
+    }
+}
@@ -0,0 +1,80 @@
+/** @file
+ * @brief tests H3 function `gridRing`
+ *
+ *  usage: `testGridRing`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+// Check that the ring is the set of cells at distance k in the disk.
+static void assertRingIsDiskDifference(H3Index origin, int k) {
+    int64_t size;
+    t_assertSuccess(maxGridDiskSize(k, &size));
+    H3Index *ring = calloc(size, sizeof(H3Index));
+    H3Index *disk = calloc(size, sizeof(H3Index));
+    int *distances = calloc(size, sizeof(int));
+    int count = -1;
+    t_assertSuccess(gridRing(origin, k, ring, &count));
+    t_assertSuccess(gridDiskDistances(origin, k, disk, distances));
+
+    int expected = 0;
+    for (int64_t i = 0; i < size; i++) {
+        if (disk[i] == H3_NULL || distances[i] != k) {
+            continue;
+        }
+        expected++;
+
+        int found = 0;
+        for (int j = 0; j < count; j++) {
+            found |= ring[j] == disk[i];
+        }
+        t_assert(found, "cell at distance k is in the ring");
+    }
+    t_assert(count == expected, "ring has the expected size");
+    for (int64_t i = count; i < size; i++) {
+        t_assert(ring[i] == H3_NULL, "tail is left untouched");
+    }
+
+    free(distances);
+    free(disk);
+    free(ring);
+}
+
+SUITE(gridRing) {
+    TEST(hexagon) {
+        H3Index sf = 0x89283080ddbffff;
+        assertRingIsDiskDifference(sf, 0);
+        assertRingIsDiskDifference(sf, 1);
+        assertRingIsDiskDifference(sf, 3);
+    }
+
+    TEST(pentagonAdjacent) {
+        H3Index pentagon = 0x821c07fffffffff;
+        H3Index neighbors[6], edges[6];
+        int numNeighbors;
+        t_assertSuccess(
+            cellToNeighborEdges(pentagon, neighbors, edges, &numNeighbors));
+        for (int k = 0; k <= 3; k++) {
+            assertRingIsDiskDifference(pentagon, k);
+            assertRingIsDiskDifference(neighbors[0], k);
+        }
+
+        // The unsafe version fails where the safe one doesn't.
+        H3Index ring[6];
+        t_assert(gridRingUnsafe(pentagon, 1, ring) == E_PENTAGON,
+                 "unsafe version fails on pentagon");
+    }
+
+    TEST(invalidArgs) {
+        H3Index ring[1];
+        int count;
+        t_assert(gridRing(0x89283080ddbffff, -1, ring, &count) == E_DOMAIN,
+                 "negative k is rejected");
+        t_assert(gridRing(0x7fffffffffffffff, 0, ring, &count) ==
+                     E_CELL_INVALID,
+                 "invalid cell is rejected");
+    }
+}
@@ -0,0 +1,32 @@
+/** @file
+ * @brief tests H3 functions `h3ErrorCode` and `h3ErrorIsSuccess`
+ *
+ *  usage: `testH3ErrorAccessors`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(h3ErrorAccessors) {
+    TEST(success) {
+        H3Index cell;
+        LatLng sf = {0.659966917655, -2.1364398519396};
+        H3Error err = latLngToCell(&sf, 9, &cell);
+        t_assert(h3ErrorCode(err) == 0, "success code is 0");
+        t_assert(h3ErrorIsSuccess(err) == 1, "success is a success");
+    }
+
+    TEST(errors) {
+        H3Index cell;
+        LatLng sf = {0.659966917655, -2.1364398519396};
+        H3Error err = latLngToCell(&sf, 16, &cell);
+        t_assert(h3ErrorCode(err) == E_RES_DOMAIN, "got resolution error");
+        t_assert(h3ErrorIsSuccess(err) == 0, "error is not a success");
+
+        err = cellToParent(0x7fffffffffffffff, 0, &cell);
+        t_assert(h3ErrorCode(err) == E_CELL_INVALID, "got invalid cell error");
+        t_assert(h3ErrorIsSuccess(err) == 0, "error is not a success");
+    }
+}
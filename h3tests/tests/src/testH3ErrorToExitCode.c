@@ -0,0 +1,47 @@
+/** @file
+ * @brief tests H3 function `h3ErrorToExitCode`
+ *
+ *  usage: `testH3ErrorToExitCode`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(h3ErrorToExitCode) {
+    TEST(success) {
+        t_assert(h3ErrorToExitCode(E_SUCCESS) == 0, "success is 0");
+    }
+
+    TEST(failure) {
+        t_assert(h3ErrorToExitCode(E_FAILED) == 1, "generic failure is 1");
+        t_assert(h3ErrorToExitCode(99) == 1, "unknown error is 1");
+    }
+
+    TEST(domain) {
+        t_assert(h3ErrorToExitCode(E_DOMAIN) == 2, "domain");
+        t_assert(h3ErrorToExitCode(E_LATLNG_DOMAIN) == 2, "latlng domain");
+        t_assert(h3ErrorToExitCode(E_RES_DOMAIN) == 2, "res domain");
+        t_assert(h3ErrorToExitCode(E_OPTION_INVALID) == 2, "option");
+    }
+
+    TEST(invalidIndex) {
+        t_assert(h3ErrorToExitCode(E_CELL_INVALID) == 3, "cell");
+        t_assert(h3ErrorToExitCode(E_DIR_EDGE_INVALID) == 3, "edge");
+        t_assert(h3ErrorToExitCode(E_UNDIR_EDGE_INVALID) == 3, "undir edge");
+        t_assert(h3ErrorToExitCode(E_VERTEX_INVALID) == 3, "vertex");
+    }
+
+    TEST(memory) {
+        t_assert(h3ErrorToExitCode(E_MEMORY_ALLOC) == 4, "alloc");
+        t_assert(h3ErrorToExitCode(E_MEMORY_BOUNDS) == 4, "bounds");
+    }
+
+    TEST(unsupportedInput) {
+        t_assert(h3ErrorToExitCode(E_PENTAGON) == 5, "pentagon");
+        t_assert(h3ErrorToExitCode(E_DUPLICATE_INPUT) == 5, "duplicate");
+        t_assert(h3ErrorToExitCode(E_NOT_NEIGHBORS) == 5, "not neighbors");
+        t_assert(h3ErrorToExitCode(E_RES_MISMATCH) == 5, "res mismatch");
+    }
+}
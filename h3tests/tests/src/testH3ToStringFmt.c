@@ -0,0 +1,58 @@
+/** @file
+ * @brief tests H3 function `h3ToStringFmt`
+ *
+ *  usage: `testH3ToStringFmt`
+ */
+
+#include <stdlib.h>
+#include <string.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void assertFmt(H3Index h, uint32_t flags, const char *expected) {
+    char buf[32];
+    t_assertSuccess(h3ToStringFmt(h, buf, sizeof(buf), flags));
+    t_assert(strcmp(buf, expected) == 0, "got expected string");
+}
+
+SUITE(h3ToStringFmt) {
+    H3Index sf = 0x8928308280fffff;
+
+    TEST(flags) {
+        assertFmt(sf, 0, "8928308280fffff");
+        assertFmt(sf, H3_STRING_UPPERCASE, "8928308280FFFFF");
+        assertFmt(sf, H3_STRING_PREFIX, "0x8928308280fffff");
+        assertFmt(sf, H3_STRING_UPPERCASE | H3_STRING_PREFIX,
+                  "0x8928308280FFFFF");
+    }
+
+    TEST(sameAsH3ToString) {
+        char expected[17];
+        char buf[17];
+        t_assertSuccess(h3ToString(sf, expected, sizeof(expected)));
+        t_assertSuccess(h3ToStringFmt(sf, buf, sizeof(buf), 0));
+        t_assert(strcmp(buf, expected) == 0, "no flags means h3ToString");
+    }
+
+    TEST(tightBuffer) {
+        // 15 digits, the prefix and the null terminator.
+        char buf[18];
+        t_assertSuccess(h3ToStringFmt(sf, buf, 18, H3_STRING_PREFIX));
+        t_assert(strcmp(buf, "0x8928308280fffff") == 0, "fits exactly");
+        t_assert(h3ToStringFmt(sf, buf, 17, H3_STRING_PREFIX) ==
+                     E_MEMORY_BOUNDS,
+                 "no room for the null terminator");
+        t_assertSuccess(h3ToStringFmt(sf, buf, 16, 0));
+        t_assert(strcmp(buf, "8928308280fffff") == 0,
+                 "size depends on the index");
+        t_assert(h3ToStringFmt(sf, buf, 15, 0) == E_MEMORY_BOUNDS,
+                 "too small buffer is rejected");
+    }
+
+    TEST(invalidFlags) {
+        char buf[32];
+        t_assert(h3ToStringFmt(sf, buf, sizeof(buf), 4) == E_OPTION_INVALID,
+                 "unknown flag is rejected");
+    }
+}
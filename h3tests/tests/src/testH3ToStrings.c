@@ -0,0 +1,56 @@
+/** @file
+ * @brief tests H3 function `h3ToStrings`
+ *
+ *  usage: `testH3ToStrings`
+ */
+
+#include <stdlib.h>
+#include <string.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define STRIDE 20
+
+SUITE(h3ToStrings) {
+    H3Index hs[4] = {0x8928308280fffff, 0x85283473fffffff, 0x1, 0};
+
+    TEST(rows) {
+        char out[4 * STRIDE];
+        memset(out, 'x', sizeof(out));
+        t_assertSuccess(h3ToStrings(hs, 4, out, STRIDE));
+
+        for (int i = 0; i < 4; i++) {
+            char expected[17];
+            t_assertSuccess(h3ToString(hs[i], expected, sizeof(expected)));
+            t_assert(strcmp(out + i * STRIDE, expected) == 0,
+                     "row matches h3ToString");
+
+        }
+        for (int i = 0; i < 2; i++) {
+            H3Index parsed;
+            t_assertSuccess(stringToH3(out + i * STRIDE, &parsed));
+            t_assert(parsed == hs[i], "row reads back");
+        }
+        t_assert(strcmp(out + 2 * STRIDE, "1") == 0,
+                 "invalid index is still converted");
+        t_assert(out[STRIDE - 1] == 'x', "padding is left untouched");
+    }
+
+    TEST(tightStride) {
+        H3Index max[2] = {0xffffffffffffffff, 0x8928308280fffff};
+        char out[2 * 17];
+        t_assertSuccess(h3ToStrings(max, 2, out, 17));
+        t_assert(strcmp(out, "ffffffffffffffff") == 0, "16 digits fit");
+        t_assert(strcmp(out + 17, "8928308280fffff") == 0, "second row");
+    }
+
+    TEST(invalid) {
+        char out[4 * STRIDE];
+        t_assert(h3ToStrings(hs, 4, out, 16) == E_MEMORY_BOUNDS,
+                 "small stride is rejected");
+        t_assert(h3ToStrings(hs, -1, out, STRIDE) == E_DOMAIN,
+                 "negative size is rejected");
+        t_assertSuccess(h3ToStrings(NULL, 0, NULL, STRIDE));
+    }
+}
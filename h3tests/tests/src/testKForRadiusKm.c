@@ -0,0 +1,84 @@
+/** @file
+ * @brief tests H3 function `kForRadiusKm`
+ *
+ *  usage: `testKForRadiusKm`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static void assertDiskCovers(H3Index origin, double radiusKm) {
+    int k = 0;
+    t_assertSuccess(kForRadiusKm(origin, radiusKm, &k));
+    t_assert(k >= 0, "k is non-negative");
+
+    // Scan a wider disk for cells within the radius.
+    int outer = k + 2;
+    int64_t size;
+    t_assertSuccess(maxGridDiskSize(outer, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    t_assertSuccess(gridDisk(origin, outer, cells));
+
+    LatLng center;
+    t_assertSuccess(cellToLatLng(origin, &center));
+    int found = 0;
+    for (int64_t i = 0; i < size; i++) {
+        if (cells[i] == H3_NULL) {
+            continue;
+        }
+        LatLng ll;
+        t_assertSuccess(cellToLatLng(cells[i], &ll));
+        if (greatCircleDistanceKm(&center, &ll) > radiusKm) {
+            continue;
+        }
+        int64_t distance;
+        t_assertSuccess(gridDistance(origin, cells[i], &distance));
+        t_assert(distance <= k, "cell within the radius is in the disk");
+        found++;
+    }
+    t_assert(found > 0, "at least the origin is within the radius");
+    free(cells);
+}
+
+SUITE(kForRadiusKm) {
+    TEST(hexagon) {
+        H3Index origin = 0x8928308280fffff;
+        LatLng center;
+        t_assertSuccess(cellToLatLng(origin, &center));
+
+        // Radius reaching exactly the center of a cell three rings away.
+        H3Index ring[18];
+        t_assertSuccess(gridRingUnsafe(origin, 3, ring));
+        LatLng edge;
+        t_assertSuccess(cellToLatLng(ring[0], &edge));
+        double radiusKm = greatCircleDistanceKm(&center, &edge);
+
+        int k = 0;
+        t_assertSuccess(kForRadiusKm(origin, radiusKm, &k));
+        t_assert(k >= 3, "edge cell is in the disk");
+        assertDiskCovers(origin, radiusKm);
+        assertDiskCovers(origin, 0.001);
+        assertDiskCovers(origin, 2.5);
+    }
+
+    TEST(pentagon) {
+        H3Index origin = 0x85080003fffffff;
+        assertDiskCovers(origin, 10);
+        assertDiskCovers(origin, 50);
+    }
+
+    TEST(invalid) {
+        int k = 0;
+        t_assert(kForRadiusKm(0x8928308280fffff, 0, &k) == E_DOMAIN,
+                 "zero radius");
+        t_assert(kForRadiusKm(0x8928308280fffff, -1, &k) == E_DOMAIN,
+                 "negative radius");
+        t_assert(kForRadiusKm(0x8928308280fffff, NAN, &k) == E_DOMAIN,
+                 "NaN radius");
+        t_assert(kForRadiusKm(0x7fffffffffffffff, 1, &k) == E_CELL_INVALID,
+                 "invalid cell");
+    }
+}
@@ -0,0 +1,33 @@
+/** @file
+ * @brief tests H3 functions `latLngGetLatDegs`, `latLngGetLngDegs` and
+ * `latLngSetDegs`
+ *
+ *  usage: `testLatLngDegs`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(latLngDegs) {
+    TEST(roundTrip) {
+        LatLng sf;
+        latLngSetDegs(&sf, 37.7749, -122.4194);
+        t_assert(fabs(sf.lat - degsToRads(37.7749)) < 1e-12,
+                 "latitude stored in radians");
+        t_assert(fabs(sf.lng - degsToRads(-122.4194)) < 1e-12,
+                 "longitude stored in radians");
+        t_assert(fabs(latLngGetLatDegs(&sf) - 37.7749) < 1e-12,
+                 "latitude round-trips");
+        t_assert(fabs(latLngGetLngDegs(&sf) + 122.4194) < 1e-12,
+                 "longitude round-trips");
+    }
+
+    TEST(nullPointer) {
+        t_assert(isnan(latLngGetLatDegs(NULL)), "NULL latitude is NaN");
+        t_assert(isnan(latLngGetLngDegs(NULL)), "NULL longitude is NaN");
+        latLngSetDegs(NULL, 0, 0);
+    }
+}
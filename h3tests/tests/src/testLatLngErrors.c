@@ -0,0 +1,40 @@
+/** @file
+ * @brief tests how invalid coordinates are reported
+ *
+ *  usage: `testLatLngErrors`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static const H3Index sfCell = 0x8928308280fffff;
+
+SUITE(latLngErrors) {
+    TEST(degrees) {
+        int contained;
+        LatLng nan = {NAN, 0};
+        t_assert(cellContainsLatLng(sfCell, &nan, &contained) == E_FAILED,
+                 "non-finite latitude is malformed");
+        LatLng inf = {0, INFINITY};
+        t_assert(cellContainsLatLng(sfCell, &inf, &contained) == E_FAILED,
+                 "infinite longitude is malformed");
+        LatLng offGlobe = {100, 0};
+        t_assert(cellContainsLatLng(sfCell, &offGlobe, &contained) ==
+                     E_LATLNG_DOMAIN,
+                 "latitude beyond the poles is off the globe");
+    }
+
+    TEST(radians) {
+        // Radian-based H3 functions keep the H3 error.
+        H3Index h;
+        LatLng nan = {NAN, 0};
+        t_assert(latLngToCell(&nan, 1, &h) == E_LATLNG_DOMAIN,
+                 "non-finite latitude is out of domain");
+        LatLng inf = {0, INFINITY};
+        t_assert(latLngToCell(&inf, 1, &h) == E_LATLNG_DOMAIN,
+                 "infinite longitude is out of domain");
+    }
+}
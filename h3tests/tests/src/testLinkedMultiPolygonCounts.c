@@ -0,0 +1,65 @@
+/** @file
+ * @brief tests H3 function `linkedMultiPolygonCounts`
+ *
+ *  usage: `testLinkedMultiPolygonCounts`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(linkedMultiPolygonCounts) {
+    TEST(nullPolygon) {
+        int numPolygons = -1, numLoops = -1, numVerts = -1;
+        t_assertSuccess(
+            linkedMultiPolygonCounts(NULL, &numPolygons, &numLoops, &numVerts));
+        t_assert(numPolygons == 0, "no polygon");
+        t_assert(numLoops == 0, "no loop");
+        t_assert(numVerts == 0, "no vertex");
+    }
+
+    TEST(emptyPolygon) {
+        LinkedGeoPolygon polygon;
+        t_assertSuccess(cellsToLinkedMultiPolygon(NULL, 0, &polygon));
+
+        int numPolygons = -1, numLoops = -1, numVerts = -1;
+        t_assertSuccess(linkedMultiPolygonCounts(&polygon, &numPolygons,
+                                                 &numLoops, &numVerts));
+        t_assert(numPolygons == 0, "no polygon");
+        t_assert(numLoops == 0, "no loop");
+        t_assert(numVerts == 0, "no vertex");
+    }
+
+    TEST(multiPolygon) {
+        // Two disjoint hexagons.
+        H3Index set[] = {0x8928308291bffff, 0x89283082943ffff};
+        LinkedGeoPolygon polygon;
+        t_assertSuccess(cellsToLinkedMultiPolygon(set, 2, &polygon));
+
+        int numPolygons, numLoops, numVerts;
+        t_assertSuccess(linkedMultiPolygonCounts(&polygon, &numPolygons,
+                                                 &numLoops, &numVerts));
+        t_assert(numPolygons == 2, "two polygons");
+        t_assert(numLoops == 2, "one loop per polygon");
+        t_assert(numVerts == 12, "six vertices per loop");
+
+        destroyLinkedMultiPolygon(&polygon);
+    }
+
+    TEST(polygonWithHole) {
+        // A ring of cells around a missing center cell.
+        H3Index ring[6];
+        t_assertSuccess(gridRingUnsafe(0x8928308280fffff, 1, ring));
+        LinkedGeoPolygon polygon;
+        t_assertSuccess(cellsToLinkedMultiPolygon(ring, 6, &polygon));
+
+        int numPolygons, numLoops;
+        t_assertSuccess(linkedMultiPolygonCounts(&polygon, &numPolygons,
+                                                 &numLoops, NULL));
+        t_assert(numPolygons == 1, "one polygon");
+        t_assert(numLoops == 2, "outer loop and hole");
+
+        destroyLinkedMultiPolygon(&polygon);
+    }
+}
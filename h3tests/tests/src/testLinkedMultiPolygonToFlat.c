@@ -0,0 +1,96 @@
+/** @file
+ * @brief tests H3 function `linkedMultiPolygonToFlat`
+ *
+ *  usage: `testLinkedMultiPolygonToFlat`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(linkedMultiPolygonToFlat) {
+    // Two disjoint polygons, one of them with a hole.
+    H3Index set[8];
+    t_assertSuccess(gridRingUnsafe(0x8928308280fffff, 1, set));
+    set[6] = 0x8928308291bffff;
+    set[7] = 0x89283082943ffff;
+    LinkedGeoPolygon polygon;
+    t_assertSuccess(cellsToLinkedMultiPolygon(set, 8, &polygon));
+
+    int numPolygons, numLoops, numVerts;
+    t_assertSuccess(
+        linkedMultiPolygonCounts(&polygon, &numPolygons, &numLoops, &numVerts));
+
+    TEST(roundTrip) {
+        double *coords = calloc(2 * numVerts, sizeof(double));
+        int *ringLens = calloc(numLoops, sizeof(int));
+        int *polyRingCounts = calloc(numPolygons, sizeof(int));
+        t_assertSuccess(linkedMultiPolygonToFlat(&polygon, coords,
+                                                 2 * numVerts, ringLens,
+                                                 numLoops, polyRingCounts,
+                                                 numPolygons));
+
+        // Rebuild the topology from the flat arrays and compare.
+        int polyIdx = 0, ringIdx = 0, coordIdx = 0;
+        for (LinkedGeoPolygon *poly = &polygon; poly != NULL;
+             poly = poly->next, polyIdx++) {
+            int ringCount = 0;
+            for (LinkedGeoLoop *loop = poly->first; loop != NULL;
+                 loop = loop->next, ringIdx++, ringCount++) {
+                int vertCount = 0;
+                for (LinkedLatLng *coord = loop->first; coord != NULL;
+                     coord = coord->next, coordIdx += 2, vertCount++) {
+                    t_assert(fabs(coords[coordIdx] -
+                                  radsToDegs(coord->vertex.lat)) < 1e-9,
+                             "latitude matches");
+                    t_assert(fabs(coords[coordIdx + 1] -
+                                  radsToDegs(coord->vertex.lng)) < 1e-9,
+                             "longitude matches");
+                }
+                t_assert(ringLens[ringIdx] == vertCount, "ring length matches");
+            }
+            t_assert(polyRingCounts[polyIdx] == ringCount,
+                     "ring count matches");
+        }
+        t_assert(polyIdx == numPolygons, "all polygons visited");
+        t_assert(ringIdx == numLoops, "all rings visited");
+        t_assert(coordIdx == 2 * numVerts, "all coordinates visited");
+
+        free(polyRingCounts);
+        free(ringLens);
+        free(coords);
+    }
+
+    TEST(tooSmall) {
+        double *coords = calloc(2 * numVerts, sizeof(double));
+        int *ringLens = calloc(numLoops, sizeof(int));
+        int *polyRingCounts = calloc(numPolygons, sizeof(int));
+
+        t_assert(linkedMultiPolygonToFlat(&polygon, coords, 2 * numVerts - 1,
+                                          ringLens, numLoops, polyRingCounts,
+                                          numPolygons) == E_MEMORY_BOUNDS,
+                 "coordinates capacity is checked");
+        t_assert(linkedMultiPolygonToFlat(&polygon, coords, 2 * numVerts,
+                                          ringLens, numLoops - 1,
+                                          polyRingCounts,
+                                          numPolygons) == E_MEMORY_BOUNDS,
+                 "rings capacity is checked");
+        t_assert(linkedMultiPolygonToFlat(&polygon, coords, 2 * numVerts,
+                                          ringLens, numLoops, polyRingCounts,
+                                          numPolygons - 1) == E_MEMORY_BOUNDS,
+                 "polygons capacity is checked");
+
+        free(polyRingCounts);
+        free(ringLens);
+        free(coords);
+    }
+
+    TEST(empty) {
+        t_assertSuccess(
+            linkedMultiPolygonToFlat(NULL, NULL, 0, NULL, 0, NULL, 0));
+    }
+
+    destroyLinkedMultiPolygon(&polygon);
+}
@@ -0,0 +1,79 @@
+/** @file
+ * @brief tests H3 function `localIjRectToCells`
+ *
+ *  usage: `testLocalIjRectToCells`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+/** Checks the rectangle against per-coordinate localIjToCell calls. */
+static void assertRect(H3Index origin, int radius) {
+    CoordIJ center;
+    t_assertSuccess(cellToLocalIj(origin, origin, 0, &center));
+    int iMin = center.i - radius, iMax = center.i + radius;
+    int jMin = center.j - radius, jMax = center.j + radius;
+    int size = (2 * radius + 1) * (2 * radius + 1);
+
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    int64_t count = -1;
+    t_assertSuccess(
+        localIjRectToCells(origin, iMin, iMax, jMin, jMax, cells, &count));
+
+    int64_t expected = 0;
+    for (int i = iMin; i <= iMax; i++) {
+        for (int j = jMin; j <= jMax; j++) {
+            CoordIJ ij = {i, j};
+            H3Index cell;
+            if (localIjToCell(origin, &ij, 0, &cell) == E_SUCCESS) {
+                t_assert(expected < count && cells[expected] == cell,
+                         "same cells, in the same order");
+                expected++;
+            }
+        }
+    }
+    t_assert(count == expected, "same number of cells");
+    free(cells);
+}
+
+SUITE(localIjRectToCells) {
+    TEST(hexagon) {
+        assertRect(0x8928308280fffff, 0);
+        assertRect(0x8928308280fffff, 2);
+
+        H3Index cells[9] = {0};
+        int64_t count = -1;
+        CoordIJ center;
+        t_assertSuccess(cellToLocalIj(0x8928308280fffff, 0x8928308280fffff,
+                                      0, &center));
+        t_assertSuccess(localIjRectToCells(0x8928308280fffff, center.i - 1,
+                                           center.i + 1, center.j - 1,
+                                           center.j + 1, cells, &count));
+        t_assert(count == 9, "full rectangle away from pentagons");
+    }
+
+    TEST(pentagon) {
+        H3Index cells[25] = {0};
+        int64_t count = -1;
+        assertRect(0x8009fffffffffff, 2);
+        t_assertSuccess(
+            localIjRectToCells(0x8009fffffffffff, -2, 2, -2, 2, cells, &count));
+        t_assert(count < 25, "coordinates across the pentagon are skipped");
+    }
+
+    TEST(invalid) {
+        H3Index cells[1] = {0};
+        int64_t count;
+        t_assert(localIjRectToCells(0x8928308280fffff, 1, 0, 0, 0, cells,
+                                    &count) == E_DOMAIN,
+                 "empty i range");
+        t_assert(localIjRectToCells(0x8928308280fffff, 0, 0, 1, 0, cells,
+                                    &count) == E_DOMAIN,
+                 "empty j range");
+        t_assert(localIjRectToCells(0, 0, 0, 0, 0, cells, &count) ==
+                     E_CELL_INVALID,
+                 "invalid origin");
+    }
+}
@@ -0,0 +1,55 @@
+/** @file
+ * @brief tests H3 function `localIjToCellStrict`
+ *
+ *  usage: `testLocalIjToCellStrict`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(localIjToCellStrict) {
+    TEST(roundTrip) {
+        H3Index sf = 0x8928308280fffff;
+        H3Index disk[19] = {0};
+        t_assertSuccess(gridDisk(sf, 2, disk));
+        for (int i = 0; i < 19; i++) {
+            CoordIJ ij;
+            t_assertSuccess(cellToLocalIj(sf, disk[i], 0, &ij));
+            H3Index cell;
+            t_assertSuccess(localIjToCellStrict(sf, &ij, 0, &cell));
+            t_assert(cell == disk[i], "cell round-trips");
+        }
+    }
+
+    TEST(nearPentagon) {
+        // Next to a res 3 pentagon.
+        H3Index origin = 0x830802fffffffff;
+        CoordIJ ij = {0, -1};
+
+        H3Index cell;
+        t_assertSuccess(localIjToCell(origin, &ij, 0, &cell));
+        CoordIJ back;
+        int roundTrips = cellToLocalIj(origin, cell, 0, &back) == E_SUCCESS &&
+                         back.i == ij.i && back.j == ij.j;
+        t_assert(!roundTrips, "non-strict cell fails the round-trip");
+
+        t_assert(localIjToCellStrict(origin, &ij, 0, &cell) == E_FAILED,
+                 "strict version rejects the cell");
+    }
+
+    TEST(invalid) {
+        CoordIJ ij = {0, 0};
+        H3Index cell;
+        t_assert(localIjToCellStrict(0x7fffffffffffffff, &ij, 0, &cell) ==
+                     E_CELL_INVALID,
+                 "invalid origin is rejected");
+        t_assert(localIjToCellStrict(0x8928308280fffff, &ij, 1, &cell) ==
+                     E_DOMAIN,
+                 "invalid mode is rejected");
+        t_assert(localIjToCellStrict(0x8928308280fffff, NULL, 0, &cell) ==
+                     E_FAILED,
+                 "missing coordinates are rejected");
+    }
+}
@@ -0,0 +1,47 @@
+/** @file
+ * @brief tests H3 function `maxPolygonToCellsSize`
+ *
+ *  usage: `testMaxPolygonToCellsSize`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(maxPolygonToCellsSize) {
+    // Nearly global polygon, with no edge longer than 180 degrees of
+    // longitude.
+    LatLng verts[] = {{-1.48, -3.12}, {-1.48, -1.57}, {-1.48, 0},
+                      {-1.48, 1.57},  {-1.48, 3.12},  {1.48, 3.12},
+                      {1.48, 1.57},   {1.48, 0},      {1.48, -1.57},
+                      {1.48, -3.12}};
+    GeoLoop geoloop = {.numVerts = 10, .verts = verts};
+    GeoLoop noHoles[1];
+    GeoPolygon polygon = {.geoloop = geoloop, .numHoles = 0, .holes = noHoles};
+
+    TEST(nearGlobalPolygon) {
+        // Even at res 15, the estimate fits in 64 bits.
+        const int64_t expected[] = {88,
+                                    666,
+                                    4308,
+                                    34270,
+                                    215258,
+                                    1694940,
+                                    10581058,
+                                    83166265,
+                                    518710025,
+                                    4075950645,
+                                    25418462568,
+                                    199727211473,
+                                    1245516367793,
+                                    9786672737973,
+                                    61030383239185,
+                                    479547230183359};
+        for (int res = 0; res <= 15; res++) {
+            int64_t size = -1;
+            t_assertSuccess(maxPolygonToCellsSize(&polygon, res, 0, &size));
+            t_assert(size == expected[res], "expected estimate");
+        }
+    }
+}
@@ -0,0 +1,84 @@
+/** @file
+ * @brief tests H3 function `normalizeGeoPolygon`
+ *
+ *  usage: `testNormalizeGeoPolygon`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+// Shoelace formula, positive for counter-clockwise loops.
+static double signedArea(const GeoLoop *loop) {
+    double area = 0;
+    for (int i = 0; i < loop->numVerts; i++) {
+        LatLng a = loop->verts[i];
+        LatLng b = loop->verts[(i + 1) % loop->numVerts];
+        area += a.lng * b.lat - b.lng * a.lat;
+    }
+    return area / 2;
+}
+
+SUITE(normalizeGeoPolygon) {
+    TEST(closedRing) {
+        // Clockwise and closed.
+        LatLng verts[] = {{0.659, -2.136}, {0.659, -2.130}, {0.653, -2.130},
+                          {0.653, -2.136}, {0.659, -2.136}};
+        GeoLoop noHoles[1];
+        GeoPolygon polygon = {
+            .geoloop = {.numVerts = 5, .verts = verts},
+            .numHoles = 0,
+            .holes = noHoles,
+        };
+        t_assertSuccess(normalizeGeoPolygon(&polygon));
+        t_assert(polygon.geoloop.numVerts == 4, "closing vertex removed");
+        t_assert(signedArea(&polygon.geoloop) > 0, "exterior is ccw");
+
+        int64_t size = 0;
+        t_assertSuccess(maxPolygonToCellsSize(&polygon, 7, 0, &size));
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        t_assertSuccess(polygonToCells(&polygon, 7, 0, cells));
+        free(cells);
+    }
+
+    TEST(reversedHole) {
+        LatLng outer[] = {{0.653, -2.136}, {0.653, -2.130}, {0.659, -2.130},
+                          {0.659, -2.136}};
+        // Counter-clockwise hole.
+        LatLng inner[] = {{0.655, -2.134}, {0.655, -2.132}, {0.657, -2.132},
+                          {0.657, -2.134}};
+        GeoLoop hole = {.numVerts = 4, .verts = inner};
+        GeoPolygon polygon = {
+            .geoloop = {.numVerts = 4, .verts = outer},
+            .numHoles = 1,
+            .holes = &hole,
+        };
+        t_assert(signedArea(&hole) > 0, "hole starts ccw");
+        t_assertSuccess(normalizeGeoPolygon(&polygon));
+        t_assert(signedArea(&polygon.geoloop) > 0, "exterior stays ccw");
+        t_assert(polygon.geoloop.verts[0].lat == 0.653 &&
+                     polygon.geoloop.verts[0].lng == -2.136,
+                 "exterior untouched");
+        t_assert(hole.numVerts == 4, "hole keeps its vertices");
+        t_assert(signedArea(&hole) < 0, "hole is cw");
+    }
+
+    TEST(unfixable) {
+        LatLng verts[] = {{0.659, -2.136}, {0.653, -2.130}, {0.659, -2.136}};
+        GeoLoop noHoles[1];
+        GeoPolygon polygon = {
+            .geoloop = {.numVerts = 3, .verts = verts},
+            .numHoles = 0,
+            .holes = noHoles,
+        };
+        t_assert(normalizeGeoPolygon(&polygon) == E_FAILED,
+                 "two distinct vertices fail");
+
+        LatLng flat[] = {{0.1, 0.1}, {0.2, 0.2}, {0.3, 0.3}};
+        polygon.geoloop.verts = flat;
+        t_assert(normalizeGeoPolygon(&polygon) == E_FAILED,
+                 "zero area fails");
+        t_assert(normalizeGeoPolygon(NULL) == E_FAILED, "null fails");
+    }
+}
@@ -0,0 +1,81 @@
+/** @file
+ * @brief tests H3 function `pointsToEnclosingCell`
+ *
+ *  usage: `testPointsToEnclosingCell`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+/** Checks that every point, in degrees, is in the cell. */
+static int containsAll(H3Index cell, const LatLng *points, int n) {
+    for (int i = 0; i < n; i++) {
+        LatLng point;
+        H3Index pointCell;
+        latLngSetDegs(&point, points[i].lat, points[i].lng);
+        t_assertSuccess(latLngToCell(&point, getResolution(cell), &pointCell));
+        if (pointCell != cell) {
+            return 0;
+        }
+    }
+    return 1;
+}
+
+SUITE(pointsToEnclosingCell) {
+    TEST(clustered) {
+        LatLng points[] = {{37.77490, -122.41940},
+                           {37.77491, -122.41941},
+                           {37.77492, -122.41939}};
+        H3Index cell = 0;
+        int res = -1;
+        t_assertSuccess(pointsToEnclosingCell(points, 3, &cell, &res));
+        t_assert(res >= 10, "clustered points yield a fine resolution");
+        t_assert(getResolution(cell) == res, "cell at the resolution");
+        t_assert(containsAll(cell, points, 3), "cell contains the points");
+    }
+
+    TEST(scattered) {
+        LatLng points[] = {{37.7749, -122.4194},
+                           {36.1699, -115.1398},
+                           {34.0522, -118.2437}};
+        H3Index cell = 0;
+        int res = -1;
+        t_assertSuccess(pointsToEnclosingCell(points, 3, &cell, &res));
+        t_assert(res <= 1, "scattered points yield a coarse resolution");
+        t_assert(containsAll(cell, points, 3), "cell contains the points");
+    }
+
+    TEST(singlePoint) {
+        LatLng points[] = {{37.7749, -122.4194}};
+        int res = -1;
+        t_assertSuccess(pointsToEnclosingCell(points, 1, NULL, &res));
+        t_assert(res == 15, "a single point is enclosed at res 15");
+    }
+
+    TEST(noEnclosingCell) {
+        LatLng points[] = {{37.7749, -122.4194}, {-33.8688, 151.2093}};
+        H3Index cell;
+        t_assert(pointsToEnclosingCell(points, 2, &cell, NULL) == E_FAILED,
+                 "points on different base cells");
+    }
+
+    TEST(invalid) {
+        LatLng points[] = {{37.7749, -122.4194}};
+        H3Index cell;
+        t_assert(pointsToEnclosingCell(points, 0, &cell, NULL) == E_DOMAIN,
+                 "no points");
+        t_assert(pointsToEnclosingCell(NULL, 1, &cell, NULL) == E_FAILED,
+                 "null points");
+
+        LatLng nan[] = {{37.7749, -122.4194}, {NAN, -122.4194}};
+        t_assert(pointsToEnclosingCell(nan, 2, &cell, NULL) == E_FAILED,
+                 "non-finite point");
+        LatLng offGlobe[] = {{37.7749, -122.4194}, {91, -122.4194}};
+        t_assert(
+            pointsToEnclosingCell(offGlobe, 2, &cell, NULL) == E_LATLNG_DOMAIN,
+            "latitude beyond the pole");
+    }
+}
@@ -106,7 +106,7 @@ static void fillIndex_assertions(H3Index h) {
         int64_t childrenSize;
         t_assertSuccess(cellToChildrenSize(h, nextRes, &childrenSize));
         H3Index *children = calloc(childrenSize, sizeof(H3Index));
-        t_assertSuccess(cellToChildren(h, nextRes, children));
+        t_assertSuccess(cellToChildren(h, nextRes, children, childrenSize));
 
         int64_t cellToChildrenCount =
             countNonNullIndexes(children, childrenSize);
@@ -0,0 +1,176 @@
+/** @file
+ * @brief tests polygonToCells tie-breaking for cell centers on the boundary
+ *
+ *  usage: `testPolygonToCellsBoundary`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static const H3Index origin = 0x8928308280fffff;
+static const int res = 9;
+static GeoLoop noHoles[1];
+
+static bool fillContains(GeoPolygon *polygon, H3Index cell) {
+    int64_t size;
+    t_assertSuccess(maxPolygonToCellsSize(polygon, res, 0, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    t_assertSuccess(polygonToCells(polygon, res, 0, cells));
+
+    int64_t count;
+    t_assertSuccess(polygonToCellsCount(polygon, res, 0, &count));
+    int64_t found = 0;
+    bool contains = false;
+    for (int64_t i = 0; i < size; i++) {
+        if (cells[i] != H3_NULL) {
+            found++;
+            contains |= cells[i] == cell;
+        }
+    }
+    t_assert(found == count, "count matches the fill");
+    free(cells);
+    return contains;
+}
+
+static bool bboxContains(double north, double south, double east, double west,
+                         H3Index cell) {
+    int64_t size;
+    t_assertSuccess(
+        maxBboxToCellsSize(north, south, east, west, res, 0, &size));
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    t_assertSuccess(bboxToCells(north, south, east, west, res, 0, cells));
+
+    bool contains = false;
+    for (int64_t i = 0; i < size; i++) {
+        contains |= cells[i] == cell;
+    }
+    free(cells);
+    return contains;
+}
+
+SUITE(polygonToCellsBoundary) {
+    LatLng center;
+    t_assertSuccess(cellToLatLng(origin, &center));
+    double d = 0.0002;
+
+    TEST(westEdge) {
+        // The origin center lies exactly on the western edge.
+        LatLng verts[] = {{center.lat - d, center.lng},
+                          {center.lat - d, center.lng + d},
+                          {center.lat + d, center.lng + d},
+                          {center.lat + d, center.lng}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = verts},
+                          .holes = noHoles};
+        t_assert(fillContains(&polygon, origin), "center on edge is inside");
+        t_assert(fillContains(&polygon, origin), "consistent across runs");
+    }
+
+    TEST(eastEdge) {
+        LatLng verts[] = {{center.lat - d, center.lng - d},
+                          {center.lat - d, center.lng},
+                          {center.lat + d, center.lng},
+                          {center.lat + d, center.lng - d}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = verts},
+                          .holes = noHoles};
+        t_assert(fillContains(&polygon, origin), "center on edge is inside");
+    }
+
+    TEST(southAndNorthEdges) {
+        LatLng above[] = {{center.lat, center.lng - d},
+                          {center.lat, center.lng + d},
+                          {center.lat + d, center.lng + d},
+                          {center.lat + d, center.lng - d}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = above},
+                          .holes = noHoles};
+        t_assert(fillContains(&polygon, origin), "center on south edge");
+
+        LatLng below[] = {{center.lat - d, center.lng - d},
+                          {center.lat - d, center.lng + d},
+                          {center.lat, center.lng + d},
+                          {center.lat, center.lng - d}};
+        polygon.geoloop.verts = below;
+        t_assert(fillContains(&polygon, origin), "center on north edge");
+    }
+
+    TEST(holeEdge) {
+        // The origin center lies on the edge of a hole: still inside.
+        LatLng outer[] = {{center.lat - 2 * d, center.lng - 2 * d},
+                          {center.lat - 2 * d, center.lng + 2 * d},
+                          {center.lat + 2 * d, center.lng + 2 * d},
+                          {center.lat + 2 * d, center.lng - 2 * d}};
+        LatLng inner[] = {{center.lat - d, center.lng},
+                          {center.lat + d, center.lng},
+                          {center.lat + d, center.lng + d},
+                          {center.lat - d, center.lng + d}};
+        GeoLoop hole = {.numVerts = 4, .verts = inner};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = outer},
+                              .numHoles = 1,
+                              .holes = &hole};
+        t_assert(fillContains(&polygon, origin), "center on hole edge");
+    }
+
+    TEST(vertex) {
+        LatLng verts[] = {{center.lat, center.lng},
+                          {center.lat, center.lng + d},
+                          {center.lat + d, center.lng + d},
+                          {center.lat + d, center.lng}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = verts},
+                          .holes = noHoles};
+        t_assert(fillContains(&polygon, origin), "center on vertex");
+    }
+
+    TEST(diagonalEdge) {
+        // The origin center lies on the diagonal shared by two triangles.
+        LatLng below[] = {{center.lat - d, center.lng - d},
+                          {center.lat - d, center.lng + d},
+                          {center.lat + d, center.lng + d}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 3, .verts = below},
+                              .holes = noHoles};
+        t_assert(fillContains(&polygon, origin), "center on diagonal");
+
+        LatLng above[] = {{center.lat - d, center.lng - d},
+                          {center.lat + d, center.lng + d},
+                          {center.lat + d, center.lng - d}};
+        polygon.geoloop.verts = above;
+        t_assert(fillContains(&polygon, origin), "center on diagonal");
+    }
+
+    TEST(slantedEdges) {
+        // Edges through the origin center (off their middle), with the
+        // polygon on either side of them.
+        for (int i = 0; i < 12; i++) {
+            double angle = i * M_PI / 12 + 0.1;
+            double dLat = d * sin(angle);
+            double dLng = d * cos(angle);
+            LatLng left[] = {{center.lat - dLat, center.lng - dLng},
+                             {center.lat + 2 * dLat, center.lng + 2 * dLng},
+                             {center.lat + dLng, center.lng - dLat}};
+            LatLng right[] = {{center.lat - dLat, center.lng - dLng},
+                              {center.lat - dLng, center.lng + dLat},
+                              {center.lat + 2 * dLat, center.lng + 2 * dLng}};
+            GeoPolygon polygon = {.geoloop = {.numVerts = 3, .verts = left},
+                                  .holes = noHoles};
+            t_assert(fillContains(&polygon, origin), "polygon on the left");
+            polygon.geoloop.verts = right;
+            t_assert(fillContains(&polygon, origin), "polygon on the right");
+        }
+    }
+
+    TEST(bboxEdges) {
+        // Same rule for boxes, whose bounds are in degrees.
+        double lat = radsToDegs(center.lat);
+        double lng = radsToDegs(center.lng);
+        double e = 0.01;
+        t_assert(bboxContains(lat + e, lat, lng + e, lng - e, origin),
+                 "center on south edge");
+        t_assert(bboxContains(lat, lat - e, lng + e, lng - e, origin),
+                 "center on north edge");
+        t_assert(bboxContains(lat + e, lat - e, lng + e, lng, origin),
+                 "center on west edge");
+        t_assert(bboxContains(lat + e, lat - e, lng, lng - e, origin),
+                 "center on east edge");
+    }
+}
@@ -0,0 +1,135 @@
+/** @file
+ * @brief tests H3 function `polygonToCellsClipped`
+ *
+ *  usage: `testPolygonToCellsClipped`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int contains(const H3Index *cells, int64_t len, H3Index cell) {
+    for (int64_t i = 0; i < len; i++) {
+        if (cells[i] == cell) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+SUITE(polygonToCellsClipped) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    GeoLoop sfGeoLoop = {.numVerts = 6, .verts = sfVerts};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {
+        .geoloop = sfGeoLoop, .numHoles = 0, .holes = noHoles};
+
+    TEST(halfMask) {
+        int64_t size;
+        t_assertSuccess(maxPolygonToCellsSize(&sfGeoPolygon, 9, 0, &size));
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        t_assertSuccess(polygonToCells(&sfGeoPolygon, 9, 0, cells));
+
+        // Keep every other cell of the polygon.
+        H3Index *mask = calloc(size, sizeof(H3Index));
+        int64_t maskLen = 0;
+        int64_t numCells = 0;
+        for (int64_t i = 0; i < size; i++) {
+            if (cells[i] == H3_NULL) {
+                continue;
+            }
+            if (numCells++ % 2 == 0) {
+                mask[maskLen++] = cells[i];
+            }
+        }
+        t_assert(maskLen > 0, "mask is not empty");
+
+        H3Index *clipped = calloc(size, sizeof(H3Index));
+        t_assertSuccess(polygonToCellsClipped(&sfGeoPolygon, 9, 0, mask,
+                                              maskLen, clipped));
+        int64_t numClipped = 0;
+        for (int64_t i = 0; i < size; i++) {
+            if (clipped[i] != H3_NULL) {
+                numClipped++;
+                t_assert(contains(mask, maskLen, clipped[i]),
+                         "clipped cell is in the mask");
+            }
+        }
+        t_assert(numClipped == maskLen, "every masked cell is found");
+
+        free(clipped);
+        free(mask);
+        free(cells);
+    }
+
+    TEST(boundaryCenters) {
+        // The eastern edge passes exactly through the centers of a column of
+        // cells, which polygonToCells counts as inside.
+        H3Index origin = 0x8928308280fffff;
+        LatLng c;
+        t_assertSuccess(cellToLatLng(origin, &c));
+        double d = 0.0002;
+        LatLng verts[] = {{c.lat - d, c.lng - d},
+                          {c.lat - d, c.lng},
+                          {c.lat + d, c.lng},
+                          {c.lat + d, c.lng - d}};
+        GeoPolygon polygon = {.geoloop = {.numVerts = 4, .verts = verts},
+                              .numHoles = 0,
+                              .holes = noHoles};
+
+        int64_t size;
+        t_assertSuccess(maxPolygonToCellsSize(&polygon, 9, 0, &size));
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        t_assertSuccess(polygonToCells(&polygon, 9, 0, cells));
+        t_assert(contains(cells, size, origin), "center on edge is inside");
+
+        // With the whole fill as mask, the clipped fill is the same.
+        H3Index *mask = calloc(size, sizeof(H3Index));
+        int64_t numCells = 0;
+        for (int64_t i = 0; i < size; i++) {
+            if (cells[i] != H3_NULL) {
+                mask[numCells++] = cells[i];
+            }
+        }
+        H3Index *clipped = calloc(size, sizeof(H3Index));
+        t_assertSuccess(
+            polygonToCellsClipped(&polygon, 9, 0, mask, numCells, clipped));
+        int64_t numClipped = 0;
+        for (int64_t i = 0; i < size; i++) {
+            numClipped += clipped[i] != H3_NULL;
+        }
+        t_assert(contains(clipped, size, origin), "center on edge is kept");
+        t_assert(numClipped == numCells, "same cells as polygonToCells");
+        free(clipped);
+        free(mask);
+        free(cells);
+    }
+
+    TEST(emptyMask) {
+        H3Index out[1] = {0};
+        t_assertSuccess(
+            polygonToCellsClipped(&sfGeoPolygon, 9, 0, NULL, 0, out));
+        t_assert(out[0] == H3_NULL, "no cell without mask");
+    }
+
+    TEST(invalidArgs) {
+        H3Index mask[] = {0x7fffffffffffffff};
+        H3Index out[1] = {0};
+        t_assert(polygonToCellsClipped(&sfGeoPolygon, 9, 0, mask, 1, out) ==
+                     E_CELL_INVALID,
+                 "invalid mask cell is rejected");
+        t_assert(polygonToCellsClipped(&sfGeoPolygon, 9, 1, mask, 1, out) ==
+                     E_OPTION_INVALID,
+                 "invalid flags are rejected");
+        t_assert(polygonToCellsClipped(&sfGeoPolygon, 9, 0, mask, -1, out) ==
+                     E_DOMAIN,
+                 "negative mask length is rejected");
+    }
+}
@@ -0,0 +1,64 @@
+/** @file
+ * @brief tests H3 function `polygonToCellsCount`
+ *
+ *  usage: `testPolygonToCellsCount`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(polygonToCellsCount) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {.geoloop = {.numVerts = 6, .verts = sfVerts},
+                               .numHoles = 0,
+                               .holes = noHoles};
+
+    TEST(matchesPolygonToCells) {
+        for (int res = 5; res <= 9; res++) {
+            int64_t size = 0;
+            t_assertSuccess(
+                maxPolygonToCellsSize(&sfGeoPolygon, res, 0, &size));
+            H3Index *cells = calloc(size, sizeof(H3Index));
+            t_assertSuccess(polygonToCells(&sfGeoPolygon, res, 0, cells));
+            int64_t expected = 0;
+            for (int64_t i = 0; i < size; i++) {
+                expected += cells[i] != H3_NULL;
+            }
+
+            int64_t count = -1;
+            t_assertSuccess(
+                polygonToCellsCount(&sfGeoPolygon, res, 0, &count));
+            t_assert(count == expected, "same count as polygonToCells");
+            free(cells);
+        }
+    }
+
+    TEST(empty) {
+        GeoPolygon empty = {
+            .geoloop = {.numVerts = 0}, .numHoles = 0, .holes = noHoles};
+        int64_t count = -1;
+        t_assertSuccess(polygonToCellsCount(&empty, 9, 0, &count));
+        t_assert(count == 0, "empty polygon has no cell");
+    }
+
+    TEST(invalid) {
+        int64_t count = -1;
+        t_assert(polygonToCellsCount(&sfGeoPolygon, 16, 0, &count) ==
+                     E_RES_DOMAIN,
+                 "invalid resolution fails");
+        t_assert(polygonToCellsCount(&sfGeoPolygon, 9, 42, &count) ==
+                     E_OPTION_INVALID,
+                 "invalid flags fail");
+        t_assert(polygonToCellsCount(NULL, 9, 0, &count) == E_FAILED,
+                 "null polygon fails");
+    }
+}
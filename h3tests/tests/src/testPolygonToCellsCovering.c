@@ -0,0 +1,117 @@
+/** @file
+ * @brief tests H3 function `polygonToCellsCovering`
+ *
+ *  usage: `testPolygonToCellsCovering`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+/** Checks that the point is in one of the cells. */
+static int isCovered(const LatLng *point, int res, const H3Index *cells,
+                     int64_t count) {
+    H3Index cell;
+    t_assertSuccess(latLngToCell(point, res, &cell));
+    for (int64_t i = 0; i < count; i++) {
+        if (cells[i] == cell) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+SUITE(polygonToCellsCovering) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    // A thin horizontal sliver, far thinner than a res 5 cell.
+    LatLng sliverVerts[] = {{0.6590000, -2.1400000},
+                            {0.6590000, -2.1300000},
+                            {0.6590001, -2.1300000},
+                            {0.6590001, -2.1400000}};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {.geoloop = {.numVerts = 6, .verts = sfVerts},
+                               .numHoles = 0,
+                               .holes = noHoles};
+    GeoPolygon sliver = {.geoloop = {.numVerts = 4, .verts = sliverVerts},
+                         .numHoles = 0,
+                         .holes = noHoles};
+
+    TEST(thinPolygon) {
+        int64_t centers = -1;
+        t_assertSuccess(polygonToCellsCount(&sliver, 5, 0, &centers));
+        t_assert(centers == 0, "no cell center in the sliver");
+
+        H3Index cells[32] = {0};
+        int64_t count = 0;
+        t_assertSuccess(
+            polygonToCellsCovering(&sliver, 5, 0, cells, 32, &count));
+        t_assert(count > 0, "sliver is covered");
+
+        for (int i = 0; i <= 100; i++) {
+            LatLng point = {0.65900005, -2.14 + i * 0.0001};
+            t_assert(isCovered(&point, 5, cells, count),
+                     "sliver points are covered");
+        }
+    }
+
+    TEST(supersetOfCenters) {
+        for (int res = 5; res <= 8; res++) {
+            int64_t size = 0;
+            t_assertSuccess(
+                maxPolygonToCellsSize(&sfGeoPolygon, res, 0, &size));
+            H3Index *centers = calloc(size, sizeof(H3Index));
+            t_assertSuccess(polygonToCells(&sfGeoPolygon, res, 0, centers));
+
+            int64_t cap = 4 * size + 64;
+            H3Index *cells = calloc(cap, sizeof(H3Index));
+            int64_t count = 0;
+            t_assertSuccess(polygonToCellsCovering(&sfGeoPolygon, res, 0,
+                                                   cells, cap, &count));
+            for (int64_t i = 0; i < size; i++) {
+                if (centers[i] == H3_NULL) {
+                    continue;
+                }
+                int found = 0;
+                for (int64_t j = 0; j < count; j++) {
+                    found |= cells[j] == centers[i];
+                }
+                t_assert(found, "contained cells are part of the covering");
+            }
+            for (int i = 0; i < 6; i++) {
+                t_assert(isCovered(&sfVerts[i], res, cells, count),
+                         "polygon vertices are covered");
+            }
+            free(cells);
+            free(centers);
+        }
+    }
+
+    TEST(memoryBounds) {
+        H3Index cells[1] = {0};
+        int64_t count = 0;
+        t_assert(polygonToCellsCovering(&sliver, 5, 0, cells, 1, &count) ==
+                     E_MEMORY_BOUNDS,
+                 "output too small");
+    }
+
+    TEST(invalid) {
+        H3Index cells[32] = {0};
+        int64_t count = 0;
+        t_assert(polygonToCellsCovering(&sliver, 16, 0, cells, 32, &count) ==
+                     E_RES_DOMAIN,
+                 "invalid resolution fails");
+        t_assert(polygonToCellsCovering(&sliver, 5, 42, cells, 32, &count) ==
+                     E_OPTION_INVALID,
+                 "invalid flags fail");
+        t_assert(polygonToCellsCovering(NULL, 5, 0, cells, 32, &count) ==
+                     E_FAILED,
+                 "null polygon fails");
+    }
+}
@@ -0,0 +1,83 @@
+/** @file
+ * @brief tests H3 function `polygonToCellsProgress`
+ *
+ *  usage: `testPolygonToCellsProgress`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+typedef struct {
+    int64_t seen;
+    int64_t limit;
+    H3Index *cells;
+} Progress;
+
+static int collect(H3Index cell, void *ctx) {
+    Progress *progress = ctx;
+    progress->cells[progress->seen++] = cell;
+    return progress->limit > 0 && progress->seen >= progress->limit;
+}
+
+SUITE(polygonToCellsProgress) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    GeoLoop sfGeoLoop = {.numVerts = 6, .verts = sfVerts};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {
+        .geoloop = sfGeoLoop, .numHoles = 0, .holes = noHoles};
+
+    int64_t size;
+    t_assertSuccess(maxPolygonToCellsSize(&sfGeoPolygon, 9, 0, &size));
+
+    TEST(streamsEveryCell) {
+        H3Index *expected = calloc(size, sizeof(H3Index));
+        t_assertSuccess(polygonToCells(&sfGeoPolygon, 9, 0, expected));
+        int64_t numExpected = 0;
+        for (int64_t i = 0; i < size; i++) {
+            numExpected += expected[i] != H3_NULL;
+        }
+
+        Progress progress = {
+            .seen = 0, .limit = 0, .cells = calloc(size, sizeof(H3Index))};
+        t_assertSuccess(
+            polygonToCellsProgress(&sfGeoPolygon, 9, 0, collect, &progress));
+        t_assert(progress.seen == numExpected, "every cell is streamed");
+        t_assert(progress.seen == 1253, "got expected number of cells");
+
+        free(progress.cells);
+        free(expected);
+    }
+
+    TEST(cancel) {
+        Progress progress = {
+            .seen = 0, .limit = 10, .cells = calloc(size, sizeof(H3Index))};
+        t_assert(polygonToCellsProgress(&sfGeoPolygon, 9, 0, collect,
+                                        &progress) == E_FAILED,
+                 "cancelled fill is reported");
+        t_assert(progress.seen == 10, "fill stops right after cancelling");
+
+        free(progress.cells);
+    }
+
+    TEST(invalid) {
+        Progress progress = {.seen = 0, .limit = 0, .cells = NULL};
+        t_assert(polygonToCellsProgress(&sfGeoPolygon, 16, 0, collect,
+                                        &progress) == E_RES_DOMAIN,
+                 "invalid resolution is rejected");
+        t_assert(polygonToCellsProgress(&sfGeoPolygon, 9, 1, collect,
+                                        &progress) == E_OPTION_INVALID,
+                 "invalid flags are rejected");
+        t_assert(polygonToCellsProgress(&sfGeoPolygon, 9, 0, NULL,
+                                        &progress) == E_FAILED,
+                 "missing callback is rejected");
+        t_assert(progress.seen == 0, "callback is never invoked");
+    }
+}
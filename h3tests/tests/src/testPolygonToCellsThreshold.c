@@ -0,0 +1,97 @@
+/** @file
+ * @brief tests H3 function `polygonToCellsThreshold`
+ *
+ *  usage: `testPolygonToCellsThreshold`
+ */
+
+#include <math.h>
+#include <stdbool.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static bool contains(const H3Index *cells, int64_t count, H3Index cell) {
+    for (int64_t i = 0; i < count; i++) {
+        if (cells[i] == cell) {
+            return true;
+        }
+    }
+    return false;
+}
+
+SUITE(polygonToCellsThreshold) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {.geoloop = {.numVerts = 6, .verts = sfVerts},
+                               .numHoles = 0,
+                               .holes = noHoles};
+    int res = 9;
+    int64_t size;
+    t_assertSuccess(maxPolygonToCellsSize(&sfGeoPolygon, res, 0, &size));
+
+    TEST(monotonic) {
+        H3Index *previous = calloc(size, sizeof(H3Index));
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        int64_t previousCount = 0;
+        t_assertSuccess(
+            polygonToCellsThreshold(&sfGeoPolygon, res, 0, previous,
+                                    &previousCount));
+
+        int64_t coveringCount = 0;
+        t_assertSuccess(polygonToCellsCovering(&sfGeoPolygon, res, 0, cells,
+                                               size, &coveringCount));
+        t_assert(previousCount == coveringCount,
+                 "no threshold keeps the covering");
+
+        for (int step = 1; step <= 10; step++) {
+            int64_t count = 0;
+            t_assertSuccess(polygonToCellsThreshold(&sfGeoPolygon, res,
+                                                    step / 10.0, cells,
+                                                    &count));
+            t_assert(count <= previousCount, "result set shrinks");
+            for (int64_t i = 0; i < count; i++) {
+                t_assert(contains(previous, previousCount, cells[i]),
+                         "kept cells were kept at lower thresholds");
+                double fraction;
+                t_assertSuccess(cellPolygonOverlapFraction(
+                    cells[i], &sfGeoPolygon, &fraction));
+                t_assert(fraction >= step / 10.0, "overlap above threshold");
+            }
+            H3Index *tmp = previous;
+            previous = cells;
+            cells = tmp;
+            previousCount = count;
+        }
+        t_assert(previousCount > 0, "some cells are fully covered");
+        free(previous);
+        free(cells);
+    }
+
+    TEST(invalid) {
+        H3Index *cells = calloc(size, sizeof(H3Index));
+        int64_t count;
+        t_assert(polygonToCellsThreshold(&sfGeoPolygon, res, -0.1, cells,
+                                         &count) == E_DOMAIN,
+                 "negative threshold");
+        t_assert(polygonToCellsThreshold(&sfGeoPolygon, res, 1.1, cells,
+                                         &count) == E_DOMAIN,
+                 "threshold above 1");
+        t_assert(polygonToCellsThreshold(&sfGeoPolygon, res, NAN, cells,
+                                         &count) == E_DOMAIN,
+                 "NaN threshold");
+        t_assert(polygonToCellsThreshold(&sfGeoPolygon, 16, 0.5, cells,
+                                         &count) == E_RES_DOMAIN,
+                 "invalid resolution");
+        t_assert(polygonToCellsThreshold(NULL, res, 0.5, cells, &count) ==
+                     E_FAILED,
+                 "null polygon");
+        free(cells);
+    }
+}
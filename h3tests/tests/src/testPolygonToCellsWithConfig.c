@@ -0,0 +1,92 @@
+/** @file
+ * @brief tests H3 functions `polyfillConfigNew`, `polygonToCellsWithConfig`
+ * and `polyfillConfigFree`
+ *
+ *  usage: `testPolygonToCellsWithConfig`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static int contains(const H3Index *cells, int64_t len, H3Index cell) {
+    for (int64_t i = 0; i < len; i++) {
+        if (cells[i] == cell) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+static void assertSameAsOneShot(const H3PolyfillConfig *cfg,
+                                const GeoPolygon *polygon, int res) {
+    int64_t size;
+    t_assertSuccess(maxPolygonToCellsSize(polygon, res, 0, &size));
+    H3Index *expected = calloc(size, sizeof(H3Index));
+    t_assertSuccess(polygonToCells(polygon, res, 0, expected));
+    int64_t numExpected = 0;
+    for (int64_t i = 0; i < size; i++) {
+        numExpected += expected[i] != H3_NULL;
+    }
+
+    H3Index *cells = calloc(size, sizeof(H3Index));
+    int64_t count;
+    t_assertSuccess(polygonToCellsWithConfig(cfg, polygon, cells, &count));
+    t_assert(count == numExpected, "same number of cells");
+    for (int64_t i = 0; i < count; i++) {
+        t_assert(contains(expected, size, cells[i]), "same cells");
+    }
+
+    free(cells);
+    free(expected);
+}
+
+SUITE(polygonToCellsWithConfig) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    GeoLoop sfGeoLoop = {.numVerts = 6, .verts = sfVerts};
+    LatLng triangleVerts[] = {{0.6580, -2.1360},
+                              {0.6570, -2.1350},
+                              {0.6570, -2.1370}};
+    GeoLoop triangleGeoLoop = {.numVerts = 3, .verts = triangleVerts};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {
+        .geoloop = sfGeoLoop, .numHoles = 0, .holes = noHoles};
+    GeoPolygon triangleGeoPolygon = {
+        .geoloop = triangleGeoLoop, .numHoles = 0, .holes = noHoles};
+
+    TEST(reuse) {
+        H3PolyfillConfig *cfg;
+        t_assertSuccess(polyfillConfigNew(9, 0, &cfg));
+        assertSameAsOneShot(cfg, &sfGeoPolygon, 9);
+        assertSameAsOneShot(cfg, &triangleGeoPolygon, 9);
+        polyfillConfigFree(cfg);
+    }
+
+    TEST(emptyPolygon) {
+        GeoLoop emptyGeoLoop = {.numVerts = 0, .verts = NULL};
+        GeoPolygon empty = {
+            .geoloop = emptyGeoLoop, .numHoles = 0, .holes = noHoles};
+        H3PolyfillConfig *cfg;
+        t_assertSuccess(polyfillConfigNew(9, 0, &cfg));
+        int64_t count = 42;
+        t_assertSuccess(polygonToCellsWithConfig(cfg, &empty, NULL, &count));
+        t_assert(count == 0, "empty polygon contains no cell");
+        polyfillConfigFree(cfg);
+    }
+
+    TEST(invalidConfig) {
+        H3PolyfillConfig *cfg;
+        t_assert(polyfillConfigNew(16, 0, &cfg) == E_RES_DOMAIN,
+                 "invalid resolution is rejected");
+        t_assert(polyfillConfigNew(9, 1, &cfg) == E_OPTION_INVALID,
+                 "invalid flags are rejected");
+        polyfillConfigFree(NULL);
+    }
+}
@@ -0,0 +1,43 @@
+/** @file
+ * @brief tests H3 function `repairCellIndex`
+ *
+ *  usage: `testRepairCellIndex`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+static const H3Index cell = 0x85283473fffffff;
+
+SUITE(repairCellIndex) {
+    TEST(validCell) {
+        H3Index out = 0;
+        t_assertSuccess(repairCellIndex(cell, &out));
+        t_assert(out == cell, "valid cell is left untouched");
+    }
+
+    TEST(dirtyTrailingDigits) {
+        H3Index dirty = cell & ~0x7ULL;
+        H3Index out = 0;
+        t_assert(!isValidCell(dirty), "dirty cell is invalid");
+        t_assertSuccess(repairCellIndex(dirty, &out));
+        t_assert(out == cell, "trailing digits are reset");
+    }
+
+    TEST(wrongMode) {
+        H3Index dirty = (cell & ~(0xFULL << 59)) | (2ULL << 59);
+        H3Index out = 0;
+        t_assert(!isValidCell(dirty), "dirty cell is invalid");
+        t_assertSuccess(repairCellIndex(dirty, &out));
+        t_assert(out == cell, "mode is reset");
+    }
+
+    TEST(garbage) {
+        H3Index out = 0;
+        t_assert(repairCellIndex(0xFFFFFFFFFFFFFFFF, &out) == E_CELL_INVALID,
+                 "garbage can't be repaired");
+        t_assert(out == 0, "output untouched");
+    }
+}
@@ -0,0 +1,59 @@
+/** @file
+ * @brief tests H3 function `resolutionForCellBudget`
+ *
+ *  usage: `testResolutionForCellBudget`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(resolutionForCellBudget) {
+    // Around San Francisco.
+    LatLng sfVerts[] = {{0.659966917655, -2.1364398519396},
+                        {0.6595011102219, -2.1359434279405},
+                        {0.6583348114025, -2.1354884206045},
+                        {0.6581220034068, -2.1382437718946},
+                        {0.6594479998527, -2.1384597563896},
+                        {0.6599990002976, -2.1376771158464}};
+    GeoLoop noHoles[1];
+    GeoPolygon sfGeoPolygon = {.geoloop = {.numVerts = 6, .verts = sfVerts},
+                               .numHoles = 0,
+                               .holes = noHoles};
+
+    TEST(budget) {
+        int64_t budgets[] = {1, 10, 100, 1000, 5000};
+        for (int i = 0; i < 5; i++) {
+            int res = -1;
+            t_assertSuccess(
+                resolutionForCellBudget(&sfGeoPolygon, budgets[i], &res));
+            t_assert(res >= 0 && res < MAX_H3_RES, "valid resolution");
+
+            int64_t count;
+            t_assertSuccess(polygonToCellsCount(&sfGeoPolygon, res, 0, &count));
+            t_assert(count <= budgets[i], "fits in the budget");
+            t_assertSuccess(
+                polygonToCellsCount(&sfGeoPolygon, res + 1, 0, &count));
+            t_assert(count > budgets[i], "finer resolution doesn't fit");
+        }
+    }
+
+    TEST(invalid) {
+        int res;
+        t_assert(resolutionForCellBudget(&sfGeoPolygon, 0, &res) == E_DOMAIN,
+                 "empty budget");
+        t_assert(resolutionForCellBudget(&sfGeoPolygon, -5, &res) == E_DOMAIN,
+                 "negative budget");
+        t_assert(resolutionForCellBudget(NULL, 10, &res) == E_FAILED,
+                 "null polygon");
+
+        // A polygon spanning many base cells.
+        LatLng bigVerts[] = {{-1, -3}, {-1, 3}, {1, 3}, {1, -3}};
+        GeoPolygon big = {.geoloop = {.numVerts = 4, .verts = bigVerts},
+                          .numHoles = 0,
+                          .holes = noHoles};
+        t_assert(resolutionForCellBudget(&big, 1, &res) == E_DOMAIN,
+                 "doesn't fit even at resolution 0");
+    }
+}
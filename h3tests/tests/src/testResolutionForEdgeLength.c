@@ -0,0 +1,57 @@
+/** @file
+ * @brief tests H3 functions `resolutionForEdgeLengthM` and
+ * `resolutionForAreaM2`
+ *
+ *  usage: `testResolutionForEdgeLength`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(resolutionForEdgeLength) {
+    TEST(edgeLengthRoundTrip) {
+        for (int res = 0; res <= 15; res++) {
+            double meters = 0;
+            int found = -1;
+            t_assertSuccess(getHexagonEdgeLengthAvgM(res, &meters));
+            t_assertSuccess(resolutionForEdgeLengthM(meters, &found));
+            t_assert(found == res, "edge length maps back to resolution");
+        }
+    }
+
+    TEST(areaRoundTrip) {
+        for (int res = 0; res <= 15; res++) {
+            double m2 = 0;
+            int found = -1;
+            t_assertSuccess(getHexagonAreaAvgM2(res, &m2));
+            t_assertSuccess(resolutionForAreaM2(m2, &found));
+            t_assert(found == res, "area maps back to resolution");
+        }
+    }
+
+    TEST(clamped) {
+        int found = -1;
+        t_assertSuccess(resolutionForEdgeLengthM(1e9, &found));
+        t_assert(found == 0, "huge edge length clamps to res 0");
+        t_assertSuccess(resolutionForEdgeLengthM(1e-6, &found));
+        t_assert(found == 15, "tiny edge length clamps to res 15");
+        t_assertSuccess(resolutionForAreaM2(1e18, &found));
+        t_assert(found == 0, "huge area clamps to res 0");
+        t_assertSuccess(resolutionForAreaM2(1e-6, &found));
+        t_assert(found == 15, "tiny area clamps to res 15");
+    }
+
+    TEST(invalid) {
+        int found = -1;
+        t_assert(resolutionForEdgeLengthM(0, &found) == E_DOMAIN,
+                 "zero edge length fails");
+        t_assert(resolutionForEdgeLengthM(-1, &found) == E_DOMAIN,
+                 "negative edge length fails");
+        t_assert(resolutionForAreaM2(0, &found) == E_DOMAIN,
+                 "zero area fails");
+        t_assert(resolutionForAreaM2(-1, &found) == E_DOMAIN,
+                 "negative area fails");
+    }
+}
@@ -0,0 +1,68 @@
+/** @file
+ * @brief tests H3 function `sampleCells`
+ *
+ *  usage: `testSampleCells`
+ */
+
+#include <stdlib.h>
+#include <string.h>
+
+#include "h3api.h"
+#include "test.h"
+
+#define CELL 0x85283473fffffff
+#define RES 9
+
+SUITE(sampleCells) {
+    int64_t n = 0;
+    t_assertSuccess(cellToChildrenSize(CELL, RES, &n));
+    H3Index *cells = calloc(n, sizeof(H3Index));
+    t_assertSuccess(cellToChildren(CELL, RES, cells, n));
+
+    TEST(deterministic) {
+        H3Index *a = calloc(n, sizeof(H3Index));
+        H3Index *b = calloc(n, sizeof(H3Index));
+        int64_t countA = -1;
+        int64_t countB = -1;
+        t_assertSuccess(sampleCells(cells, n, 0.25, 42, a, &countA));
+        t_assertSuccess(sampleCells(cells, n, 0.25, 42, b, &countB));
+        t_assert(countA == countB, "same count");
+        t_assert(memcmp(a, b, countA * sizeof(H3Index)) == 0, "same subset");
+        t_assert(countA > n / 5 && countA < n * 3 / 10,
+                 "count is roughly fraction * n");
+
+        t_assertSuccess(sampleCells(cells, n, 0.25, 43, b, &countB));
+        t_assert(countA != countB ||
+                     memcmp(a, b, countA * sizeof(H3Index)) != 0,
+                 "another seed gives another subset");
+        free(a);
+        free(b);
+    }
+
+    TEST(bounds) {
+        H3Index *out = calloc(n, sizeof(H3Index));
+        int64_t count = -1;
+        t_assertSuccess(sampleCells(cells, n, 0, 42, out, &count));
+        t_assert(count == 0, "zero fraction selects nothing");
+        t_assertSuccess(sampleCells(cells, n, 1, 42, out, &count));
+        t_assert(count == n, "unit fraction selects everything");
+        t_assert(memcmp(out, cells, n * sizeof(H3Index)) == 0,
+                 "input order is kept");
+        t_assertSuccess(sampleCells(NULL, 0, 0.5, 42, NULL, &count));
+        t_assert(count == 0, "empty set");
+        free(out);
+    }
+
+    TEST(invalid) {
+        H3Index out[1] = {0};
+        int64_t count = -1;
+        t_assert(sampleCells(cells, 1, -0.1, 42, out, &count) == E_DOMAIN,
+                 "negative fraction fails");
+        t_assert(sampleCells(cells, 1, 1.1, 42, out, &count) == E_DOMAIN,
+                 "fraction above 1 fails");
+        t_assert(sampleCells(cells, -1, 0.5, 42, out, &count) == E_DOMAIN,
+                 "negative size fails");
+    }
+
+    free(cells);
+}
@@ -0,0 +1,77 @@
+/** @file
+ * @brief tests H3 function `snapLatLngToCellCenterDegs`
+ *
+ *  usage: `testSnapLatLngToCellCenterDegs`
+ */
+
+#include <math.h>
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(snapLatLngToCellCenterDegs) {
+    TEST(snap) {
+        LatLng point = {.lat = 37.779265, .lng = -122.419277};
+        for (int res = 0; res <= MAX_H3_RES; res++) {
+            LatLng center;
+            H3Index cell;
+            t_assertSuccess(
+                snapLatLngToCellCenterDegs(&point, res, &center, &cell));
+
+            LatLng radians;
+            H3Index expected;
+            latLngSetDegs(&radians, point.lat, point.lng);
+            t_assertSuccess(latLngToCell(&radians, res, &expected));
+            t_assert(cell == expected, "containing cell");
+
+            LatLng expectedCenter;
+            t_assertSuccess(cellToLatLng(cell, &expectedCenter));
+            t_assert(fabs(radsToDegs(expectedCenter.lat) - center.lat) < 1e-12,
+                     "center latitude");
+            t_assert(fabs(radsToDegs(expectedCenter.lng) - center.lng) < 1e-12,
+                     "center longitude");
+
+            // Snapping a cell center is a no-op.
+            LatLng again;
+            H3Index againCell;
+            t_assertSuccess(
+                snapLatLngToCellCenterDegs(&center, res, &again, &againCell));
+            t_assert(againCell == cell, "idempotent cell");
+            t_assert(again.lat == center.lat && again.lng == center.lng,
+                     "idempotent center");
+        }
+    }
+
+    TEST(optionalOutputs) {
+        LatLng point = {.lat = 10, .lng = 20};
+        H3Index cell;
+        t_assertSuccess(snapLatLngToCellCenterDegs(&point, 5, NULL, &cell));
+        LatLng center;
+        t_assertSuccess(snapLatLngToCellCenterDegs(&point, 5, &center, NULL));
+    }
+
+    TEST(invalid) {
+        LatLng point = {.lat = 10, .lng = 20};
+        LatLng center;
+        H3Index cell;
+        t_assert(snapLatLngToCellCenterDegs(&point, -1, &center, &cell) ==
+                     E_RES_DOMAIN,
+                 "negative resolution");
+        t_assert(snapLatLngToCellCenterDegs(&point, 16, &center, &cell) ==
+                     E_RES_DOMAIN,
+                 "resolution too high");
+
+        LatLng offGlobe = {.lat = 91, .lng = 0};
+        t_assert(snapLatLngToCellCenterDegs(&offGlobe, 5, &center, &cell) ==
+                     E_LATLNG_DOMAIN,
+                 "latitude off the globe");
+        LatLng nan = {.lat = NAN, .lng = 0};
+        t_assert(snapLatLngToCellCenterDegs(&nan, 5, &center, &cell) ==
+                     E_FAILED,
+                 "non-finite coordinate");
+        t_assert(snapLatLngToCellCenterDegs(NULL, 5, &center, &cell) ==
+                     E_FAILED,
+                 "null coordinate");
+    }
+}
@@ -0,0 +1,40 @@
+/** @file
+ * @brief tests H3 function `uncompactCellsSize`
+ *
+ *  usage: `testUncompactCellsSize`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+
+SUITE(uncompactCellsSize) {
+    TEST(res0ToRes15) {
+        H3Index res0Cells[122] = {0};
+        t_assertSuccess(getRes0Cells(res0Cells));
+
+        int64_t size = 0;
+        t_assertSuccess(uncompactCellsSize(res0Cells, 122, 15, &size));
+        int64_t expected = 0;
+        t_assertSuccess(getNumCells(15, &expected));
+        t_assert(size == expected, "every res 15 cell");
+    }
+
+    TEST(overflow) {
+        // 7^15 children per hexagon: 2 millions of them don't fit in 64-bit.
+        int64_t numCells = 2000000;
+        H3Index *cells = calloc(numCells, sizeof(H3Index));
+        for (int64_t i = 0; i < numCells; i++) {
+            cells[i] = 0x8001fffffffffff;
+        }
+
+        int64_t size = 0;
+        t_assert(uncompactCellsSize(cells, numCells, 15, &size) ==
+                     E_MEMORY_BOUNDS,
+                 "overflow is reported");
+        t_assertSuccess(uncompactCellsSize(cells, numCells, 5, &size));
+        t_assert(size == numCells * 16807, "no overflow at res 5");
+        free(cells);
+    }
+}
@@ -0,0 +1,50 @@
+/** @file
+ * @brief tests H3 function `vertexToOwnerCell`
+ *
+ *  usage: `testVertexToOwnerCell`
+ */
+
+#include <stdlib.h>
+
+#include "h3api.h"
+#include "test.h"
+#include "utility.h"
+
+static void vertexToOwnerCell_assertions(H3Index cell) {
+    int numVerts = isPentagon(cell) ? 5 : 6;
+    for (int i = 0; i < numVerts; i++) {
+        H3Index vertex, owner;
+        t_assertSuccess(cellToVertex(cell, i, &vertex));
+        t_assertSuccess(vertexToOwnerCell(vertex, &owner));
+
+        H3Index ownerVertexes[6] = {0};
+        t_assertSuccess(cellToVertexes(owner, ownerVertexes));
+        int found = 0;
+        for (int j = 0; j < 6; j++) {
+            found |= ownerVertexes[j] == vertex;
+        }
+        t_assert(found, "owner shares the vertex");
+
+        if (owner != cell) {
+            int isNeighbor;
+            t_assertSuccess(areNeighborCells(cell, owner, &isNeighbor));
+            t_assert(isNeighbor, "owner is the cell or one of its neighbors");
+        }
+    }
+}
+
+SUITE(vertexToOwnerCell) {
+    TEST(allCells) {
+        iterateAllIndexesAtRes(0, vertexToOwnerCell_assertions);
+        iterateAllIndexesAtRes(1, vertexToOwnerCell_assertions);
+    }
+
+    TEST(invalidVertex) {
+        H3Index owner;
+        t_assert(vertexToOwnerCell(0x8928308280fffff, &owner) ==
+                     E_VERTEX_INVALID,
+                 "cell is not a vertex");
+        t_assert(vertexToOwnerCell(H3_NULL, &owner) == E_VERTEX_INVALID,
+                 "null is not a vertex");
+    }
+}
@@ -0,0 +1,40 @@
+//! Bit layout of an H3 index.
+
+/// Offset of the reserved high bit.
+pub const HIGH_BIT_OFFSET: u32 = 63;
+/// Mask of the reserved high bit.
+pub const HIGH_BIT_MASK: u64 = 1 << HIGH_BIT_OFFSET;
+
+/// Offset of the index mode.
+pub const MODE_OFFSET: u32 = 59;
+/// Mask of the index mode.
+pub const MODE_MASK: u64 = 0b1111 << MODE_OFFSET;
+/// Mode of a cell index.
+pub const CELL_MODE: u64 = 1;
+/// Mode of a vertex index.
+pub const VERTEX_MODE: u64 = 4;
+
+/// Offset of the mode-dependent bits (e.g. the vertex number of a vertex).
+pub const RESERVED_OFFSET: u32 = 56;
+/// Mask of the mode-dependent bits.
+pub const RESERVED_MASK: u64 = 0b111 << RESERVED_OFFSET;
+
+/// Offset of the resolution.
+pub const RES_OFFSET: u32 = 52;
+/// Mask of the resolution.
+pub const RES_MASK: u64 = 0b1111 << RES_OFFSET;
+
+/// Offset of the base cell.
+pub const BASE_CELL_OFFSET: u32 = 45;
+
+/// Size of a digit, in bits.
+pub const DIGIT_BITS: u32 = 3;
+/// Mask of a digit, once shifted to the lowest bits.
+pub const DIGIT_MASK: u64 = 0b111;
+/// Mask of all the digits.
+pub const DIGITS_MASK: u64 = (1 << BASE_CELL_OFFSET) - 1;
+
+/// Returns the offset of the digit at the given resolution (1-15).
+pub const fn digit_offset(resolution: u32) -> u32 {
+    (15 - resolution) * DIGIT_BITS
+}
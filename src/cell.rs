@@ -1,9 +1,101 @@
 use crate::{
-    convert, delegate_inner, CellBoundary, H3Error, H3ErrorCodes, H3Index,
-    LatLng,
+    bits, convert, delegate_inner, latlng, CellBoundary, H3Error, H3ErrorCodes,
+    H3Index, LatLng, H3_NULL,
 };
-use h3o::CellIndex;
-use std::ffi::c_int;
+use h3o::{CellIndex, Resolution};
+use std::{
+    collections::HashSet,
+    f64::consts::PI,
+    ffi::{c_int, c_void},
+};
+
+/// Center, resolution, base cell and shape of an H3 cell.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CellDecoded {
+    /// Center of the cell, in degrees.
+    pub center: LatLng,
+    /// Resolution of the cell.
+    pub resolution: c_int,
+    /// Base cell "number" of the cell.
+    pub baseCell: c_int,
+    /// 1 if the cell is a pentagon, 0 otherwise.
+    pub isPentagon: c_int,
+}
+
+/// Resolution, base cell and shape of an H3 cell.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CellInfo {
+    /// Resolution of the cell.
+    pub resolution: c_int,
+    /// Base cell "number" of the cell.
+    pub baseCell: c_int,
+    /// 1 if the cell is a pentagon, 0 otherwise.
+    pub isPentagon: c_int,
+}
+
+/// Shape metrics of an H3 cell.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ShapeMetrics {
+    /// Area of the cell, in meters^2.
+    pub areaM2: f64,
+    /// Perimeter of the cell, in meters.
+    pub perimeterM: f64,
+    /// Isoperimetric quotient (4π·area/perimeter²), 1 for a perfect disk.
+    pub isoperimetricQuotient: f64,
+    /// Number of vertices of the cell boundary.
+    pub numVerts: c_int,
+}
+
+/// Latitude/longitude in degrees, with an altitude.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct LatLngAlt {
+    /// Latitude in degrees.
+    pub lat: f64,
+    /// Longitude in degrees.
+    pub lng: f64,
+    /// Altitude, in the caller's unit.
+    pub alt: f64,
+}
+
+/// Projection used by cellToBoundaryProjected.
+///
+/// Converts a latitude/longitude in degrees into the `x, y` pointed to by the
+/// third and fourth arguments, with the user-provided context as last argument.
+/// A nonzero return value aborts the conversion.
+pub type H3Projection = Option<
+    unsafe extern "C" fn(f64, f64, *mut f64, *mut f64, *mut c_void) -> c_int,
+>;
+
+/// Finds the cell, at the same resolution, containing the antipode of the
+/// center of a cell.
+///
+/// The result is approximate: the grid isn't symmetric, so the antipode of the
+/// antipode cell may be a neighbor of the original cell rather than the cell
+/// itself.
+///
+/// @param h   The H3 cell
+/// @param out The cell containing the antipodal point
+#[no_mangle]
+pub extern "C" fn cellAntipode(
+    h: H3Index,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(h: H3Index) -> Result<H3Index, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let center = h3o::LatLng::from(index);
+        let antipode = h3o::LatLng::from_radians(
+            -center.lat_radians(),
+            center.lng_radians() + PI,
+        )?;
+        Ok(antipode.to_cell(index.resolution()).into())
+    }
+
+    delegate_inner!(inner(h), out)
+}
 
 /// Area of H3 cell in kilometers^2.
 #[no_mangle]
@@ -48,6 +140,439 @@ pub extern "C" fn cellAreaRads2(h: H3Index, out: Option<&mut f64>) -> H3Error {
     delegate_inner!(inner(h), out)
 }
 
+/// Finds the cell reached by traveling along a great circle from the center of
+/// a start cell, with the given initial bearing and distance.
+///
+/// @param start       Start cell
+/// @param bearingDegs Initial bearing, in degrees clockwise from north
+/// @param distanceKm  Distance to travel, in kilometers
+/// @param res         Resolution of the destination cell
+/// @param out         The cell containing the destination point
+/// @return            E_DOMAIN if the bearing or the distance isn't finite, or
+///                    the distance is negative.
+#[no_mangle]
+pub extern "C" fn cellAtBearingDistanceKm(
+    start: H3Index,
+    bearingDegs: f64,
+    distanceKm: f64,
+    res: c_int,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(
+        start: H3Index,
+        bearingDegs: f64,
+        distanceKm: f64,
+        res: c_int,
+    ) -> Result<H3Index, H3Error> {
+        let start = h3o::LatLng::from(CellIndex::try_from(start)?);
+        let resolution = convert::h3res_to_resolution(res)?;
+        if !(bearingDegs.is_finite() && distanceKm.is_finite())
+            || distanceKm < 0.
+        {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+
+        let bearing = bearingDegs.to_radians();
+        let angle = distanceKm / h3o::EARTH_RADIUS_KM;
+        let (lat1, lng1) = (start.lat_radians(), start.lng_radians());
+        let lat2 = lat1
+            .sin()
+            .mul_add(angle.cos(), lat1.cos() * angle.sin() * bearing.cos())
+            .clamp(-1., 1.)
+            .asin();
+        let lng2 = lng1
+            + (bearing.sin() * angle.sin() * lat1.cos())
+                .atan2(lat1.sin().mul_add(-lat2.sin(), angle.cos()));
+
+        let destination = h3o::LatLng::from_radians(lat2, lng2)?;
+        Ok(destination.to_cell(resolution).into())
+    }
+
+    delegate_inner!(inner(start, bearingDegs, distanceKm, res), out)
+}
+
+/// Determines whether a point lies in an H3 cell.
+///
+/// @param cell  The H3 cell.
+/// @param point The point, in degrees.
+/// @param out   Set to 1 if the point is in the cell, 0 otherwise.
+/// @return      E_SUCCESS on success, E_CELL_INVALID for an invalid cell,
+///              E_LATLNG_DOMAIN for a point off the globe or E_FAILED for a
+///              non-finite one.
+#[no_mangle]
+pub extern "C" fn cellContainsLatLng(
+    cell: H3Index,
+    point: Option<&LatLng>,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(cell: H3Index, point: Option<&LatLng>) -> Result<c_int, H3Error> {
+        let index = CellIndex::try_from(cell)?;
+        let point = point.ok_or(H3ErrorCodes::EFailed)?;
+        let ll = convert::latlng_from_degrees(point)?;
+
+        Ok((ll.to_cell(index.resolution()) == index).into())
+    }
+
+    delegate_inner!(inner(cell, point), out)
+}
+
+/// Returns the center (in degrees), resolution, base cell and shape of an H3
+/// cell at once.
+///
+/// @param h   The H3 cell.
+/// @param out Output decoded cell.
+/// @return    E_SUCCESS on success, or E_CELL_INVALID for an invalid cell.
+#[no_mangle]
+pub extern "C" fn cellDecode(
+    h: H3Index,
+    out: Option<&mut CellDecoded>,
+) -> H3Error {
+    fn inner(h: H3Index) -> Result<CellDecoded, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let center = h3o::LatLng::from(index);
+        Ok(CellDecoded {
+            center: LatLng {
+                lat: center.lat(),
+                lng: center.lng(),
+            },
+            resolution: u8::from(index.resolution()).into(),
+            baseCell: u8::from(index.base_cell()).into(),
+            isPentagon: index.is_pentagon().into(),
+        })
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Returns the resolution, base cell and shape of an H3 cell at once.
+///
+/// @param h   The H3 cell.
+/// @param out Output cell info.
+/// @return    E_SUCCESS on success, or E_CELL_INVALID for an invalid cell.
+#[no_mangle]
+pub extern "C" fn cellInfo(h: H3Index, out: Option<&mut CellInfo>) -> H3Error {
+    fn inner(h: H3Index) -> Result<CellInfo, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        Ok(CellInfo {
+            resolution: u8::from(index.resolution()).into(),
+            baseCell: u8::from(index.base_cell()).into(),
+            isPentagon: index.is_pentagon().into(),
+        })
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Writes the digits (0-6) of a cell, from resolution 1 to the cell's
+/// resolution.
+///
+/// Along with the base cell, the digits are the hierarchical address of the
+/// cell: `digits[r - 1]` is the direction of the resolution `r` ancestor within
+/// its parent.
+///
+/// @param h      The H3 cell.
+/// @param digits Output digits, one per resolution.
+/// @param count  Set to the number of digits written (the cell resolution).
+/// @return       E_FAILED if `digits` is null for a cell finer than res 0.
+///
+/// # Safety
+///
+/// `digits` must points to an array of at least 15 elements (or the cell
+/// resolution).
+#[no_mangle]
+pub unsafe extern "C" fn cellToDigits(
+    h: H3Index,
+    digits: *mut c_int,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(h: H3Index, digits: *mut c_int) -> Result<c_int, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let resolution = index.resolution();
+        let len = usize::from(resolution);
+        if len == 0 {
+            return Ok(0);
+        }
+        if digits.is_null() {
+            return Err(H3ErrorCodes::EFailed.into());
+        }
+
+        let digits = std::slice::from_raw_parts_mut(digits, len);
+        let directions = Resolution::range(Resolution::One, resolution)
+            .filter_map(|resolution| index.direction_at(resolution));
+        for (digit, direction) in digits.iter_mut().zip(directions) {
+            *digit = u8::from(direction).into();
+        }
+        Ok(u8::from(resolution).into())
+    }
+
+    delegate_inner!(inner(h, digits), count)
+}
+
+/// Builds a cell from its base cell and its digits, as produced by
+/// cellToDigits.
+///
+/// @param baseCell Base cell number (0-121).
+/// @param digits   Digits (0-6), from resolution 1 to `count`.
+/// @param count    Number of digits, i.e. the resolution of the cell.
+/// @param out      The built cell.
+/// @return         E_DOMAIN for an out-of-range base cell or digit,
+///                 E_CELL_INVALID if the digits aren't a valid path (e.g. a
+///                 deleted pentagon subsequence).
+///
+/// # Safety
+///
+/// `digits` must points to an array of at least `count` elements.
+#[no_mangle]
+pub unsafe extern "C" fn digitsToCell(
+    baseCell: c_int,
+    digits: *const c_int,
+    count: c_int,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    unsafe fn inner(
+        baseCell: c_int,
+        digits: *const c_int,
+        count: c_int,
+    ) -> Result<H3Index, H3Error> {
+        // Cell mode, resolution 0, base cell 0 and unused digits set to 7.
+        const TEMPLATE: u64 =
+            (bits::CELL_MODE << bits::MODE_OFFSET) | bits::DIGITS_MASK;
+
+        let resolution = convert::h3res_to_resolution(count)?;
+        let base_cell = u8::try_from(baseCell)
+            .ok()
+            .and_then(|value| h3o::BaseCell::try_from(value).ok())
+            .ok_or(H3ErrorCodes::EDomain)?;
+        let digits = match usize::from(resolution) {
+            0 => &[],
+            _ if digits.is_null() => {
+                return Err(H3ErrorCodes::EFailed.into());
+            }
+            len => std::slice::from_raw_parts(digits, len),
+        };
+
+        let mut index = TEMPLATE
+            | (u64::from(u8::from(resolution)) << bits::RES_OFFSET)
+            | (u64::from(u8::from(base_cell)) << bits::BASE_CELL_OFFSET);
+        for (&digit, resolution) in digits.iter().zip(1_u32..) {
+            let digit = u64::try_from(digit)
+                .ok()
+                .filter(|&digit| digit <= 6)
+                .ok_or(H3ErrorCodes::EDomain)?;
+            let offset = bits::digit_offset(resolution);
+            index = (index & !(bits::DIGIT_MASK << offset)) | (digit << offset);
+        }
+
+        Ok(CellIndex::try_from(index)?.into())
+    }
+
+    delegate_inner!(inner(baseCell, digits, count), out)
+}
+
+/// Determines whether a great-circle segment intersects an H3 cell.
+///
+/// A segment intersects the cell when it crosses or touches the cell boundary,
+/// or when it lies entirely within the cell.
+///
+/// @param cell The H3 cell.
+/// @param a    Start of the segment, in degrees.
+/// @param b    End of the segment, in degrees.
+/// @param out  Set to 1 if the segment intersects the cell, 0 otherwise.
+/// @return     E_SUCCESS on success, E_CELL_INVALID for an invalid cell,
+///             E_LATLNG_DOMAIN for an endpoint off the globe or E_FAILED for
+///             a non-finite one.
+#[no_mangle]
+pub extern "C" fn cellIntersectsSegment(
+    cell: H3Index,
+    a: Option<&LatLng>,
+    b: Option<&LatLng>,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(
+        cell: H3Index,
+        a: Option<&LatLng>,
+        b: Option<&LatLng>,
+    ) -> Result<c_int, H3Error> {
+        let index = CellIndex::try_from(cell)?;
+        let a = a.ok_or(H3ErrorCodes::EFailed)?;
+        let b = b.ok_or(H3ErrorCodes::EFailed)?;
+        let a = convert::latlng_from_degrees(a)?;
+        let b = convert::latlng_from_degrees(b)?;
+
+        // A segment that doesn't cross the boundary is either entirely inside
+        // or entirely outside: checking one endpoint is enough.
+        if a.to_cell(index.resolution()) == index {
+            return Ok(1);
+        }
+
+        let boundary = index.boundary();
+        let vertexes = boundary.iter().copied();
+        let crosses = vertexes
+            .clone()
+            .zip(vertexes.cycle().skip(1))
+            .any(|(c, d)| arcs_intersect(a, b, c, d));
+
+        Ok(crosses.into())
+    }
+
+    delegate_inner!(inner(cell, a, b), out)
+}
+
+/// Tests if the minor great-circle arcs `start1→end1` and `start2→end2`
+/// intersect.
+fn arcs_intersect(
+    start1: h3o::LatLng,
+    end1: h3o::LatLng,
+    start2: h3o::LatLng,
+    end2: h3o::LatLng,
+) -> bool {
+    let arc1 = (latlng::to_vec3(start1), latlng::to_vec3(end1));
+    let arc2 = (latlng::to_vec3(start2), latlng::to_vec3(end2));
+    // Short arcs have tiny normals: normalize them so that the same-circle
+    // test below doesn't depend on the arc lengths.
+    let (Some(normal1), Some(normal2)) = (
+        normalize(cross(arc1.0, arc1.1)),
+        normalize(cross(arc2.0, arc2.1)),
+    ) else {
+        // Degenerate arc (a single point): no great circle to intersect.
+        return false;
+    };
+    let line = cross(normal1, normal2);
+
+    // Arcs on the same great circle: they intersect if they overlap.
+    if norm(line) < f64::EPSILON {
+        return is_on_arc(arc2.0, arc1, normal1)
+            || is_on_arc(arc2.1, arc1, normal1)
+            || is_on_arc(arc1.0, arc2, normal2);
+    }
+
+    let candidates = [line, [-line[0], -line[1], -line[2]]];
+    candidates.into_iter().any(|point| {
+        is_on_arc(point, arc1, normal1) && is_on_arc(point, arc2, normal2)
+    })
+}
+
+/// Tests if `point`, located on the great circle of normal `normal`, lies on
+/// the minor arc `arc`.
+fn is_on_arc(
+    point: [f64; 3],
+    arc: ([f64; 3], [f64; 3]),
+    normal: [f64; 3],
+) -> bool {
+    dot(cross(arc.0, point), normal) >= 0.
+        && dot(cross(point, arc.1), normal) >= 0.
+}
+
+fn cross(u: [f64; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        u[1].mul_add(v[2], -u[2] * v[1]),
+        u[2].mul_add(v[0], -u[0] * v[2]),
+        u[0].mul_add(v[1], -u[1] * v[0]),
+    ]
+}
+
+fn dot(u: [f64; 3], v: [f64; 3]) -> f64 {
+    u[0].mul_add(v[0], u[1].mul_add(v[1], u[2] * v[2]))
+}
+
+fn norm(u: [f64; 3]) -> f64 {
+    dot(u, u).sqrt()
+}
+
+fn normalize(u: [f64; 3]) -> Option<[f64; 3]> {
+    let norm = norm(u);
+    (norm >= f64::EPSILON).then(|| [u[0] / norm, u[1] / norm, u[2] / norm])
+}
+
+/// Perimeter of H3 cell in kilometers.
+///
+/// The perimeter follows the cell boundary, including the extra distortion
+/// vertices of Class III cells.
+#[no_mangle]
+pub extern "C" fn cellPerimeterKm(
+    h: H3Index,
+    out: Option<&mut f64>,
+) -> H3Error {
+    fn inner(h: H3Index) -> Result<f64, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        Ok(perimeter(index, h3o::LatLng::distance_km))
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Perimeter of H3 cell in meters.
+///
+/// The perimeter follows the cell boundary, including the extra distortion
+/// vertices of Class III cells.
+#[no_mangle]
+pub extern "C" fn cellPerimeterM(h: H3Index, out: Option<&mut f64>) -> H3Error {
+    fn inner(h: H3Index) -> Result<f64, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        Ok(perimeter(index, h3o::LatLng::distance_m))
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Perimeter of H3 cell in radians.
+///
+/// The perimeter follows the cell boundary, including the extra distortion
+/// vertices of Class III cells.
+#[no_mangle]
+pub extern "C" fn cellPerimeterRads(
+    h: H3Index,
+    out: Option<&mut f64>,
+) -> H3Error {
+    fn inner(h: H3Index) -> Result<f64, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        Ok(perimeter(index, h3o::LatLng::distance_rads))
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Computes the shape metrics of an H3 cell.
+///
+/// The perimeter follows the cell boundary, including the extra distortion
+/// vertices of Class III cells.
+///
+/// @param h   H3 cell
+/// @param out Shape metrics of the cell
+#[no_mangle]
+pub extern "C" fn cellShapeMetrics(
+    h: H3Index,
+    out: Option<&mut ShapeMetrics>,
+) -> H3Error {
+    fn inner(h: H3Index) -> Result<ShapeMetrics, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let area = index.area_m2();
+        let perimeter = perimeter(index, h3o::LatLng::distance_m);
+        Ok(ShapeMetrics {
+            areaM2: area,
+            perimeterM: perimeter,
+            isoperimetricQuotient: 4. * PI * area / (perimeter * perimeter),
+            numVerts: convert::checked_int(index.boundary().len())?,
+        })
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Sums the great-circle distances between consecutive boundary vertices.
+fn perimeter(
+    index: CellIndex,
+    distance: fn(h3o::LatLng, h3o::LatLng) -> f64,
+) -> f64 {
+    let boundary = index.boundary();
+    let vertexes = boundary.iter().copied();
+    vertexes
+        .clone()
+        .zip(vertexes.cycle().skip(1))
+        .map(|(a, b)| distance(a, b))
+        .sum()
+}
+
 /// Determines the cell boundary in spherical coordinates for an H3 index.1
 //
 /// @param h3 The H3 index.
@@ -64,6 +589,268 @@ pub extern "C" fn cellToBoundary(
     delegate_inner!(inner(h3), gp)
 }
 
+/// Whether the boundary of a cell has distortion vertices.
+///
+/// On Class III resolutions, cellToBoundary may add vertices where the cell
+/// edges cross an icosahedron face edge, beyond the 5 (pentagon) or 6
+/// (hexagon) topological vertices.
+///
+/// @param h3  The H3 index.
+/// @param out Set to 1 if the boundary has distortion vertices, 0 otherwise.
+#[no_mangle]
+pub extern "C" fn cellBoundaryHasDistortion(
+    h3: H3Index,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(h3: H3Index) -> Result<c_int, H3Error> {
+        let index = CellIndex::try_from(h3)?;
+        let base = if index.is_pentagon() { 5 } else { 6 };
+        Ok((index.boundary().len() > base).into())
+    }
+
+    delegate_inner!(inner(h3), out)
+}
+
+/// Writes the boundary vertices of a cell, reprojected by a caller-provided
+/// projection, as interleaved `x, y` pairs.
+///
+/// The projection is invoked once per vertex, with coordinates in degrees and
+/// the user-provided context. If it returns a nonzero value, the conversion is
+/// aborted and E_FAILED is returned.
+///
+/// @param h        The H3 cell
+/// @param proj     Projection invoked for each vertex
+/// @param ctx      User context, passed as is to the projection
+/// @param out      Output coordinates, as `x, y` pairs
+/// @param cap      Capacity of `out`, in vertices
+/// @param numVerts Set to the number of vertices of the boundary, even when
+///                 `cap` is too small (optional)
+/// @return         E_MEMORY_BOUNDS if `cap` is too small.
+///
+/// # Safety
+///
+/// - `out` must points to an array of at least `2 * cap` elements.
+/// - `proj` must be safe to call with `ctx`.
+#[no_mangle]
+pub unsafe extern "C" fn cellToBoundaryProjected(
+    h: H3Index,
+    proj: H3Projection,
+    ctx: *mut c_void,
+    out: *mut f64,
+    cap: c_int,
+    numVerts: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        proj: H3Projection,
+        ctx: *mut c_void,
+        out: *mut f64,
+        cap: c_int,
+        numVerts: Option<&mut c_int>,
+    ) -> Result<(), H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let proj = proj.ok_or(H3ErrorCodes::EFailed)?;
+        let boundary = index.boundary();
+        let len = boundary.len();
+        if let Some(numVerts) = numVerts {
+            *numVerts = convert::checked_int(len)?;
+        }
+        if usize::try_from(cap).map_or(true, |cap| cap < len) {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let out = std::slice::from_raw_parts_mut(out, 2 * len);
+        for (xy, ll) in out.chunks_exact_mut(2).zip(boundary.iter()) {
+            let xy = xy.as_mut_ptr();
+            if proj(ll.lat(), ll.lng(), xy, xy.add(1), ctx) != 0 {
+                return Err(H3ErrorCodes::EFailed.into());
+            }
+        }
+        Ok(())
+    }
+
+    inner(h, proj, ctx, out, cap, numVerts)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Writes the center of a cell followed by its boundary vertices (in degrees),
+/// suitable for rendering the cell as a triangle fan (e.g. `GL_TRIANGLE_FAN`).
+///
+/// The `numVerts + 1` points aren't closed: the first boundary vertex isn't
+/// repeated at the end.
+///
+/// @param h     The H3 cell
+/// @param out   Output points, the center first
+/// @param cap   Capacity of `out`
+/// @param count Set to the number of points written in `out`
+/// @return      E_MEMORY_BOUNDS if `out` is too small.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToFan(
+    h: H3Index,
+    out: *mut LatLng,
+    cap: c_int,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        out: *mut LatLng,
+        cap: c_int,
+    ) -> Result<c_int, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let boundary = index.boundary();
+        let len = boundary.len() + 1;
+        if usize::try_from(cap).map_or(true, |cap| cap < len) {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let points = std::iter::once(h3o::LatLng::from(index))
+            .chain(boundary.iter().copied());
+        for (slot, ll) in out.iter_mut().zip(points) {
+            *slot = LatLng {
+                lat: ll.lat(),
+                lng: ll.lng(),
+            };
+        }
+        Ok(convert::checked_int(len)?)
+    }
+
+    delegate_inner!(inner(h, out, cap), count)
+}
+
+/// Determines the cell boundary (in degrees), with `pointsPerEdge` points
+/// interpolated along the great circle of each boundary edge.
+///
+/// Each boundary vertex is followed by the points of the edge starting from it,
+/// so `numVerts * (pointsPerEdge + 1)` points are written. The boundary isn't
+/// closed: the first vertex isn't repeated at the end.
+///
+/// @param h             The H3 cell
+/// @param pointsPerEdge Number of points inserted along each edge (>= 0)
+/// @param out           Output points
+/// @param cap           Capacity of `out`
+/// @param count         Set to the number of points written in `out`
+/// @return              E_DOMAIN if `pointsPerEdge` is negative,
+///                      E_MEMORY_BOUNDS if `out` is too small.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToBoundaryDensified(
+    h: H3Index,
+    pointsPerEdge: c_int,
+    out: *mut LatLng,
+    cap: c_int,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        pointsPerEdge: c_int,
+        out: *mut LatLng,
+        cap: c_int,
+    ) -> Result<c_int, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let points_per_edge = usize::try_from(pointsPerEdge)
+            .map_err(|_| H3ErrorCodes::EDomain)?;
+        let boundary = index.boundary();
+        let len = points_per_edge
+            .checked_add(1)
+            .and_then(|n| n.checked_mul(boundary.len()))
+            .ok_or(H3ErrorCodes::EMemoryBounds)?;
+        if usize::try_from(cap).map_or(true, |cap| cap < len) {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut slots = out.iter_mut();
+        let edges = boundary.iter().zip(boundary.iter().cycle().skip(1));
+        for (&start, &end) in edges {
+            let (a, b) = (latlng::to_vec3(start), latlng::to_vec3(end));
+            let angle = start.distance_rads(end);
+            let steps = f64::from(pointsPerEdge) + 1.;
+            let points = std::iter::once(Ok(start)).chain(
+                (1..=pointsPerEdge).map(|i| {
+                    let t = f64::from(i) / steps;
+                    latlng::from_vec3(latlng::slerp(a, b, angle, t))
+                }),
+            );
+            for (ll, slot) in points.zip(slots.by_ref()) {
+                let ll = ll?;
+                *slot = LatLng {
+                    lat: ll.lat(),
+                    lng: ll.lng(),
+                };
+            }
+        }
+        Ok(convert::checked_int(len)?)
+    }
+
+    delegate_inner!(inner(h, pointsPerEdge, out, cap), count)
+}
+
+/// Determines the cell boundary in spherical coordinates for an H3 index, in
+/// the requested winding order.
+///
+/// The counter-clockwise order is the one used by cellToBoundary, and the
+/// clockwise one is its exact reverse: `numVerts` is the same, and the first
+/// clockwise vertex is the last counter-clockwise one.
+///
+/// @param h3        The H3 index.
+/// @param clockwise Non-zero for clockwise order, 0 for counter-clockwise.
+/// @param gp        Output cell boundary.
+#[no_mangle]
+pub extern "C" fn cellToBoundaryWinding(
+    h3: H3Index,
+    clockwise: c_int,
+    gp: Option<&mut CellBoundary>,
+) -> H3Error {
+    fn inner(h3: H3Index, clockwise: c_int) -> Result<CellBoundary, H3Error> {
+        let index = CellIndex::try_from(h3)?;
+        let mut boundary = CellBoundary::from(index.boundary());
+        if clockwise != 0 {
+            let len = usize::try_from(boundary.numVerts).expect("vertex count");
+            boundary.verts[..len].reverse();
+        }
+        Ok(boundary)
+    }
+
+    delegate_inner!(inner(h3, clockwise), gp)
+}
+
+/// Computes the initial great-circle bearing from the center of a cell to the
+/// center of another one.
+///
+/// @param from Origin cell
+/// @param to   Destination cell
+/// @param out  Forward azimuth, in degrees clockwise from north, in [0, 360)
+#[no_mangle]
+pub extern "C" fn cellToCellBearingDegs(
+    from: H3Index,
+    to: H3Index,
+    out: Option<&mut f64>,
+) -> H3Error {
+    fn inner(from: H3Index, to: H3Index) -> Result<f64, H3Error> {
+        let from = h3o::LatLng::from(CellIndex::try_from(from)?);
+        let to = h3o::LatLng::from(CellIndex::try_from(to)?);
+        let (lat1, lat2) = (from.lat_radians(), to.lat_radians());
+        let delta_lng = to.lng_radians() - from.lng_radians();
+
+        let y = delta_lng.sin() * lat2.cos();
+        let x = lat1
+            .cos()
+            .mul_add(lat2.sin(), -(lat1.sin() * lat2.cos() * delta_lng.cos()));
+        Ok((y.atan2(x).to_degrees() + 360.) % 360.)
+    }
+
+    delegate_inner!(inner(from, to), out)
+}
+
 /// cellToCenterChild produces the center child index for a given H3 index at
 /// the specified resolution
 ///
@@ -91,7 +878,47 @@ pub extern "C" fn cellToCenterChild(
 
 /// cellToChildren takes the given hexagon id and generates all of the children
 /// at the specified resolution storing them into the provided memory pointer.
-/// It's assumed that cellToChildrenSize was used to determine the allocation.
+///
+/// The capacity of the output array is checked before anything is written: use
+/// cellToChildrenSize to determine the allocation.
+///
+/// @param h H3Index to find the children of
+/// @param childRes int the child level to produce
+/// @param children H3Index* the memory to store the resulting addresses in
+/// @param cap Capacity of `children`
+/// @return E_MEMORY_BOUNDS if `cap` is smaller than the number of children.
+///
+/// # Safety
+///
+/// `children` must points to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToChildren(
+    h: H3Index,
+    childRes: c_int,
+    children: *mut H3Index,
+    cap: i64,
+) -> H3Error {
+    fn inner(h: H3Index, childRes: c_int, cap: i64) -> Result<(), H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let child_res = convert::h3res_to_resolution(childRes)?;
+        let len = index.children_count(child_res);
+        if u64::try_from(cap).map_or(true, |cap| cap < len) {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        Ok(())
+    }
+
+    match inner(h, childRes, cap) {
+        Ok(()) => cellToChildrenUnchecked(h, childRes, children),
+        Err(err) => err,
+    }
+}
+
+/// cellToChildrenOrdered takes the given hexagon id and generates all of the
+/// children at the specified resolution, sorted by child position.
+///
+/// Unlike cellToChildren, the output order is guaranteed: `children[p]` is
+/// the child returned by `childPosToCell(p, h, childRes)`.
 ///
 /// @param h H3Index to find the children of
 /// @param childRes int the child level to produce
@@ -102,7 +929,7 @@ pub extern "C" fn cellToCenterChild(
 /// `children` must points to an array of at least `cellToChildrenSize(h,
 /// childRes)` elements.
 #[no_mangle]
-pub unsafe extern "C" fn cellToChildren(
+pub unsafe extern "C" fn cellToChildrenOrdered(
     h: H3Index,
     childRes: c_int,
     children: *mut H3Index,
@@ -110,17 +937,27 @@ pub unsafe extern "C" fn cellToChildren(
     fn inner(
         h: H3Index,
         childRes: c_int,
-    ) -> Result<(u64, impl Iterator<Item = CellIndex>), H3Error> {
+    ) -> Result<(usize, impl Iterator<Item = (usize, CellIndex)>), H3Error>
+    {
         let index = CellIndex::try_from(h)?;
+        let parent_res = index.resolution();
         let child_res = convert::h3res_to_resolution(childRes)?;
-        Ok((index.children_count(child_res), index.children(child_res)))
+        let len = convert::checked_int(index.children_count(child_res))?;
+        Ok((
+            len,
+            index.children(child_res).map(move |child| {
+                let position = child
+                    .child_position(parent_res)
+                    .expect("child of the cell");
+                (usize::try_from(position).expect("overflow"), child)
+            }),
+        ))
     }
 
     match inner(h, childRes) {
         Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
             let slice = std::slice::from_raw_parts_mut(children, len);
-            for (i, child) in iter.enumerate() {
+            for (i, child) in iter {
                 slice[i] = child.into();
             }
             H3ErrorCodes::ESuccess.into()
@@ -154,6 +991,186 @@ pub extern "C" fn cellToChildrenSize(
     delegate_inner!(inner(h, childRes), out)
 }
 
+/// cellToChildrenUnchecked is cellToChildren without the capacity check, for
+/// callers who already sized the output with cellToChildrenSize.
+///
+/// Nothing protects against an undersized output array: it's up to the caller
+/// to uphold the contract below.
+///
+/// @param h H3Index to find the children of
+/// @param childRes int the child level to produce
+/// @param children H3Index* the memory to store the resulting addresses in
+///
+/// # Safety
+///
+/// `children` must points to an array of at least `cellToChildrenSize(h,
+/// childRes)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToChildrenUnchecked(
+    h: H3Index,
+    childRes: c_int,
+    children: *mut H3Index,
+) -> H3Error {
+    fn inner(
+        h: H3Index,
+        childRes: c_int,
+    ) -> Result<(u64, impl Iterator<Item = CellIndex>), H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let child_res = convert::h3res_to_resolution(childRes)?;
+        Ok((index.children_count(child_res), index.children(child_res)))
+    }
+
+    match inner(h, childRes) {
+        Ok((len, iter)) => {
+            let len = usize::try_from(len).expect("overflow");
+            let slice = std::slice::from_raw_parts_mut(children, len);
+            for (i, child) in iter.enumerate() {
+                slice[i] = child.into();
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// Fills the children of many parents at once, in parallel.
+///
+/// The children of `parents[i]` are written at `out + offsets[i]`, in the same
+/// order as cellToChildren: offsets are typically the cumulative sum of the
+/// cellToChildrenSize of the previous parents.
+///
+/// @param parents  Set of parent cells
+/// @param nParents Number of parent cells
+/// @param childRes The child level to produce
+/// @param out      Output array for the children
+/// @param offsets  Offset of each parent's children in `out`
+/// @return         E_DOMAIN if the offsets aren't increasing or if two parents'
+///                 children would overlap.
+///
+/// # Safety
+///
+/// - `parents` and `offsets` must points to an array of at least `nParents`
+///   elements.
+/// - `out` must points to an array large enough to hold the children of the
+///   last parent at its offset.
+#[cfg(feature = "rayon")]
+#[no_mangle]
+pub unsafe extern "C" fn cellsToChildrenParallel(
+    parents: *const H3Index,
+    nParents: i64,
+    childRes: c_int,
+    out: *mut H3Index,
+    offsets: *const i64,
+) -> H3Error {
+    use rayon::prelude::*;
+
+    unsafe fn inner(
+        parents: *const H3Index,
+        nParents: i64,
+        childRes: c_int,
+        out: *mut H3Index,
+        offsets: *const i64,
+    ) -> Result<(), H3Error> {
+        let child_res = convert::h3res_to_resolution(childRes)?;
+        let len =
+            usize::try_from(nParents).map_err(|_| H3ErrorCodes::EDomain)?;
+        if len == 0 {
+            return Ok(());
+        }
+        let parents = convert::h3ptr_to_h3oslice(parents, nParents)?;
+        let offsets = std::slice::from_raw_parts(offsets, len);
+
+        // Compute the output range of every parent, which must not overlap.
+        let mut ranges = Vec::with_capacity(len);
+        let mut end = 0;
+        for (parent, &offset) in parents.iter().zip(offsets) {
+            let start = usize::try_from(offset)
+                .ok()
+                .filter(|&start| start >= end)
+                .ok_or(H3ErrorCodes::EDomain)?;
+            let count = usize::try_from(parent.children_count(child_res))
+                .map_err(|_| H3ErrorCodes::EMemoryBounds)?;
+            end = start.checked_add(count).ok_or(H3ErrorCodes::EDomain)?;
+            ranges.push((start, count));
+        }
+        if end == 0 {
+            return Ok(());
+        }
+
+        // Carve the disjoint output ranges, so they can be filled concurrently.
+        let mut rest = std::slice::from_raw_parts_mut(out, end);
+        let mut position = 0;
+        let mut chunks = Vec::with_capacity(len);
+        for (parent, (start, count)) in parents.iter().zip(ranges) {
+            let (_, tail) = rest.split_at_mut(start - position);
+            let (chunk, tail) = tail.split_at_mut(count);
+            chunks.push((*parent, chunk));
+            rest = tail;
+            position = start + count;
+        }
+
+        chunks.into_par_iter().for_each(|(parent, chunk)| {
+            for (slot, child) in
+                chunk.iter_mut().zip(parent.children(child_res))
+            {
+                *slot = child.into();
+            }
+        });
+        Ok(())
+    }
+
+    inner(parents, nParents, childRes, out, offsets)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Produces the hexagonal children of a cell at the given resolution.
+///
+/// Pentagon descendants are skipped.
+///
+/// @param h        H3Index to find the children of
+/// @param childRes The child resolution
+/// @param out      Output array for the hexagonal children
+/// @param count    Set to the number of children written in `out`
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `cellToChildrenSize(h,
+/// childRes)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToHexagonChildren(
+    h: H3Index,
+    childRes: c_int,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        childRes: c_int,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let child_res = convert::h3res_to_resolution(childRes)?;
+        let len = convert::checked_int(index.children_count(child_res))?;
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut count = 0;
+        for child in index
+            .children(child_res)
+            .filter(|child| !child.is_pentagon())
+        {
+            out[count] = child.into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(h, childRes, out), count)
+}
+
 /// Determines the spherical coordinates of the center point of an H3 index.
 ///
 /// @param h3 The H3 index.
@@ -167,6 +1184,33 @@ pub extern "C" fn cellToLatLng(h3: H3Index, g: Option<&mut LatLng>) -> H3Error {
     delegate_inner!(inner(h3), g)
 }
 
+/// Determines the center point of an H3 index, in degrees, along with an
+/// altitude.
+///
+/// The altitude isn't derived from the cell: it's passed through unchanged,
+/// for 3D pipelines that carry it alongside the coordinates.
+///
+/// @param h   The H3 index.
+/// @param alt The altitude.
+/// @param out Set to the center of the cell, with the altitude.
+#[no_mangle]
+pub extern "C" fn cellToLatLngAltDegs(
+    h: H3Index,
+    alt: f64,
+    out: Option<&mut LatLngAlt>,
+) -> H3Error {
+    fn inner(h: H3Index, alt: f64) -> Result<LatLngAlt, H3Error> {
+        let center = h3o::LatLng::from(CellIndex::try_from(h)?);
+        Ok(LatLngAlt {
+            lat: center.lat(),
+            lng: center.lng(),
+            alt,
+        })
+    }
+
+    delegate_inner!(inner(h, alt), out)
+}
+
 /// cellToParent produces the parent index for a given H3 index
 ///
 /// @param h H3Index to find parent of
@@ -190,6 +1234,331 @@ pub extern "C" fn cellToParent(
     delegate_inner!(inner(h, parentRes), parent)
 }
 
+/// Produces the pentagonal children of a cell at the given resolution.
+///
+/// Only a pentagon has a pentagonal descendant (its center child), so at most
+/// one cell is written.
+///
+/// @param h        H3Index to find the children of
+/// @param childRes The child resolution
+/// @param out      Output array for the pentagonal children
+/// @param count    Set to the number of children written in `out`
+///
+/// # Safety
+///
+/// `out` must points to an array of at least one element.
+#[no_mangle]
+pub unsafe extern "C" fn cellToPentagonChildren(
+    h: H3Index,
+    childRes: c_int,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        childRes: c_int,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let child_res = convert::h3res_to_resolution(childRes)?;
+        let child = index
+            .center_child(child_res)
+            .filter(|_| index.is_pentagon());
+
+        Ok(child.map_or(0, |child| {
+            *out = child.into();
+            1
+        }))
+    }
+
+    delegate_inner!(inner(h, childRes, out), count)
+}
+
+/// Sums the exact areas of a set of H3 cells, in meters^2.
+///
+/// Invalid cells are skipped.
+///
+/// @param cells      Set of cells
+/// @param numCells   The number of cells in the input set
+/// @param out        Total area of the valid cells, in meters^2
+/// @param numSkipped Number of invalid cells that were skipped (optional)
+/// @return           E_SUCCESS on success, or E_DOMAIN for a negative size.
+///
+/// # Safety
+///
+/// `cells` must points to an array of at least `numCells` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellsAreaM2(
+    cells: *const H3Index,
+    numCells: i64,
+    out: Option<&mut f64>,
+    numSkipped: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        numCells: i64,
+        numSkipped: Option<&mut i64>,
+    ) -> Result<f64, H3Error> {
+        let len =
+            usize::try_from(numCells).map_err(|_| H3ErrorCodes::EDomain)?;
+        let cells = if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(cells, len)
+        };
+
+        let mut skipped = 0;
+        let area = cells
+            .iter()
+            .filter_map(|&cell| {
+                let index = CellIndex::try_from(cell).ok();
+                skipped += i64::from(index.is_none());
+                index
+            })
+            .map(CellIndex::area_m2)
+            .sum();
+
+        if let Some(numSkipped) = numSkipped {
+            *numSkipped = skipped;
+        }
+        Ok(area)
+    }
+
+    delegate_inner!(inner(cells, numCells, numSkipped), out)
+}
+
+/// Writes the boundaries of a set of cells, packed contiguously into a single
+/// flat buffer.
+///
+/// The boundary of each cell is written, in order, as `lat, lng` pairs (in
+/// degrees), right after the one of the previous cell. `vertCounts[i]` gives
+/// the number of vertices of the i-th cell, from which the offsets can be
+/// computed.
+///
+/// @param cells      Set of cells
+/// @param n          The number of cells in the input set
+/// @param coords     Output coordinates, as `lat, lng` pairs in degrees
+/// @param coordsCap  Capacity of `coords`, in number of doubles
+/// @param vertCounts Output number of vertices of each cell
+/// @return           E_MEMORY_BOUNDS if `coordsCap` is exceeded.
+///
+/// # Safety
+///
+/// - `cells` and `vertCounts` must points to an array of at least `n`
+///   elements.
+/// - `coords` must points to an array of at least `coordsCap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellsToBoundariesFlat(
+    cells: *const H3Index,
+    n: i64,
+    coords: *mut f64,
+    coordsCap: c_int,
+    vertCounts: *mut c_int,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        coords: *mut f64,
+        coordsCap: c_int,
+        vertCounts: *mut c_int,
+    ) -> Result<(), H3Error> {
+        let len = usize::try_from(n).map_err(|_| H3ErrorCodes::EDomain)?;
+        let cap =
+            usize::try_from(coordsCap).map_err(|_| H3ErrorCodes::EDomain)?;
+        if len == 0 {
+            return Ok(());
+        }
+
+        let cells = std::slice::from_raw_parts(cells, len);
+        let vert_counts = std::slice::from_raw_parts_mut(vertCounts, len);
+        let boundaries = cells
+            .iter()
+            .map(|&cell| Ok(CellIndex::try_from(cell)?.boundary()))
+            .collect::<Result<Vec<_>, H3Error>>()?;
+        let total = boundaries.iter().map(|boundary| 2 * boundary.len()).sum();
+        if total > cap {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        if total == 0 {
+            return Ok(());
+        }
+
+        let coords = std::slice::from_raw_parts_mut(coords, total);
+        let mut offset = 0;
+        for (boundary, count) in boundaries.iter().zip(vert_counts) {
+            *count = convert::checked_int(boundary.len())?;
+            for ll in boundary.iter() {
+                coords[offset] = ll.lat();
+                coords[offset + 1] = ll.lng();
+                offset += 2;
+            }
+        }
+        Ok(())
+    }
+
+    inner(cells, n, coords, coordsCap, vertCounts)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Produces the parents of a set of cells at the given resolution.
+///
+/// Cells that are invalid, or whose resolution is coarser than `parentRes`,
+/// get `H3_NULL` as parent: the other cells are still processed.
+///
+/// @param cells     Set of cells
+/// @param numCells  The number of cells in the input and output arrays
+/// @param parentRes The resolution of the parents
+/// @param out       Output array for the parents
+/// @return          E_SUCCESS on success, E_RES_DOMAIN for an invalid
+///                  resolution or E_DOMAIN for a negative size.
+///
+/// # Safety
+///
+/// `cells` and `out` must points to an array of at least `numCells` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellsToParents(
+    cells: *const H3Index,
+    numCells: i64,
+    parentRes: c_int,
+    out: *mut H3Index,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        numCells: i64,
+        parentRes: c_int,
+        out: *mut H3Index,
+    ) -> Result<(), H3Error> {
+        let parent_res = convert::h3res_to_resolution(parentRes)?;
+        let len =
+            usize::try_from(numCells).map_err(|_| H3ErrorCodes::EDomain)?;
+        if len == 0 {
+            return Ok(());
+        }
+
+        let cells = std::slice::from_raw_parts(cells, len);
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (&cell, parent) in cells.iter().zip(out.iter_mut()) {
+            *parent = CellIndex::try_from(cell)
+                .ok()
+                .and_then(|index| index.parent(parent_res))
+                .map_or(H3_NULL, Into::into);
+        }
+        Ok(())
+    }
+
+    inner(cells, numCells, parentRes, out)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Produces the unique parents, at the given resolution, covering a set of
+/// cells.
+///
+/// Parents are written in order of first appearance. Like for cellsToParents,
+/// cells that are invalid or coarser than `parentRes` are skipped.
+///
+/// @param cells     Set of cells
+/// @param numCells  The number of cells in the input array
+/// @param parentRes The resolution of the parents
+/// @param out       Output array for the unique parents
+/// @param count     Set to the number of unique parents written in `out`.
+///
+/// # Safety
+///
+/// `cells` and `out` must points to an array of at least `numCells` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellsToParentsUnique(
+    cells: *const H3Index,
+    numCells: i64,
+    parentRes: c_int,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        numCells: i64,
+        parentRes: c_int,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let parent_res = convert::h3res_to_resolution(parentRes)?;
+        let len =
+            usize::try_from(numCells).map_err(|_| H3ErrorCodes::EDomain)?;
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let cells = std::slice::from_raw_parts(cells, len);
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut seen = HashSet::new();
+        let parents = cells
+            .iter()
+            .filter_map(|&cell| CellIndex::try_from(cell).ok())
+            .filter_map(|index| index.parent(parent_res))
+            .filter(|&parent| seen.insert(parent));
+        let mut count = 0;
+        for parent in parents {
+            out[count] = parent.into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(cells, numCells, parentRes, out), count)
+}
+
+/// Returns the center of an icosahedron face, in degrees.
+///
+/// @param faceNum Icosahedron face number, between 0 and 19
+/// @param out     Center of the face, in degrees
+/// @return        E_DOMAIN if the face number is out of range.
+#[no_mangle]
+pub extern "C" fn faceCenterLatLng(
+    faceNum: c_int,
+    out: Option<&mut LatLng>,
+) -> H3Error {
+    fn inner(faceNum: c_int) -> Result<LatLng, H3Error> {
+        let face = u8::try_from(faceNum)
+            .ok()
+            .and_then(|face| h3o::Face::try_from(face).ok())
+            .ok_or(H3ErrorCodes::EDomain)?;
+        let (lat, lng) = FACE_CENTER_GEO[usize::from(face)];
+        Ok(LatLng {
+            lat: lat.to_degrees(),
+            lng: lng.to_degrees(),
+        })
+    }
+
+    delegate_inner!(inner(faceNum), out)
+}
+
+/// Icosahedron face centers in lat/lng radians.
+///
+/// Mirror of H3's `faceCenterGeo`, which h3o doesn't expose.
+#[rustfmt::skip]
+static FACE_CENTER_GEO: [(f64, f64); 20] = [
+    ( 0.80358264971899,     1.2483974196173961),
+    ( 1.3077478834556382,   2.5369450098779214),
+    ( 1.054751253523952,   -1.3475173589003966),
+    ( 0.6001915955381868,  -0.45060390946975576),
+    ( 0.49171542819877384,  0.40198820291130694),
+    ( 0.1727453274156187,   1.6781468852804338),
+    ( 0.6059293215713507,   2.9539233298124117),
+    ( 0.42737051832897965, -1.8888762003362853),
+    (-0.07906611854921283, -0.7334295133808677),
+    (-0.23096164445538364,  0.506495587332349),
+    ( 0.07906611854921283,  2.4081631402089254),
+    ( 0.23096164445538364, -2.635097066257444),
+    (-0.1727453274156187,  -1.4634457683093596),
+    (-0.6059293215713507,  -0.18766932377738163),
+    (-0.42737051832897965,  1.2527164532535078),
+    (-0.6001915955381868,   2.6909887441200375),
+    (-0.49171542819877384, -2.7396044506784865),
+    (-0.80358264971899,    -1.8931952339723972),
+    (-1.3077478834556382,  -0.6046476437118721),
+    (-1.054751253523952,    1.7940752946893965),
+];
+
 /// Returns the H3 base cell "number" of an H3 cell (hexagon or pentagon).
 ///
 /// @param h The H3 cell.
@@ -284,8 +1653,48 @@ pub extern "C" fn maxFaceCount(
     delegate_inner!(inner(h3), out)
 }
 
+/// Attempts to repair a corrupted cell index.
+///
+/// This is a best-effort heuristic, for data cleaning: the reserved high bit
+/// is cleared, the mode is forced to "cell", the reserved mode-dependent bits
+/// are cleared and the unused digits (beyond the resolution) are reset to 7.
+/// The resolution, base cell and used digits are kept as is: if the result is
+/// still invalid, the index is deemed unrepairable.
+///
+/// Note that a repaired index is valid, but not necessarily the cell that was
+/// originally intended.
+///
+/// @param maybeCell The index to repair
+/// @param out       The repaired cell
+/// @return          E_CELL_INVALID if the index can't be repaired.
+#[no_mangle]
+pub extern "C" fn repairCellIndex(
+    maybeCell: H3Index,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(maybeCell: H3Index) -> Result<H3Index, H3Error> {
+        const CLEARED_MASK: u64 =
+            bits::HIGH_BIT_MASK | bits::MODE_MASK | bits::RESERVED_MASK;
+
+        let resolution =
+            u32::try_from((maybeCell & bits::RES_MASK) >> bits::RES_OFFSET)
+                .expect("4-bit res");
+        // Unused digits are all set to 7 (i.e. all bits set).
+        let unused_mask = (1_u64 << bits::digit_offset(resolution)) - 1;
+        let repaired = (maybeCell & !CLEARED_MASK)
+            | (bits::CELL_MODE << bits::MODE_OFFSET)
+            | unused_mask;
+
+        Ok(CellIndex::try_from(repaired)?.into())
+    }
+
+    delegate_inner!(inner(maybeCell), out)
+}
+
 /// Returns the position of the cell within an ordered list of all children of
 /// the cell's parent at the specified resolution.
+///
+/// Returns E_RES_MISMATCH if `parentRes` is finer than the cell's resolution.
 #[no_mangle]
 pub extern "C" fn cellToChildPos(
     child: H3Index,
@@ -296,9 +1705,12 @@ pub extern "C" fn cellToChildPos(
         let index = CellIndex::try_from(child)
             .map_err(|_| H3ErrorCodes::ECellInvalid)?;
         let parent_res = convert::h3res_to_resolution(parentRes)?;
+        if parent_res > index.resolution() {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
         let position = index
             .child_position(parent_res)
-            .ok_or(H3ErrorCodes::EResMismatch)?;
+            .expect("parent resolution checked above");
         Ok(position.try_into().expect("overflow"))
     }
 
@@ -307,6 +1719,9 @@ pub extern "C" fn cellToChildPos(
 
 /// Returns the child cell at a given position within an ordered list of all
 /// children at the specified resolution.
+///
+/// Returns E_DOMAIN if `childPos` is negative or past the last child, and
+/// E_RES_MISMATCH if `childRes` is coarser than the parent's resolution.
 #[no_mangle]
 pub extern "C" fn childPosToCell(
     childPos: i64,
@@ -324,9 +1739,12 @@ pub extern "C" fn childPosToCell(
         let child_pos =
             u64::try_from(childPos).map_err(|_| H3ErrorCodes::EDomain)?;
         let child_res = convert::h3res_to_resolution(childRes)?;
+        if child_res < index.resolution() {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
         let child = index
             .child_at(child_pos, child_res)
-            .ok_or(H3ErrorCodes::EResMismatch)?;
+            .ok_or(H3ErrorCodes::EDomain)?;
         Ok(child.into())
     }
 
@@ -1,6 +1,64 @@
 use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index};
-use h3o::CellIndex;
-use std::ffi::c_int;
+use h3o::{CellIndex, Resolution};
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::c_int,
+};
+
+/// cellSetResolutions lists the distinct resolutions present in a set of
+/// cells, e.g. to decide whether a compacted set needs to be uncompacted.
+///
+/// Resolutions are written in increasing order. Invalid cells are skipped.
+///
+/// @param   cells    Set of cells, possibly of mixed resolutions
+/// @param   n        The number of cells in the input set
+/// @param   present  Output array for the resolutions found
+/// @param   count    Set to the number of resolutions written in `present`
+/// @return           E_DOMAIN if `n` is negative.
+///
+/// # Safety
+///
+/// - `cells` must points to an array of at least `n` elements.
+/// - `present` must points to an array of at least 16 elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetResolutions(
+    cells: *const H3Index,
+    n: i64,
+    present: *mut c_int,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        present: *mut c_int,
+    ) -> Result<c_int, H3Error> {
+        let len = usize::try_from(n).map_err(|_| H3ErrorCodes::EDomain)?;
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let cells = std::slice::from_raw_parts(cells, len);
+        let mut found = [false; 16];
+        for index in cells
+            .iter()
+            .filter_map(|&cell| CellIndex::try_from(cell).ok())
+        {
+            found[usize::from(index.resolution())] = true;
+        }
+
+        let present = std::slice::from_raw_parts_mut(present, found.len());
+        let mut count = 0;
+        for res in Resolution::range(Resolution::Zero, Resolution::Fifteen)
+            .filter(|&res| found[usize::from(res)])
+        {
+            present[count] = u8::from(res).into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(cells, n, present), count)
+}
 
 /// compactCells takes a set of hexagons all at the same resolution and
 /// compresses them by pruning full child branches to the parent level. This is
@@ -50,6 +108,159 @@ pub unsafe extern "C" fn compactCells(
     }
 }
 
+/// compactCellsWithGroups compacts a set of cells like compactCells, and also
+/// reports which compacted cell each original cell collapsed into.
+///
+/// `groupOf[i]` is the index, in `compacted`, of the cell covering the i-th
+/// input cell. This allows aggregating per-cell attributes during compaction.
+///
+/// @param cells          Set of cells, all at the same resolution
+/// @param n              The number of cells in the input set
+/// @param compacted      The output array of compacted cells
+/// @param groupOf        The output array of group indexes, one per input cell
+/// @param compactedCount Set to the number of cells written in `compacted`
+/// @return an error code on bad input data
+///
+/// # Safety
+///
+/// `cells`, `compacted` and `groupOf` must points to an array of at least `n`
+/// elements.
+#[no_mangle]
+pub unsafe extern "C" fn compactCellsWithGroups(
+    cells: *const H3Index,
+    n: i64,
+    compacted: *mut H3Index,
+    groupOf: *mut c_int,
+    compactedCount: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        compacted: *mut H3Index,
+        groupOf: *mut c_int,
+    ) -> Result<i64, H3Error> {
+        if n == 0 {
+            return Ok(0);
+        }
+        let indexes = convert::h3ptr_to_h3oslice(cells, n)?;
+        let groups = CellIndex::compact(indexes.iter().copied())?
+            .enumerate()
+            .map(|(i, cell)| (cell, i))
+            .collect::<HashMap<_, _>>();
+
+        let compacted =
+            std::slice::from_raw_parts_mut(compacted, indexes.len());
+        for (&cell, &i) in &groups {
+            compacted[i] = cell.into();
+        }
+
+        let group_of = std::slice::from_raw_parts_mut(groupOf, indexes.len());
+        for (group, index) in group_of.iter_mut().zip(indexes) {
+            // The covering cell is either the cell itself or an ancestor.
+            let i = Resolution::range(Resolution::Zero, index.resolution())
+                .rev()
+                .filter_map(|res| index.parent(res))
+                .find_map(|parent| groups.get(&parent))
+                .ok_or(H3ErrorCodes::EFailed)?;
+            *group = convert::checked_int(*i)?;
+        }
+
+        Ok(convert::checked_int(groups.len())?)
+    }
+
+    delegate_inner!(inner(cells, n, compacted, groupOf), compactedCount)
+}
+
+/// compactedCellsAreaM2 sums the exact areas of a compacted set of cells, in
+/// meters^2, without uncompacting it first.
+///
+/// Cells of mixed resolutions are expected here.
+///
+/// @param   compactedSet  Set of compacted cells
+/// @param   numCompacted  The number of cells in the input compacted set
+/// @param   out           Total area of the set, in meters^2
+/// @return                E_CELL_INVALID if any cell is invalid.
+///
+/// # Safety
+///
+/// `compactedSet` must points to an array of at least `numCompacted` elements.
+#[no_mangle]
+pub unsafe extern "C" fn compactedCellsAreaM2(
+    compactedSet: *const H3Index,
+    numCompacted: i64,
+    out: Option<&mut f64>,
+) -> H3Error {
+    unsafe fn inner(
+        compactedSet: *const H3Index,
+        numCompacted: i64,
+    ) -> Result<f64, H3Error> {
+        let indexes = convert::h3ptr_to_h3oslice(compactedSet, numCompacted)?;
+
+        Ok(indexes.iter().copied().map(CellIndex::area_m2).sum())
+    }
+
+    if numCompacted == 0 {
+        if let Some(out) = out {
+            *out = 0.;
+        }
+        return H3ErrorCodes::ESuccess.into();
+    }
+    delegate_inner!(inner(compactedSet, numCompacted), out)
+}
+
+/// compactedSetContains checks whether a cell is covered by a compacted set of
+/// cells, without uncompacting it.
+///
+/// The cell is covered when either itself or one of its ancestors is part of
+/// the set.
+///
+/// @param   compacted  Set of compacted cells
+/// @param   n          The number of cells in the input compacted set
+/// @param   target     The cell to look for
+/// @param   out        Set to 1 if the cell is covered, 0 otherwise
+/// @return             E_CELL_INVALID if any cell is invalid.
+///
+/// # Safety
+///
+/// `compacted` must points to an array of at least `n` elements.
+#[no_mangle]
+pub unsafe extern "C" fn compactedSetContains(
+    compacted: *const H3Index,
+    n: i64,
+    target: H3Index,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        compacted: *const H3Index,
+        n: i64,
+        target: H3Index,
+    ) -> Result<c_int, H3Error> {
+        let target = CellIndex::try_from(target)?;
+        if n < 0 {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        if n == 0 {
+            return Ok(0);
+        }
+        let indexes = convert::h3ptr_to_h3oslice(compacted, n)?;
+        let indexes = indexes.iter().copied().collect::<HashSet<_>>();
+
+        // Walk up from the target to the coarsest ancestor.
+        let resolution = u8::from(target.resolution());
+        let found = (0..=resolution)
+            .rev()
+            .filter_map(|res| {
+                let res = Resolution::try_from(res).ok()?;
+                target.parent(res)
+            })
+            .any(|ancestor| indexes.contains(&ancestor));
+
+        Ok(found.into())
+    }
+
+    delegate_inner!(inner(compacted, n, target), out)
+}
+
 /// uncompactCells takes a compressed set of cells and expands back to the
 /// original set of cells.
 ///
@@ -107,6 +318,7 @@ pub unsafe extern "C" fn uncompactCells(
 /// @param   numHexes      The number of hexes in the input set
 /// @param   res           The hexagon resolution to decompress to
 /// @param   out           The number of hexagons to allocate memory for
+/// @return  E_MEMORY_BOUNDS if the size doesn't fit in 64-bit.
 ///
 /// # Safety
 ///
@@ -126,9 +338,15 @@ pub unsafe extern "C" fn uncompactCellsSize(
         let res = convert::h3res_to_resolution(res)?;
         let indexes = convert::h3ptr_to_h3oslice(compactedSet, numCompacted)?;
 
-        Ok(CellIndex::uncompact_size(indexes.iter().copied(), res)
-            .try_into()
-            .expect("positive count"))
+        // Large sets expanded to fine resolutions may not fit.
+        indexes
+            .iter()
+            .try_fold(0_i64, |total, index| {
+                i64::try_from(index.children_count(res))
+                    .ok()
+                    .and_then(|count| total.checked_add(count))
+            })
+            .ok_or_else(|| H3ErrorCodes::EMemoryBounds.into())
     }
 
     if numCompacted == 0 {
@@ -1,4 +1,4 @@
-use crate::{H3Error, H3ErrorCodes, H3Index};
+use crate::{H3Error, H3ErrorCodes, H3Index, LatLng};
 use h3o::{CellIndex, Resolution};
 use std::ffi::c_int;
 
@@ -7,6 +7,51 @@ pub fn h3res_to_resolution(res: c_int) -> Result<Resolution, H3ErrorCodes> {
     res.try_into().map_err(|_| H3ErrorCodes::EResDomain)
 }
 
+/// Convert an integer into another integer type, without panicking.
+///
+/// Values that don't fit in the target type are reported as `EFailed`.
+pub fn checked_int<T, U>(value: T) -> Result<U, H3ErrorCodes>
+where
+    U: TryFrom<T>,
+{
+    U::try_from(value).map_err(|_| H3ErrorCodes::EFailed)
+}
+
+/// Convert a buffer size estimate into the C output type.
+///
+/// Estimates that don't fit cannot be allocated anyway, so they're reported as
+/// `EMemoryBounds`.
+pub fn size_estimate(count: usize) -> Result<i64, H3ErrorCodes> {
+    i64::try_from(count).map_err(|_| H3ErrorCodes::EMemoryBounds)
+}
+
+/// Check raw coordinates, with latitudes in `[-max_lat, max_lat]`.
+///
+/// Non-finite values are malformed inputs (`EFailed`), whereas finite
+/// latitudes beyond the poles are off the globe (`ELatlngDomain`).
+///
+/// This is for the degree-based functions only: like H3, the radian-based ones
+/// report every invalid coordinate as `ELatlngDomain`.
+pub fn check_latlng(
+    lat: f64,
+    lng: f64,
+    max_lat: f64,
+) -> Result<(), H3ErrorCodes> {
+    if !(lat.is_finite() && lng.is_finite()) {
+        return Err(H3ErrorCodes::EFailed);
+    }
+    if lat.abs() > max_lat {
+        return Err(H3ErrorCodes::ELatlngDomain);
+    }
+    Ok(())
+}
+
+/// Convert coordinates in degrees into a `LatLng`.
+pub fn latlng_from_degrees(ll: &LatLng) -> Result<h3o::LatLng, H3Error> {
+    check_latlng(ll.lat, ll.lng, 90.)?;
+    Ok(h3o::LatLng::new(ll.lat, ll.lng)?)
+}
+
 /// Cast a C-array (ptr + len) of `H3Index` into a slice of `CellIndex`.
 ///
 /// # Safety
@@ -54,3 +99,62 @@ pub unsafe fn h3ptr_to_h3oslice_mut<'a>(
     // Cast to avoid a copy (safe because CellIndex is repr(tranparent)).
     Ok(&mut *(indexes as *mut [H3Index] as *mut [CellIndex]))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_int_boundaries() {
+        assert!(matches!(checked_int::<u32, c_int>(0), Ok(0)));
+        assert!(matches!(
+            checked_int::<u32, c_int>(c_int::MAX.unsigned_abs()),
+            Ok(c_int::MAX)
+        ));
+        assert!(matches!(
+            checked_int::<u32, c_int>(c_int::MAX.unsigned_abs() + 1),
+            Err(H3ErrorCodes::EFailed)
+        ));
+        assert!(matches!(
+            checked_int::<u64, i64>(i64::MAX.unsigned_abs()),
+            Ok(i64::MAX)
+        ));
+        assert!(matches!(
+            checked_int::<u64, i64>(u64::MAX),
+            Err(H3ErrorCodes::EFailed)
+        ));
+        assert!(matches!(
+            checked_int::<i64, usize>(-1),
+            Err(H3ErrorCodes::EFailed)
+        ));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn size_estimate_overflow() {
+        assert!(matches!(size_estimate(0), Ok(0)));
+        assert!(matches!(size_estimate(usize::MAX / 2), Ok(i64::MAX)));
+        assert!(matches!(
+            size_estimate(usize::MAX),
+            Err(H3ErrorCodes::EMemoryBounds)
+        ));
+    }
+
+    #[test]
+    fn check_latlng_errors() {
+        assert!(check_latlng(90., 180., 90.).is_ok());
+        assert!(check_latlng(-90., 1000., 90.).is_ok());
+        assert!(matches!(
+            check_latlng(100., 0., 90.),
+            Err(H3ErrorCodes::ELatlngDomain)
+        ));
+        assert!(matches!(
+            check_latlng(f64::NAN, 0., 90.),
+            Err(H3ErrorCodes::EFailed)
+        ));
+        assert!(matches!(
+            check_latlng(0., f64::INFINITY, 90.),
+            Err(H3ErrorCodes::EFailed)
+        ));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{delegate_inner, CellBoundary, H3Error, H3ErrorCodes, H3Index};
+use crate::{
+    delegate_inner, latlng, CellBoundary, H3Error, H3ErrorCodes, H3Index,
+    LatLng,
+};
 use h3o::{CellIndex, DirectedEdgeIndex};
 use std::ffi::c_int;
 
@@ -28,6 +31,79 @@ pub extern "C" fn areNeighborCells(
     delegate_inner!(inner(origin, destination), out)
 }
 
+/// Returns how two cells are adjacent to each other.
+///
+/// The edge test is done first (as in areNeighborCells), then the vertex test.
+/// Note that within a single resolution, cells sharing a vertex always share
+/// an edge too (every vertex is shared by 3 mutually adjacent cells), so a
+/// vertex-only adjacency denotes an inconsistent topology.
+///
+/// @param a   The first H3 index.
+/// @param b   The second H3 index.
+/// @param out Set to 0 if the cells aren't adjacent (or are the same cell), 1
+///            if they share an edge, 2 if they share only a vertex.
+///
+/// @return E_RES_MISMATCH if the cells aren't at the same resolution.
+#[no_mangle]
+pub extern "C" fn cellAdjacencyType(
+    a: H3Index,
+    b: H3Index,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(a: H3Index, b: H3Index) -> Result<c_int, H3Error> {
+        let a = CellIndex::try_from(a)?;
+        let b = CellIndex::try_from(b)?;
+        if a.resolution() != b.resolution() {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
+        if a == b {
+            return Ok(0);
+        }
+
+        if a.is_neighbor_with(b).unwrap_or_default() {
+            return Ok(1);
+        }
+        let shares_vertex = a
+            .vertexes()
+            .any(|vertex| b.vertexes().any(|other| other == vertex));
+        Ok(if shares_vertex { 2 } else { 0 })
+    }
+
+    delegate_inner!(inner(a, b), out)
+}
+
+/// Returns whether or not the provided H3Indexes are within `k` grid steps of
+/// each other.
+///
+/// When the grid distance cannot be computed (e.g. cells too far apart, on
+/// opposite sides of a pentagon or at different resolutions), the cells are
+/// considered out of reach.
+///
+/// @param a The first H3 index.
+/// @param b The second H3 index.
+/// @param k Maximum grid distance.
+/// @param out Set to 1 if the grid distance is at most `k`, 0 otherwise
+///
+/// @return Error code if one of the indexes is invalid.
+#[no_mangle]
+pub extern "C" fn areWithinGridDistance(
+    a: H3Index,
+    b: H3Index,
+    k: c_int,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(a: H3Index, b: H3Index, k: c_int) -> Result<c_int, H3Error> {
+        let a = CellIndex::try_from(a)?;
+        let b = CellIndex::try_from(b)?;
+
+        Ok(a.grid_distance(b)
+            .is_ok_and(|distance| distance <= k)
+            .into())
+    }
+
+    delegate_inner!(inner(a, b, k), out)
+}
+
 /// Returns a directed edge H3 index based on the provided origin and
 /// destination
 ///
@@ -57,6 +133,80 @@ pub extern "C" fn cellsToDirectedEdge(
     delegate_inner!(inner(origin, destination), out)
 }
 
+/// Returns the 6 (or 5 for pentagons) neighbors of the H3Index, paired with
+/// the directed edges leading to them.
+///
+/// `neighbors[i]` is the destination of `edges[i]`.
+///
+/// @param h The origin H3 index
+/// @param neighbors Output array for the neighbor cells
+/// @param edges Output array for the directed edges
+/// @param count Set to the number of neighbors written (5 or 6)
+///
+/// # Safety
+///
+/// `neighbors` and `edges` must points to an array of at least `6` elements
+/// each (`5` for pentagons).
+#[no_mangle]
+pub unsafe extern "C" fn cellToNeighborEdges(
+    h: H3Index,
+    neighbors: *mut H3Index,
+    edges: *mut H3Index,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        neighbors: *mut H3Index,
+        edges: *mut H3Index,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(h)?;
+        let len = if origin.is_pentagon() { 5 } else { 6 };
+
+        let neighbors = std::slice::from_raw_parts_mut(neighbors, len);
+        let edges = std::slice::from_raw_parts_mut(edges, len);
+        for (i, edge) in origin.edges().enumerate() {
+            neighbors[i] = edge.destination().into();
+            edges[i] = edge.into();
+        }
+
+        Ok(c_int::try_from(len).expect("5 or 6"))
+    }
+
+    delegate_inner!(inner(h, neighbors, edges), count)
+}
+
+/// Computes the great-circle midpoint of a directed edge, in degrees.
+///
+/// The midpoint is taken between the two endpoints of the edge boundary.
+///
+/// @param edge The directed edge H3Index
+/// @param out  Midpoint of the edge, in degrees
+#[no_mangle]
+pub extern "C" fn directedEdgeMidpointDegs(
+    edge: H3Index,
+    out: Option<&mut LatLng>,
+) -> H3Error {
+    fn inner(edge: H3Index) -> Result<LatLng, H3Error> {
+        let index = DirectedEdgeIndex::try_from(edge)?;
+        let boundary = index.boundary();
+        let (Some(&start), Some(&end)) =
+            (boundary.iter().next(), boundary.iter().last())
+        else {
+            return Err(H3ErrorCodes::EFailed.into());
+        };
+
+        // Average the endpoints as 3D unit vectors, then project back.
+        let (a, b) = (latlng::to_vec3(start), latlng::to_vec3(end));
+        let ll = latlng::from_vec3([a[0] + b[0], a[1] + b[1], a[2] + b[2]])?;
+        Ok(LatLng {
+            lat: ll.lat(),
+            lng: ll.lng(),
+        })
+    }
+
+    delegate_inner!(inner(edge), out)
+}
+
 /// Provides the coordinates defining the directed edge.
 ///
 /// @param edge The directed edge H3Index
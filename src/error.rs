@@ -1,3 +1,5 @@
+use std::ffi::c_int;
+
 /// Result code (success or specific error) from an H3 operation.
 #[repr(transparent)]
 #[derive(Debug, Copy, Clone)]
@@ -141,3 +143,63 @@ pub enum H3ErrorCodes {
     // Mode or flags argument was not valid.
     EOptionInvalid = 15,
 }
+
+/// Returns the numeric code of an H3 error.
+///
+/// @param err The H3 error.
+/// @return    The numeric code of the error, 0 (E_SUCCESS) on success.
+#[no_mangle]
+pub const extern "C" fn h3ErrorCode(err: H3Error) -> u32 {
+    err.0
+}
+
+/// Returns whether an H3 error denotes a success.
+///
+/// @param err The H3 error.
+/// @return    1 if the error is E_SUCCESS, 0 otherwise.
+#[no_mangle]
+pub extern "C" fn h3ErrorIsSuccess(err: H3Error) -> c_int {
+    (err.0 == H3ErrorCodes::ESuccess as u32).into()
+}
+
+/// Maps an H3 error to a process exit code, for CLI wrappers.
+///
+/// Errors are grouped in small, stable classes:
+/// - 0: success (E_SUCCESS)
+/// - 1: generic or unknown failure (E_FAILED)
+/// - 2: argument out of range (E_DOMAIN, E_LATLNG_DOMAIN, E_RES_DOMAIN,
+///   E_OPTION_INVALID)
+/// - 3: invalid index (E_CELL_INVALID, E_DIR_EDGE_INVALID,
+///   E_UNDIR_EDGE_INVALID, E_VERTEX_INVALID)
+/// - 4: memory (E_MEMORY_ALLOC, E_MEMORY_BOUNDS)
+/// - 5: unsupported input (E_PENTAGON, E_DUPLICATE_INPUT, E_NOT_NEIGHBORS,
+///   E_RES_MISMATCH)
+///
+/// @param err The H3 error.
+/// @return    The exit code of the error class.
+#[no_mangle]
+pub extern "C" fn h3ErrorToExitCode(err: H3Error) -> c_int {
+    const CLASSES: [(H3ErrorCodes, c_int); 16] = [
+        (H3ErrorCodes::ESuccess, 0),
+        (H3ErrorCodes::EFailed, 1),
+        (H3ErrorCodes::EDomain, 2),
+        (H3ErrorCodes::ELatlngDomain, 2),
+        (H3ErrorCodes::EResDomain, 2),
+        (H3ErrorCodes::EOptionInvalid, 2),
+        (H3ErrorCodes::ECellInvalid, 3),
+        (H3ErrorCodes::EDirEdgeInvalid, 3),
+        (H3ErrorCodes::EUndirEdgeInvalid, 3),
+        (H3ErrorCodes::EVertexInvalid, 3),
+        (H3ErrorCodes::EMemoryAlloc, 4),
+        (H3ErrorCodes::EMemoryBounds, 4),
+        (H3ErrorCodes::EPentagon, 5),
+        (H3ErrorCodes::EDuplicateInput, 5),
+        (H3ErrorCodes::ENotNeighbors, 5),
+        (H3ErrorCodes::EResMismatch, 5),
+    ];
+
+    CLASSES
+        .iter()
+        .find(|&&(code, _)| code as u32 == err.0)
+        .map_or(1, |&(_, exit_code)| exit_code)
+}
@@ -1,7 +1,18 @@
-use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index, LatLng};
-use geo_types::{Coord, LineString, MultiPolygon, Polygon};
-use h3o::geom::{Polygon as h3oPolygon, ToCells, ToGeo};
-use std::{ffi::c_int, ptr};
+use crate::{
+    convert, delegate_inner, latlng, polyfill, H3Error, H3ErrorCodes, H3Index,
+    LatLng,
+};
+use geo_types::{Coord, Line, LineString, MultiPolygon, Polygon};
+use h3o::{
+    geom::{Polygon as h3oPolygon, ToCells, ToGeo},
+    CellIndex, Resolution,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    f64::consts::{PI, TAU},
+    ffi::{c_int, c_void},
+    ptr,
+};
 
 /// Create a LinkedGeoPolygon describing the outline(s) of a set of  hexagons.
 /// Polygon outlines will follow GeoJSON MultiPolygon order: Each polygon will
@@ -74,15 +85,7 @@ pub unsafe extern "C" fn destroyLinkedMultiPolygon(
             let mut curr_ring = curr_polygon.first;
             while !curr_ring.is_null() {
                 let next_ring = (*curr_ring).next;
-
-                // Free the coordinates.
-                let mut curr_coord = (*curr_ring).first;
-                while !curr_coord.is_null() {
-                    let next_coord = (*curr_coord).next;
-                    drop(Box::from_raw(curr_coord));
-                    curr_coord = next_coord;
-                }
-
+                destroyLinkedGeoLoop(curr_ring.as_mut());
                 drop(Box::from_raw(curr_ring));
                 curr_ring = next_ring;
             }
@@ -96,77 +99,1379 @@ pub unsafe extern "C" fn destroyLinkedMultiPolygon(
     }
 }
 
-/// maxPolygonToCellsSize returns the number of cells to allocate space for
-/// when performing a polygonToCells on the given GeoJSON-like data structure.
-///
-/// The size is the maximum of either the number of points in the geoloop or the
-/// number of cells in the bounding box of the geoloop.
+/// Count the polygons, loops and vertices of a linked geo structure.
+///
+/// Useful to size the arrays before flattening the structure. A null or empty
+/// structure contains nothing.
+///
+/// @param polygon     Pointer to the first polygon in the structure
+/// @param numPolygons Number of polygons (optional)
+/// @param numLoops    Total number of loops, holes included (optional)
+/// @param numVerts    Total number of vertices (optional)
+///
+/// # Safety
+///
+/// The pointer must comes from [`cellsToLinkedMultiPolygon`]
+#[no_mangle]
+pub unsafe extern "C" fn linkedMultiPolygonCounts(
+    polygon: Option<&LinkedGeoPolygon>,
+    numPolygons: Option<&mut c_int>,
+    numLoops: Option<&mut c_int>,
+    numVerts: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        polygon: Option<&LinkedGeoPolygon>,
+    ) -> Result<(c_int, c_int, c_int), H3Error> {
+        let (polygons, loops, verts) = linked_counts(polygon);
+
+        let to_c_int =
+            |count| c_int::try_from(count).map_err(|_| H3ErrorCodes::EFailed);
+        Ok((to_c_int(polygons)?, to_c_int(loops)?, to_c_int(verts)?))
+    }
+
+    match inner(polygon) {
+        Ok((polygons, loops, verts)) => {
+            if let Some(numPolygons) = numPolygons {
+                *numPolygons = polygons;
+            }
+            if let Some(numLoops) = numLoops {
+                *numLoops = loops;
+            }
+            if let Some(numVerts) = numVerts {
+                *numVerts = verts;
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// Serialize a linked geo structure into flat arrays.
+///
+/// Vertices are written as `lat, lng` pairs (in degrees) into `coords`, each
+/// ring (outer loop first, followed by holes) being described by its number of
+/// vertices in `ringLens` and each polygon by its number of rings in
+/// `polyRingCounts`.
+///
+/// Use linkedMultiPolygonCounts to size the arrays: `coords` needs two slots
+/// per vertex.
+///
+/// @param polygon        Pointer to the first polygon in the structure
+/// @param coords         Output array for the coordinates
+/// @param coordsCap      Size of `coords`
+/// @param ringLens       Output array for the number of vertices per ring
+/// @param ringsCap       Size of `ringLens`
+/// @param polyRingCounts Output array for the number of rings per polygon
+/// @param polysCap       Size of `polyRingCounts`
+/// @return E_MEMORY_BOUNDS if an array is too small.
+///
+/// # Safety
+///
+/// The pointer must comes from [`cellsToLinkedMultiPolygon`], and each output
+/// array must points to at least as many elements as its capacity.
+#[no_mangle]
+pub unsafe extern "C" fn linkedMultiPolygonToFlat(
+    polygon: Option<&LinkedGeoPolygon>,
+    coords: *mut f64,
+    coordsCap: c_int,
+    ringLens: *mut c_int,
+    ringsCap: c_int,
+    polyRingCounts: *mut c_int,
+    polysCap: c_int,
+) -> H3Error {
+    unsafe fn inner(
+        polygon: Option<&LinkedGeoPolygon>,
+        coords: *mut f64,
+        coordsCap: c_int,
+        ringLens: *mut c_int,
+        ringsCap: c_int,
+        polyRingCounts: *mut c_int,
+        polysCap: c_int,
+    ) -> Result<(), H3Error> {
+        let (polygons, loops, verts) = linked_counts(polygon);
+        let fits = |count, cap| {
+            usize::try_from(cap).map_or(count == 0, |cap| count <= cap)
+        };
+        if !(fits(2 * verts, coordsCap)
+            && fits(loops, ringsCap)
+            && fits(polygons, polysCap))
+        {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        // Nothing to write for an empty structure.
+        if polygons == 0 {
+            return Ok(());
+        }
+
+        let coords = std::slice::from_raw_parts_mut(coords, 2 * verts);
+        let ringLens = std::slice::from_raw_parts_mut(ringLens, loops);
+        let polyRingCounts =
+            std::slice::from_raw_parts_mut(polyRingCounts, polygons);
+
+        let (mut coord_idx, mut ring_idx) = (0, 0);
+        for (i, polygon) in linked_polygons(polygon).enumerate() {
+            let ring_start = ring_idx;
+            for geoloop in polygon.loops() {
+                let vert_start = coord_idx;
+                for coord in geoloop.coords() {
+                    coords[coord_idx] = coord.vertex.lat.to_degrees();
+                    coords[coord_idx + 1] = coord.vertex.lng.to_degrees();
+                    coord_idx += 2;
+                }
+                ringLens[ring_idx] =
+                    c_int::try_from((coord_idx - vert_start) / 2)
+                        .expect("bounded by coordsCap");
+                ring_idx += 1;
+            }
+            polyRingCounts[i] = c_int::try_from(ring_idx - ring_start)
+                .expect("bounded by ringsCap");
+        }
+
+        Ok(())
+    }
+
+    inner(
+        polygon,
+        coords,
+        coordsCap,
+        ringLens,
+        ringsCap,
+        polyRingCounts,
+        polysCap,
+    )
+    .err()
+    .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Create a LinkedGeoLoop describing the boundary of a single cell, in
+/// degrees.
+///
+/// It is the responsibility of the caller to call destroyLinkedGeoLoop on the
+/// populated linked geo structure, or the memory for that structure will not
+/// be freed.
+///
+/// @param h   The H3 cell
+/// @param out Output loop
+#[no_mangle]
+pub extern "C" fn cellToLinkedGeoLoop(
+    h: H3Index,
+    out: Option<&mut LinkedGeoLoop>,
+) -> H3Error {
+    fn inner(h: H3Index) -> Result<LinkedGeoLoop, H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let (exterior, _) =
+            index.to_geom(true).expect("infallible").into_inner();
+        Ok(exterior.into())
+    }
+
+    delegate_inner!(inner(h), out)
+}
+
+/// Writes the boundary vertices of a single cell, in degrees, into a caller
+/// provided buffer, e.g. to build a GeoLoop.
+///
+/// The loop isn't closed (the first vertex isn't repeated at the end). Note
+/// that polygonToCells expects radians: convert the vertices with degsToRads
+/// before filling a GeoPolygon built from them.
+///
+/// @param h        The H3 cell
+/// @param verts    Output vertices, in degrees
+/// @param cap      Capacity of `verts`
+/// @param numVerts Set to the number of vertices of the boundary, even when
+///                 `cap` is too small (optional)
+/// @return         E_MEMORY_BOUNDS if `cap` is too small.
+///
+/// # Safety
+///
+/// `verts` must points to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToGeoLoop(
+    h: H3Index,
+    verts: *mut LatLng,
+    cap: c_int,
+    numVerts: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        verts: *mut LatLng,
+        cap: c_int,
+        numVerts: Option<&mut c_int>,
+    ) -> Result<(), H3Error> {
+        let index = CellIndex::try_from(h)?;
+        let boundary = index.boundary();
+        let len = boundary.len();
+        if let Some(numVerts) = numVerts {
+            *numVerts = convert::checked_int(len)?;
+        }
+        if usize::try_from(cap).map_or(true, |cap| cap < len) {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let verts = std::slice::from_raw_parts_mut(verts, len);
+        for (vert, ll) in verts.iter_mut().zip(boundary.iter()) {
+            *vert = LatLng {
+                lat: ll.lat(),
+                lng: ll.lng(),
+            };
+        }
+        Ok(())
+    }
+
+    inner(h, verts, cap, numVerts)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Free all allocated memory for the vertices of a linked geo loop. The caller
+/// is responsible for freeing memory allocated to the input loop struct.
+///
+/// @param geoLoop Pointer to the loop
+///
+/// # Safety
+///
+/// The pointer must comes from [`cellToLinkedGeoLoop`] or be a loop of a
+/// polygon from [`cellsToLinkedMultiPolygon`].
+#[no_mangle]
+pub unsafe extern "C" fn destroyLinkedGeoLoop(
+    geoLoop: Option<&mut LinkedGeoLoop>,
+) {
+    if let Some(geoLoop) = geoLoop {
+        let mut curr_coord = geoLoop.first;
+        while !curr_coord.is_null() {
+            let next_coord = (*curr_coord).next;
+            drop(Box::from_raw(curr_coord));
+            curr_coord = next_coord;
+        }
+        geoLoop.first = ptr::null_mut();
+        geoLoop.last = ptr::null_mut();
+    }
+}
+
+/// maxPolygonToCellsSize returns the number of cells to allocate space for
+/// when performing a polygonToCells on the given GeoJSON-like data structure.
+///
+/// The size is the maximum of either the number of points in the geoloop or the
+/// number of cells in the bounding box of the geoloop.
+///
+/// @param geoPolygon A GeoJSON-like data structure indicating the poly to fill
+/// @param res Hexagon resolution (0-15)
+/// @param out number of cells to allocate for
+/// @return 0 (E_SUCCESS) on success.
+#[no_mangle]
+pub extern "C" fn maxPolygonToCellsSize(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    flags: u32,
+    out: Option<&mut i64>,
+) -> H3Error {
+    fn inner(
+        geoPolygon: &GeoPolygon,
+        res: c_int,
+        flags: u32,
+    ) -> Result<i64, H3Error> {
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(0);
+        }
+
+        let resolution = convert::h3res_to_resolution(res)?;
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let polygon = h3oPolygon::from_radians(&polygon)?;
+
+        Ok(convert::size_estimate(polygon.max_cells_count(resolution))?)
+    }
+
+    geoPolygon.map_or_else(
+        || H3ErrorCodes::EFailed.into(),
+        |geoPolygon| delegate_inner!(inner(geoPolygon, res, flags), out),
+    )
+}
+
+/// polygonToCells takes a given GeoJSON-like data structure and preallocated,
+/// zeroed memory, and fills it with the hexagons that are contained by
+/// the GeoJSON-like data structure.
+///
+/// This implementation traces the GeoJSON geoloop(s) in cartesian space with
+/// hexagons, tests them and their neighbors to be contained by the geoloop(s),
+/// and then any newly found hexagons are used to test again until no new
+/// hexagons are found.
+///
+/// A cell is included when its center is inside the polygon or lies on its
+/// boundary (holes included), so that cells whose center sits on an edge don't
+/// depend on float rounding in the point-in-polygon test. A center is on the
+/// boundary when it is within 1e-12 radians of an edge, on the planar lat/lng
+/// projection. The same rule applies to every polygon fill (bboxToCells,
+/// geoJsonToCells, ...).
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param res The Hexagon resolution (0-15)
+/// @param out The slab of zeroed memory to write to. Assumed to be big enough.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxPolygonToCellsSize` elements.
+#[no_mangle]
+pub unsafe extern "C" fn polygonToCells(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    flags: u32,
+    out: *mut H3Index,
+) -> H3Error {
+    unsafe fn inner(
+        geoPolygon: &GeoPolygon,
+        res: c_int,
+        flags: u32,
+        out: *mut H3Index,
+    ) -> Result<(), H3Error> {
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        let resolution = convert::h3res_to_resolution(res)?;
+
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(());
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let len =
+            h3oPolygon::from_radians(&polygon)?.max_cells_count(resolution);
+        let cells = polyfill::polygon_cells(&polygon, resolution);
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (i, cell_index) in cells.enumerate() {
+            *out.get_mut(i).ok_or(H3ErrorCodes::EMemoryBounds)? =
+                cell_index.into();
+        }
+        Ok(())
+    }
+
+    geoPolygon.map_or_else(
+        || H3ErrorCodes::EFailed.into(),
+        |geoPolygon| {
+            inner(geoPolygon, res, flags, out)
+                .err()
+                .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+        },
+    )
+}
+
+/// Counts the cells polygonToCells would produce, without storing them.
+///
+/// The fill is streamed and only counted, which is cheaper than allocating a
+/// `maxPolygonToCellsSize` buffer when only the exact count matters.
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param res        The Hexagon resolution (0-15)
+/// @param flags      Reserved, must be 0
+/// @param out        Set to the exact number of cells
+#[no_mangle]
+pub extern "C" fn polygonToCellsCount(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    flags: u32,
+    out: Option<&mut i64>,
+) -> H3Error {
+    fn inner(
+        geoPolygon: Option<&GeoPolygon>,
+        res: c_int,
+        flags: u32,
+    ) -> Result<i64, H3Error> {
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        let resolution = convert::h3res_to_resolution(res)?;
+
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(0);
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let count = cells_count(&polygon, resolution, usize::MAX)?;
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(geoPolygon, res, flags), out)
+}
+
+/// Finds the finest resolution at which polygonToCells yields at most
+/// `maxCells` cells.
+///
+/// Resolutions are tried from the coarsest, counting the cells as
+/// polygonToCellsCount does, until one exceeds the budget: the count stops at
+/// `maxCells + 1`, so large polygons aren't fully enumerated.
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param maxCells   Maximum number of cells, must be > 0
+/// @param out        Set to the resolution
+/// @return           E_DOMAIN if `maxCells` isn't positive, or if the polygon
+///                   spans more than `maxCells` cells even at resolution 0.
+#[no_mangle]
+pub extern "C" fn resolutionForCellBudget(
+    geoPolygon: Option<&GeoPolygon>,
+    maxCells: i64,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(
+        geoPolygon: Option<&GeoPolygon>,
+        maxCells: i64,
+    ) -> Result<c_int, H3Error> {
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+        let max_cells = usize::try_from(maxCells)
+            .ok()
+            .filter(|&max_cells| max_cells > 0)
+            .ok_or(H3ErrorCodes::EDomain)?;
+
+        // Empty polygon contains no cell, at any resolution.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(u8::from(Resolution::Fifteen).into());
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let mut found = None;
+        for resolution in
+            Resolution::range(Resolution::Zero, Resolution::Fifteen)
+        {
+            // No need to enumerate the cells past the budget.
+            let limit = max_cells.saturating_add(1);
+            if cells_count(&polygon, resolution, limit)? > max_cells {
+                break;
+            }
+            found = Some(resolution);
+        }
+
+        found
+            .map(|resolution| u8::from(resolution).into())
+            .ok_or_else(|| H3ErrorCodes::EDomain.into())
+    }
+
+    delegate_inner!(inner(geoPolygon, maxCells), out)
+}
+
+/// Same as polygonToCells, but guarantees that the union of the returned cells
+/// fully covers the polygon.
+///
+/// The cells whose center is contained by the polygon are completed with every
+/// cell overlapping the polygon boundary, so thin slivers of the polygon that
+/// don't contain any cell center are still covered. Boundary cells are found on
+/// the planar lat/lng projection, by tracing the polygon edges across the grid.
+///
+/// Cells are written contiguously at the start of `out`, the cells contained
+/// by the polygon first.
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param res        The Hexagon resolution (0-15)
+/// @param flags      Reserved, must be 0
+/// @param out        Output array for the covering cells
+/// @param cap        Capacity of `out`
+/// @param count      Set to the number of cells written in `out`
+/// @return           E_MEMORY_BOUNDS if `out` is too small.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn polygonToCellsCovering(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    flags: u32,
+    out: *mut H3Index,
+    cap: i64,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        geoPolygon: Option<&GeoPolygon>,
+        res: c_int,
+        flags: u32,
+        out: *mut H3Index,
+        cap: i64,
+    ) -> Result<i64, H3Error> {
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        let resolution = convert::h3res_to_resolution(res)?;
+        let cap = usize::try_from(cap).map_err(|_| H3ErrorCodes::EDomain)?;
+
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(0);
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let cells = covering_cells(&polygon, resolution)?;
+
+        if cells.len() > cap {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        if cells.is_empty() {
+            return Ok(0);
+        }
+        let out = std::slice::from_raw_parts_mut(out, cells.len());
+        for (slot, cell) in out.iter_mut().zip(&cells) {
+            *slot = (*cell).into();
+        }
+        Ok(convert::checked_int(cells.len())?)
+    }
+
+    delegate_inner!(inner(geoPolygon, res, flags, out, cap), count)
+}
+
+/// Computes the fraction of the area of a cell that lies inside a polygon.
+///
+/// The cell boundary is intersected with the polygon (holes excluded) on the
+/// planar lat/lng projection, which is a good approximation at the scale of a
+/// cell. Cells far from the polygon, or not crossed by its boundary, are
+/// classified without computing the intersection.
+///
+/// @param cell       The H3 cell
+/// @param geoPolygon The geoloop and holes defining the polygon
+/// @param out        Set to the overlap fraction, between 0 and 1
+#[no_mangle]
+pub extern "C" fn cellPolygonOverlapFraction(
+    cell: H3Index,
+    geoPolygon: Option<&GeoPolygon>,
+    out: Option<&mut f64>,
+) -> H3Error {
+    fn inner(
+        cell: H3Index,
+        geoPolygon: Option<&GeoPolygon>,
+    ) -> Result<f64, H3Error> {
+        let index = CellIndex::try_from(cell)?;
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+
+        // Empty polygon covers nothing.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(0.);
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        Ok(overlap_fraction(index, &polygon))
+    }
+
+    delegate_inner!(inner(cell, geoPolygon), out)
+}
+
+/// Same as polygonToCellsCovering, but only keeps the cells whose overlap with
+/// the polygon is at least `minOverlap`.
+///
+/// Overlaps are computed as in cellPolygonOverlapFraction. A `minOverlap` of 0
+/// keeps every covering cell, while 1 only keeps the fully covered ones.
+///
+/// Cells are written contiguously at the start of `out`.
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param res        The Hexagon resolution (0-15)
+/// @param minOverlap Minimum fraction of a cell's area inside the polygon
+/// @param out        Output array for the cells
+/// @param count      Set to the number of cells written in `out`
+/// @return           E_DOMAIN if `minOverlap` isn't in [0, 1], E_MEMORY_BOUNDS
+///                   if the selected cells don't fit in `maxPolygonToCellsSize`
+///                   elements (nothing is written then).
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxPolygonToCellsSize` elements.
+#[no_mangle]
+pub unsafe extern "C" fn polygonToCellsThreshold(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    minOverlap: f64,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        geoPolygon: Option<&GeoPolygon>,
+        res: c_int,
+        minOverlap: f64,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+        let resolution = convert::h3res_to_resolution(res)?;
+        if !(0. ..=1.).contains(&minOverlap) {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(0);
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let h3o_polygon = h3oPolygon::from_radians(&polygon)?;
+        let len = h3o_polygon.max_cells_count(resolution);
+
+        // Cells untouched by the boundary are fully covered, only the ones it
+        // crosses need to be clipped.
+        let edges = edge_cells(&polygon_lines(&polygon), resolution)?;
+        let mut partial = edges
+            .iter()
+            .copied()
+            .filter(|cell| overlap_fraction(*cell, &polygon) >= minOverlap)
+            .collect::<Vec<_>>();
+        partial.sort_unstable();
+        let cells = polyfill::polygon_cells(&polygon, resolution)
+            .filter(|cell| !edges.contains(cell))
+            .chain(partial)
+            .collect::<Vec<_>>();
+        if cells.len() > len {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (slot, cell_index) in out.iter_mut().zip(&cells) {
+            *slot = (*cell_index).into();
+        }
+        Ok(convert::checked_int(cells.len())?)
+    }
+
+    delegate_inner!(inner(geoPolygon, res, minOverlap, out), count)
+}
+
+/// Same as polygonToCells, but streams the cells to a callback instead of
+/// writing them into an array.
+///
+/// The callback is invoked once per cell, with the user-provided context. If it
+/// returns a nonzero value, the fill is cancelled and E_FAILED is returned.
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param res        The Hexagon resolution (0-15)
+/// @param flags      Reserved, must be 0
+/// @param cb         Callback invoked for each cell
+/// @param ctx        User context, passed as is to the callback
+///
+/// # Safety
+///
+/// `cb` must be safe to call with `ctx`.
+#[no_mangle]
+pub unsafe extern "C" fn polygonToCellsProgress(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    flags: u32,
+    cb: Option<unsafe extern "C" fn(H3Index, *mut c_void) -> c_int>,
+    ctx: *mut c_void,
+) -> H3Error {
+    unsafe fn inner(
+        geoPolygon: Option<&GeoPolygon>,
+        res: c_int,
+        flags: u32,
+        cb: Option<unsafe extern "C" fn(H3Index, *mut c_void) -> c_int>,
+        ctx: *mut c_void,
+    ) -> Result<(), H3Error> {
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+        let cb = cb.ok_or(H3ErrorCodes::EFailed)?;
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        let resolution = convert::h3res_to_resolution(res)?;
+
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(());
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        // Validates the polygon.
+        h3oPolygon::from_radians(&polygon)?;
+        for cell_index in polyfill::polygon_cells(&polygon, resolution) {
+            if cb(cell_index.into(), ctx) != 0 {
+                return Err(H3ErrorCodes::EFailed.into());
+            }
+        }
+        Ok(())
+    }
+
+    inner(geoPolygon, res, flags, cb, ctx)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Creates a polygon to cells configuration, reusable across many polygons.
+///
+/// Resolution and flags are validated once, here, instead of on every call.
+///
+/// It is the responsibility of the caller to call polyfillConfigFree on the
+/// configuration, or its memory will not be freed.
+///
+/// @param res   The Hexagon resolution (0-15)
+/// @param flags Reserved, must be 0
+/// @param out   Set to the newly allocated configuration
+#[no_mangle]
+pub extern "C" fn polyfillConfigNew(
+    res: c_int,
+    flags: u32,
+    out: Option<&mut *mut H3PolyfillConfig>,
+) -> H3Error {
+    fn inner(res: c_int, flags: u32) -> Result<*mut H3PolyfillConfig, H3Error> {
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        let resolution = convert::h3res_to_resolution(res)?;
+
+        Ok(Box::into_raw(Box::new(H3PolyfillConfig { resolution })))
+    }
+
+    delegate_inner!(inner(res, flags), out)
+}
+
+/// Frees a configuration created by polyfillConfigNew.
+///
+/// # Safety
+///
+/// `cfg` must be null or a configuration returned by polyfillConfigNew that
+/// hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn polyfillConfigFree(cfg: *mut H3PolyfillConfig) {
+    if !cfg.is_null() {
+        drop(Box::from_raw(cfg));
+    }
+}
+
+/// Same as polygonToCells, using a configuration created by polyfillConfigNew.
+///
+/// Cells are written contiguously at the start of `out`.
+///
+/// @param cfg        The polygon to cells configuration
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param out        The slab of memory to write to. Assumed to be big enough.
+/// @param count      Set to the number of cells written (optional)
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxPolygonToCellsSize` elements.
+#[no_mangle]
+pub unsafe extern "C" fn polygonToCellsWithConfig(
+    cfg: Option<&H3PolyfillConfig>,
+    geoPolygon: Option<&GeoPolygon>,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cfg: Option<&H3PolyfillConfig>,
+        geoPolygon: Option<&GeoPolygon>,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let cfg = cfg.ok_or(H3ErrorCodes::EFailed)?;
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+
+        // Empty polygon contains no cell.
+        if geoPolygon.geoloop.numVerts == 0 {
+            return Ok(0);
+        }
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let len =
+            h3oPolygon::from_radians(&polygon)?.max_cells_count(cfg.resolution);
+        let cells = polyfill::polygon_cells(&polygon, cfg.resolution);
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut count = 0;
+        for cell_index in cells {
+            *out.get_mut(count).ok_or(H3ErrorCodes::EMemoryBounds)? =
+                cell_index.into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    match inner(cfg, geoPolygon, out) {
+        Ok(value) => {
+            if let Some(count) = count {
+                *count = value;
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// Normalizes a GeoPolygon in place, before polyfilling.
+///
+/// Duplicate closing vertices (last vertex equal to the first one) are removed
+/// and the loops are rewound so that the exterior is counter-clockwise and the
+/// holes are clockwise. Winding is computed on the planar lat/lng projection,
+/// with longitudes unwrapped to handle loops crossing the antimeridian.
+///
+/// Loops are modified in place: only `numVerts` can shrink, no memory is
+/// reallocated.
+///
+/// @param geoPolygon The geoloop and holes to normalize
+/// @return           E_FAILED if a loop is degenerate (less than 3 distinct
+///                   vertices, zero area or non-finite coordinates).
+///
+/// # Safety
+///
+/// The loops of `geoPolygon` must points to arrays of at least `numVerts`
+/// elements.
+#[no_mangle]
+pub unsafe extern "C" fn normalizeGeoPolygon(
+    geoPolygon: Option<&mut GeoPolygon>,
+) -> H3Error {
+    unsafe fn inner(
+        geoPolygon: Option<&mut GeoPolygon>,
+    ) -> Result<(), H3Error> {
+        let geoPolygon = geoPolygon.ok_or(H3ErrorCodes::EFailed)?;
+        let len = usize::try_from(geoPolygon.numHoles)
+            .map_err(|_| H3ErrorCodes::EFailed)?;
+
+        // Empty polygon is left as is.
+        if geoPolygon.geoloop.numVerts != 0 {
+            normalize_geoloop(&mut geoPolygon.geoloop, true)?;
+        }
+        if len != 0 {
+            let holes = std::slice::from_raw_parts_mut(geoPolygon.holes, len);
+            for hole in holes {
+                normalize_geoloop(hole, false)?;
+            }
+        }
+        Ok(())
+    }
+
+    inner(geoPolygon)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Removes the closing vertices of a loop and enforces its winding order.
+unsafe fn normalize_geoloop(
+    geoloop: &mut GeoLoop,
+    ccw: bool,
+) -> Result<(), H3Error> {
+    let mut len =
+        usize::try_from(geoloop.numVerts).map_err(|_| H3ErrorCodes::EFailed)?;
+    if len < 3 || geoloop.verts.is_null() {
+        return Err(H3ErrorCodes::EFailed.into());
+    }
+
+    let verts = std::slice::from_raw_parts_mut(geoloop.verts, len);
+    if verts
+        .iter()
+        .any(|ll| !(ll.lat.is_finite() && ll.lng.is_finite()))
+    {
+        return Err(H3ErrorCodes::EFailed.into());
+    }
+    while len > 1
+        && (verts[len - 1].lat - verts[0].lat).abs() < f64::EPSILON
+        && (verts[len - 1].lng - verts[0].lng).abs() < f64::EPSILON
+    {
+        len -= 1;
+    }
+    if len < 3 {
+        return Err(H3ErrorCodes::EFailed.into());
+    }
+
+    let verts = &mut verts[..len];
+    let area = signed_area(verts);
+    if area.abs() < f64::EPSILON {
+        return Err(H3ErrorCodes::EFailed.into());
+    }
+    if (area > 0.) != ccw {
+        verts.reverse();
+    }
+    geoloop.numVerts = convert::checked_int(len)?;
+    Ok(())
+}
+
+/// Shoelace formula on the lat/lng plane, positive for counter-clockwise loops.
+///
+/// Longitudes are unwrapped (consecutive deltas kept within ±π) so that loops
+/// crossing the antimeridian get a meaningful area.
+fn signed_area(verts: &[LatLng]) -> f64 {
+    let mut lng = verts[0].lng;
+    let mut unwrapped = Vec::with_capacity(verts.len());
+    unwrapped.push((lng, verts[0].lat));
+    for pair in verts.windows(2) {
+        let mut delta = pair[1].lng - pair[0].lng;
+        if delta > PI {
+            delta -= TAU;
+        } else if delta < -PI {
+            delta += TAU;
+        }
+        lng += delta;
+        unwrapped.push((lng, pair[1].lat));
+    }
+
+    let coords = unwrapped.iter().copied();
+    coords
+        .clone()
+        .zip(coords.cycle().skip(1))
+        .map(|((x1, y1), (x2, y2))| x1.mul_add(y2, -(x2 * y1)))
+        .sum::<f64>()
+        / 2.
+}
+
+/// Computes the area-weighted centroid of a set of cells, in degrees.
+///
+/// Cell centers are averaged as 3D unit vectors (weighted by the cell area in
+/// m²), which handles sets crossing the antimeridian. Invalid cells are
+/// skipped.
+///
+/// @param cells      Set of cells
+/// @param numCells   The number of cells in the input set
+/// @param out        Centroid of the set, in degrees
+/// @param numSkipped Number of invalid cells that were skipped (optional)
+/// @return           E_DOMAIN if the set has no valid cell (or a negative
+///                   size), E_FAILED if the centroid is undefined (e.g.
+///                   antipodal cells).
+///
+/// # Safety
+///
+/// `cells` must points to an array of at least `numCells` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellsCentroid(
+    cells: *const H3Index,
+    numCells: i64,
+    out: Option<&mut LatLng>,
+    numSkipped: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        numCells: i64,
+        numSkipped: Option<&mut i64>,
+    ) -> Result<LatLng, H3Error> {
+        let len =
+            usize::try_from(numCells).map_err(|_| H3ErrorCodes::EDomain)?;
+        let cells = if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(cells, len)
+        };
+
+        let mut skipped = 0;
+        let mut sum = [0.; 3];
+        for index in cells.iter().filter_map(|&cell| {
+            let index = CellIndex::try_from(cell).ok();
+            skipped += i64::from(index.is_none());
+            index
+        }) {
+            let weight = index.area_m2();
+            let center = latlng::to_vec3(h3o::LatLng::from(index));
+            for (total, coord) in sum.iter_mut().zip(center) {
+                *total = weight.mul_add(coord, *total);
+            }
+        }
+        if let Some(numSkipped) = numSkipped {
+            *numSkipped = skipped;
+        }
+
+        if i64::try_from(len).map_or(true, |len| len == skipped) {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        let [x, y, z] = sum;
+        if x.hypot(y).hypot(z) < f64::EPSILON {
+            return Err(H3ErrorCodes::EFailed.into());
+        }
+        let ll = latlng::from_vec3(sum)?;
+        Ok(LatLng {
+            lat: ll.lat(),
+            lng: ll.lng(),
+        })
+    }
+
+    delegate_inner!(inner(cells, numCells, numSkipped), out)
+}
+
+/// Computes the perimeter of the outline of a set of cells, in meters.
+///
+/// The set is outlined first (like cellsToLinkedMultiPolygon), then the
+/// great-circle lengths of every ring, exterior and holes, are summed up.
+///
+/// @param cells Set of cells
+/// @param n     The number of cells in the input set
+/// @param out   Perimeter of the outline, in meters (0 for an empty set)
+///
+/// # Safety
+///
+/// `cells` must points to an array of at least `n` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellsOutlinePerimeterM(
+    cells: *const H3Index,
+    n: c_int,
+    out: Option<&mut f64>,
+) -> H3Error {
+    unsafe fn inner(cells: *const H3Index, n: c_int) -> Result<f64, H3Error> {
+        if n == 0 {
+            return Ok(0.);
+        }
+        let indexes = convert::h3ptr_to_h3oslice(cells, n.into())?;
+        let outline = indexes.iter().copied().to_geom(false)?;
+
+        let mut perimeter = 0.;
+        for polygon in &outline {
+            let rings =
+                std::iter::once(polygon.exterior()).chain(polygon.interiors());
+            for ring in rings {
+                for line in ring.lines() {
+                    let start =
+                        h3o::LatLng::from_radians(line.start.y, line.start.x)?;
+                    let end =
+                        h3o::LatLng::from_radians(line.end.y, line.end.x)?;
+                    perimeter += start.distance_m(end);
+                }
+            }
+        }
+        Ok(perimeter)
+    }
+
+    delegate_inner!(inner(cells, n), out)
+}
+
+/// Builds an indexed mesh from the boundaries of a set of cells.
+///
+/// The vertices shared by adjacent cells are only emitted once (in degrees),
+/// and each cell polygon is described in the index buffer by its number of
+/// vertices followed by the position of each of its vertices in the vertex
+/// buffer, in boundary order.
+///
+/// Polygons are built from the cell topological vertices: the extra
+/// distortion vertices of `cellToBoundary` are not included.
+///
+/// @param cells     Set of cells
+/// @param n         The number of cells in the input set
+/// @param vertices  Output array for the deduplicated vertices
+/// @param vertCap   Capacity of `vertices`
+/// @param vertCount Set to the number of vertices written in `vertices`
+/// @param indices   Output array for the cell polygons
+/// @param idxCap    Capacity of `indices`
+/// @param idxCount  Set to the number of values written in `indices`
+/// @return          E_MEMORY_BOUNDS if an output array is too small.
+///
+/// # Safety
+///
+/// - `cells` must points to an array of at least `n` elements.
+/// - `vertices` must points to an array of at least `vertCap` elements.
+/// - `indices` must points to an array of at least `idxCap` elements.
+#[no_mangle]
+#[allow(
+    clippy::too_many_arguments,
+    reason = "separate capacity and count for both output buffers"
+)]
+pub unsafe extern "C" fn cellsToMesh(
+    cells: *const H3Index,
+    n: i64,
+    vertices: *mut LatLng,
+    vertCap: c_int,
+    vertCount: Option<&mut c_int>,
+    indices: *mut c_int,
+    idxCap: c_int,
+    idxCount: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        vertices: *mut LatLng,
+        vertCap: c_int,
+        indices: *mut c_int,
+        idxCap: c_int,
+    ) -> Result<(c_int, c_int), H3Error> {
+        let len = usize::try_from(n).map_err(|_| H3ErrorCodes::EDomain)?;
+        let vert_cap =
+            usize::try_from(vertCap).map_err(|_| H3ErrorCodes::EDomain)?;
+        let idx_cap =
+            usize::try_from(idxCap).map_err(|_| H3ErrorCodes::EDomain)?;
+        if len == 0 {
+            return Ok((0, 0));
+        }
+
+        let cells = convert::h3ptr_to_h3oslice(cells, n)?;
+        let mut positions = HashMap::new();
+        let mut mesh_vertices = Vec::new();
+        let mut mesh_indices = Vec::new();
+        for cell in cells {
+            let count = if cell.is_pentagon() { 5 } else { 6 };
+            mesh_indices.push(count);
+            for vertex in cell.vertexes() {
+                let position = *positions.entry(vertex).or_insert_with(|| {
+                    mesh_vertices.push(h3o::LatLng::from(vertex));
+                    mesh_vertices.len() - 1
+                });
+                mesh_indices.push(convert::checked_int(position)?);
+            }
+        }
+        if mesh_vertices.len() > vert_cap || mesh_indices.len() > idx_cap {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let vertices =
+            std::slice::from_raw_parts_mut(vertices, mesh_vertices.len());
+        for (out, ll) in vertices.iter_mut().zip(&mesh_vertices) {
+            *out = LatLng {
+                lat: ll.lat(),
+                lng: ll.lng(),
+            };
+        }
+        std::slice::from_raw_parts_mut(indices, mesh_indices.len())
+            .copy_from_slice(&mesh_indices);
+
+        Ok((
+            convert::checked_int(mesh_vertices.len())?,
+            convert::checked_int(mesh_indices.len())?,
+        ))
+    }
+
+    match inner(cells, n, vertices, vertCap, indices, idxCap) {
+        Ok((verts, idx)) => {
+            if let Some(vertCount) = vertCount {
+                *vertCount = verts;
+            }
+            if let Some(idxCount) = idxCount {
+                *idxCount = idx;
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// polygonToCellsClipped fills `out` with the cells of a polygon (as
+/// polygonToCells does) that are also part of a mask.
+///
+/// Matching cells are written contiguously at the start of `out`.
+///
+/// @param geoPolygon The geoloop and holes defining the relevant area
+/// @param res The Hexagon resolution (0-15)
+/// @param flags Reserved, must be 0
+/// @param mask Set of allowed cells
+/// @param maskLen The number of cells in the mask
+/// @param out The slab of zeroed memory to write to. Assumed to be big enough.
+///
+/// # Safety
+///
+/// - `mask` must points to an array of at least `maskLen` elements.
+/// - `out` must points to an array of at least `maxPolygonToCellsSize`
+///   elements.
+#[no_mangle]
+pub unsafe extern "C" fn polygonToCellsClipped(
+    geoPolygon: Option<&GeoPolygon>,
+    res: c_int,
+    flags: u32,
+    mask: *const H3Index,
+    maskLen: i64,
+    out: *mut H3Index,
+) -> H3Error {
+    unsafe fn inner(
+        geoPolygon: &GeoPolygon,
+        res: c_int,
+        flags: u32,
+        mask: *const H3Index,
+        maskLen: i64,
+        out: *mut H3Index,
+    ) -> Result<(), H3Error> {
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
+        let resolution = convert::h3res_to_resolution(res)?;
+        if maskLen < 0 {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+
+        // Empty polygon or mask means no cell.
+        if geoPolygon.geoloop.numVerts == 0 || maskLen == 0 {
+            return Ok(());
+        }
+        let mask = convert::h3ptr_to_h3oslice(mask, maskLen)?
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>();
+
+        let polygon = Polygon::try_from(*geoPolygon)?;
+        let len =
+            h3oPolygon::from_radians(&polygon)?.max_cells_count(resolution);
+        let cells = polyfill::polygon_cells(&polygon, resolution)
+            .filter(|cell| mask.contains(cell));
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (i, cell_index) in cells.enumerate() {
+            *out.get_mut(i).ok_or(H3ErrorCodes::EMemoryBounds)? =
+                cell_index.into();
+        }
+        Ok(())
+    }
+
+    geoPolygon.map_or_else(
+        || H3ErrorCodes::EFailed.into(),
+        |geoPolygon| {
+            inner(geoPolygon, res, flags, mask, maskLen, out)
+                .err()
+                .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+        },
+    )
+}
+
+/// Maximum number of cells that can result from bboxToCells.
 ///
-/// @param geoPolygon A GeoJSON-like data structure indicating the poly to fill
-/// @param res Hexagon resolution (0-15)
-/// @param out number of cells to allocate for
-/// @return 0 (E_SUCCESS) on success.
+/// @param north Northern latitude of the box, in degrees
+/// @param south Southern latitude of the box, in degrees
+/// @param east  Eastern longitude of the box, in degrees
+/// @param west  Western longitude of the box, in degrees
+/// @param res   Resolution of the cells
+/// @param flags Reserved, must be 0
+/// @param out   Number of cells to allocate memory for
 #[no_mangle]
-pub extern "C" fn maxPolygonToCellsSize(
-    geoPolygon: Option<&GeoPolygon>,
+pub extern "C" fn maxBboxToCellsSize(
+    north: f64,
+    south: f64,
+    east: f64,
+    west: f64,
     res: c_int,
     flags: u32,
     out: Option<&mut i64>,
 ) -> H3Error {
     fn inner(
-        geoPolygon: &GeoPolygon,
+        north: f64,
+        south: f64,
+        east: f64,
+        west: f64,
         res: c_int,
         flags: u32,
     ) -> Result<i64, H3Error> {
         if flags != 0 {
             return Err(H3ErrorCodes::EOptionInvalid.into());
         }
-        // Empty polygon contains no cell.
-        if geoPolygon.geoloop.numVerts == 0 {
-            return Ok(0);
-        }
+        let resolution = convert::h3res_to_resolution(res)?;
+        let count = bbox_max_cells_count(north, south, east, west, resolution)?;
+
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(north, south, east, west, res, flags), out)
+}
+
+/// Fills `out` with the cells, at the given resolution, whose centers are
+/// contained in a lat/lng bounding box.
+///
+/// A box whose eastern longitude is lower than its western one crosses the
+/// antimeridian. Boxes wider than 180 degrees of longitude are supported, on
+/// either side of the antimeridian.
+///
+/// As in polygonToCells, cells whose center lies on the box boundary are
+/// included.
+///
+/// @param north Northern latitude of the box, in degrees
+/// @param south Southern latitude of the box, in degrees
+/// @param east  Eastern longitude of the box, in degrees
+/// @param west  Western longitude of the box, in degrees
+/// @param res   Resolution of the cells
+/// @param flags Reserved, must be 0
+/// @param out   The slab of zeroed memory to write to.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxBboxToCellsSize` elements.
+#[no_mangle]
+pub unsafe extern "C" fn bboxToCells(
+    north: f64,
+    south: f64,
+    east: f64,
+    west: f64,
+    res: c_int,
+    flags: u32,
+    out: *mut H3Index,
+) -> H3Error {
+    if flags != 0 {
+        return H3ErrorCodes::EOptionInvalid.into();
+    }
+    let resolution = match convert::h3res_to_resolution(res) {
+        Ok(resolution) => resolution,
+        Err(err) => return err.into(),
+    };
+    let (polygon, len) = match bbox_to_polygon(north, south, east, west)
+        .and_then(|polygon| {
+            Ok(Polygon::from(h3oPolygon::from_degrees(polygon)?))
+        })
+        .and_then(|polygon| {
+            let len =
+                bbox_max_cells_count(north, south, east, west, resolution)?;
+            Ok((polygon, len))
+        }) {
+        Ok(value) => value,
+        Err(err) => return err,
+    };
+
+    let out = std::slice::from_raw_parts_mut(out, len);
+    let cells = polyfill::polygon_cells(&polygon, resolution);
+    for (i, cell_index) in cells.enumerate() {
+        let Some(slot) = out.get_mut(i) else {
+            return H3ErrorCodes::EMemoryBounds.into();
+        };
+        *slot = cell_index.into();
+    }
+    H3ErrorCodes::ESuccess.into()
+}
 
+/// Maximum number of cells that can result from geoJsonToCells.
+///
+/// @param geojson GeoJSON string (in degrees)
+/// @param res     Resolution of the cells
+/// @param flags   Reserved, must be 0
+/// @param out     Number of cells to allocate memory for
+///
+/// # Safety
+///
+/// `geojson` must points to a null-terminated string.
+#[cfg(feature = "geojson")]
+#[no_mangle]
+pub unsafe extern "C" fn maxGeoJsonToCellsSize(
+    geojson: *const std::ffi::c_char,
+    res: c_int,
+    flags: u32,
+    out: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        geojson: *const std::ffi::c_char,
+        res: c_int,
+        flags: u32,
+    ) -> Result<i64, H3Error> {
+        if flags != 0 {
+            return Err(H3ErrorCodes::EOptionInvalid.into());
+        }
         let resolution = convert::h3res_to_resolution(res)?;
-        let polygon = Polygon::try_from(*geoPolygon)?;
-        let polygon = h3oPolygon::from_radians(&polygon)?;
+        let polygons = geojson_to_multipolygon(geojson)?;
+        let polygons = h3o::geom::MultiPolygon::from_degrees(polygons)?;
 
-        Ok(polygon
-            .max_cells_count(resolution)
-            .try_into()
-            .expect("too many cells"))
+        i64::try_from(polygons.max_cells_count(resolution))
+            .map_err(|_| H3ErrorCodes::EMemoryBounds.into())
     }
 
-    geoPolygon.map_or_else(
-        || H3ErrorCodes::EFailed.into(),
-        |geoPolygon| delegate_inner!(inner(geoPolygon, res, flags), out),
-    )
+    delegate_inner!(inner(geojson, res, flags), out)
 }
 
-/// polygonToCells takes a given GeoJSON-like data structure and preallocated,
-/// zeroed memory, and fills it with the hexagons that are contained by
-/// the GeoJSON-like data structure.
+/// Fills `out` with the cells contained by the polygons of a GeoJSON string.
 ///
-/// This implementation traces the GeoJSON geoloop(s) in cartesian space with
-/// hexagons, tests them and their neighbors to be contained by the geoloop(s),
-/// and then any newly found hexagons are used to test again until no new
-/// hexagons are found.
+/// The GeoJSON can be a `Polygon` or `MultiPolygon` geometry, a feature or a
+/// feature collection of such geometries (features without geometry are
+/// ignored), with coordinates in degrees.
 ///
-/// @param geoPolygon The geoloop and holes defining the relevant area
-/// @param res The Hexagon resolution (0-15)
-/// @param out The slab of zeroed memory to write to. Assumed to be big enough.
+/// Cells contained by several overlapping polygons (or whose center lies on a
+/// boundary they share) are reported once per polygon.
+///
+/// @param geojson GeoJSON string (in degrees)
+/// @param res     Resolution of the cells
+/// @param flags   Reserved, must be 0
+/// @param out     The slab of zeroed memory to write to.
+/// @return        E_OPTION_INVALID for non-polygonal geometries, E_FAILED for
+///                malformed GeoJSON.
 ///
 /// # Safety
 ///
-/// `out` must points to an array of at least `maxPolygonToCellsSize` elements.
+/// - `geojson` must points to a null-terminated string.
+/// - `out` must points to an array of at least `maxGeoJsonToCellsSize`
+///   elements.
+#[cfg(feature = "geojson")]
 #[no_mangle]
-pub unsafe extern "C" fn polygonToCells(
-    geoPolygon: Option<&GeoPolygon>,
+pub unsafe extern "C" fn geoJsonToCells(
+    geojson: *const std::ffi::c_char,
     res: c_int,
     flags: u32,
     out: *mut H3Index,
 ) -> H3Error {
     unsafe fn inner(
-        geoPolygon: &GeoPolygon,
+        geojson: *const std::ffi::c_char,
         res: c_int,
         flags: u32,
         out: *mut H3Index,
@@ -175,17 +1480,17 @@ pub unsafe extern "C" fn polygonToCells(
             return Err(H3ErrorCodes::EOptionInvalid.into());
         }
         let resolution = convert::h3res_to_resolution(res)?;
+        let polygons = geojson_to_multipolygon(geojson)?;
+        let polygons = h3o::geom::MultiPolygon::from_degrees(polygons)?;
 
-        // Empty polygon contains no cell.
-        if geoPolygon.geoloop.numVerts == 0 {
+        let len = polygons.max_cells_count(resolution);
+        if len == 0 {
             return Ok(());
         }
-
-        let polygon = Polygon::try_from(*geoPolygon)?;
-        let polygon = h3oPolygon::from_radians(&polygon)?;
-        let len = polygon.max_cells_count(resolution);
-        let cells = polygon.to_cells(resolution);
-
+        let polygons = MultiPolygon::from(polygons);
+        let cells = polygons
+            .iter()
+            .flat_map(|polygon| polyfill::polygon_cells(polygon, resolution));
         let out = std::slice::from_raw_parts_mut(out, len);
         for (i, cell_index) in cells.enumerate() {
             out[i] = cell_index.into();
@@ -193,18 +1498,698 @@ pub unsafe extern "C" fn polygonToCells(
         Ok(())
     }
 
-    geoPolygon.map_or_else(
-        || H3ErrorCodes::EFailed.into(),
-        |geoPolygon| {
-            inner(geoPolygon, res, flags, out)
-                .err()
-                .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+    inner(geojson, res, flags, out)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Extracts the polygons, in degrees, of a GeoJSON string.
+///
+/// # Safety
+///
+/// `geojson` must points to a null-terminated string.
+#[cfg(feature = "geojson")]
+unsafe fn geojson_to_multipolygon(
+    geojson: *const std::ffi::c_char,
+) -> Result<MultiPolygon<f64>, H3Error> {
+    use geojson::{GeoJson, Value};
+
+    if geojson.is_null() {
+        return Err(H3ErrorCodes::EFailed.into());
+    }
+    let geojson = std::ffi::CStr::from_ptr(geojson)
+        .to_str()
+        .map_err(|_| H3ErrorCodes::EFailed)?
+        .parse::<GeoJson>()
+        .map_err(|_| H3ErrorCodes::EFailed)?;
+    let geometries = match geojson {
+        GeoJson::Geometry(geometry) => vec![geometry],
+        GeoJson::Feature(feature) => feature.geometry.into_iter().collect(),
+        GeoJson::FeatureCollection(collection) => collection
+            .features
+            .into_iter()
+            .filter_map(|feature| feature.geometry)
+            .collect(),
+    };
+
+    let mut polygons = Vec::new();
+    for geometry in geometries {
+        match geometry.value {
+            Value::Polygon(_) => polygons.push(
+                Polygon::try_from(&geometry.value)
+                    .map_err(|_| H3ErrorCodes::EFailed)?,
+            ),
+            Value::MultiPolygon(_) => polygons.extend(
+                MultiPolygon::try_from(&geometry.value)
+                    .map_err(|_| H3ErrorCodes::EFailed)?,
+            ),
+            _ => return Err(H3ErrorCodes::EOptionInvalid.into()),
+        }
+    }
+    Ok(MultiPolygon::new(polygons))
+}
+
+/// Outlines a set of cells, simplifies the outline and writes it as a GeoJSON
+/// `MultiPolygon` geometry (in degrees).
+///
+/// Outlines are simplified using the Douglas–Peucker algorithm. When
+/// simplifying at the requested tolerance would introduce self-intersections
+/// (or collapse a ring), the tolerance is halved until the outline stays
+/// valid, falling back on the exact outline if needed.
+///
+/// If the buffer is too small, nothing is written and E_MEMORY_BOUNDS is
+/// returned: `needed` can be used to find the required size.
+///
+/// @param cells            Set of cells
+/// @param n                Number of cells in set
+/// @param toleranceDegrees Simplification tolerance, in degrees (>= 0)
+/// @param geojsonOut       Output buffer for the (null-terminated) GeoJSON
+/// @param sz               Size of the buffer `geojsonOut`
+/// @param needed           Set to the size required to hold the GeoJSON,
+///                         including the null terminator (optional)
+/// @return                 E_DOMAIN for an invalid tolerance, E_MEMORY_BOUNDS
+///                         if the buffer is too small.
+///
+/// # Safety
+///
+/// - `cells` must points to an array of at least `n` elements.
+/// - `geojsonOut` must points to an array of at least `sz` elements.
+#[cfg(feature = "geojson")]
+#[no_mangle]
+pub unsafe extern "C" fn cellsToSimplifiedPolygon(
+    cells: *const H3Index,
+    n: c_int,
+    toleranceDegrees: f64,
+    geojsonOut: *mut std::ffi::c_char,
+    sz: usize,
+    needed: Option<&mut usize>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: c_int,
+        toleranceDegrees: f64,
+    ) -> Result<Vec<u8>, H3Error> {
+        if !(toleranceDegrees >= 0. && toleranceDegrees.is_finite()) {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        let indexes = match n {
+            ..=-1 => return Err(H3ErrorCodes::EDomain.into()),
+            0 => &[],
+            _ => convert::h3ptr_to_h3oslice(cells, n.into())?,
+        };
+        let outline = normalize_outline(indexes.iter().copied().to_geom(true)?);
+        let simplified = simplify_outline(&outline, toleranceDegrees);
+
+        let geometry =
+            geojson::Geometry::new(geojson::Value::from(&simplified));
+        Ok(geometry.to_string().into_bytes())
+    }
+
+    let geojson = match inner(cells, n, toleranceDegrees) {
+        Ok(geojson) => geojson,
+        Err(err) => return err,
+    };
+    // Keep room for the null terminator.
+    let len = geojson.len() + 1;
+    if let Some(needed) = needed {
+        *needed = len;
+    }
+    if sz < len {
+        return H3ErrorCodes::EMemoryBounds.into();
+    }
+
+    let out = std::slice::from_raw_parts_mut(geojsonOut.cast::<u8>(), len);
+    out[..geojson.len()].copy_from_slice(&geojson);
+    out[geojson.len()] = 0;
+    H3ErrorCodes::ESuccess.into()
+}
+
+/// Makes the outline deterministic: each ring starts at its smallest vertex,
+/// and holes and polygons are sorted by their first vertex.
+///
+/// Without this, the output (and its size) could differ from one call to
+/// another.
+#[cfg(feature = "geojson")]
+fn normalize_outline(outline: MultiPolygon<f64>) -> MultiPolygon<f64> {
+    fn normalize_ring(mut ring: LineString<f64>) -> LineString<f64> {
+        // Drop the closing vertex, rotate, then close the ring again.
+        ring.0.pop();
+        let start = (0..ring.0.len())
+            .min_by(|&i, &j| compare_coords(ring.0[i], ring.0[j]))
+            .unwrap_or_default();
+        ring.0.rotate_left(start);
+        ring.close();
+        ring
+    }
+
+    fn first_coord(ring: &LineString<f64>) -> Coord {
+        ring.0.first().copied().unwrap_or_default()
+    }
+
+    let mut polygons = outline
+        .into_iter()
+        .map(|polygon| {
+            let (exterior, interiors) = polygon.into_inner();
+            let mut interiors = interiors
+                .into_iter()
+                .map(normalize_ring)
+                .collect::<Vec<_>>();
+            interiors
+                .sort_by(|a, b| compare_coords(first_coord(a), first_coord(b)));
+            Polygon::new(normalize_ring(exterior), interiors)
+        })
+        .collect::<Vec<_>>();
+    polygons.sort_by(|a, b| {
+        compare_coords(first_coord(a.exterior()), first_coord(b.exterior()))
+    });
+
+    MultiPolygon::new(polygons)
+}
+
+#[cfg(feature = "geojson")]
+fn compare_coords(a: Coord, b: Coord) -> std::cmp::Ordering {
+    a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+}
+
+/// Simplifies an outline, without introducing self-intersections.
+#[cfg(feature = "geojson")]
+fn simplify_outline(
+    outline: &MultiPolygon<f64>,
+    tolerance: f64,
+) -> MultiPolygon<f64> {
+    use geo::Simplify;
+
+    // Past a few halvings, the simplification isn't worth it anymore.
+    let mut tolerance = tolerance;
+    for _ in 0..8 {
+        if tolerance == 0. {
+            break;
+        }
+        let simplified = outline.simplify(&tolerance);
+        if is_simple_outline(&simplified) {
+            return simplified;
+        }
+        tolerance /= 2.;
+    }
+
+    outline.clone()
+}
+
+/// Checks that every ring is still a ring, and that edges don't cross, overlap
+/// or touch: only consecutive edges of a ring may meet, at their shared vertex.
+///
+/// Edges are swept along the x axis, so that each edge is only tested against
+/// the active ones (whose x range overlaps its own): O(n log n + n * a) for n
+/// edges and at most a active edges, i.e. close to O(n log n) for outlines made
+/// of short edges, and O(n²) in the worst case.
+#[cfg(feature = "geojson")]
+fn is_simple_outline(outline: &MultiPolygon<f64>) -> bool {
+    use geo::{
+        line_intersection::{line_intersection, LineIntersection},
+        Line,
+    };
+
+    let rings = outline
+        .iter()
+        .flat_map(|polygon| {
+            std::iter::once(polygon.exterior()).chain(polygon.interiors())
+        })
+        .collect::<Vec<_>>();
+    if rings.iter().any(|ring| ring.0.len() < 4) {
+        return false;
+    }
+
+    // Edges, along with their ring and their position in that ring.
+    let mut lines = rings
+        .iter()
+        .enumerate()
+        .flat_map(|(ring, coords)| {
+            let len = coords.0.len() - 1;
+            coords
+                .lines()
+                .enumerate()
+                .map(move |(i, line)| (ring, i, len, line))
+        })
+        .collect::<Vec<(usize, usize, usize, Line<f64>)>>();
+    lines.sort_by(|a, b| {
+        let (a, b) = (a.3, b.3);
+        a.start.x.min(a.end.x).total_cmp(&b.start.x.min(b.end.x))
+    });
+    let mut active = Vec::<(usize, usize, usize, Line<f64>)>::new();
+    for edge in lines {
+        let (ring, i, len, line) = edge;
+        let min_x = line.start.x.min(line.end.x);
+        active.retain(|other| other.3.start.x.max(other.3.end.x) >= min_x);
+        let touches = active.iter().any(|&(other_ring, j, _, other)| {
+            let adjacent = ring == other_ring
+                && ((i + 1) % len == j || (j + 1) % len == i);
+            match line_intersection(line, other) {
+                None => false,
+                // Consecutive edges share a vertex, and nothing else.
+                Some(LineIntersection::SinglePoint { is_proper, .. }) => {
+                    is_proper || !adjacent
+                }
+                Some(LineIntersection::Collinear { intersection }) => {
+                    intersection.start != intersection.end || !adjacent
+                }
+            }
+        });
+        if touches {
+            return false;
+        }
+        active.push(edge);
+    }
+
+    true
+}
+
+/// Counts the cells polygonToCells would produce, stopping at `limit`.
+fn cells_count(
+    polygon: &Polygon<f64>,
+    resolution: Resolution,
+    limit: usize,
+) -> Result<usize, H3Error> {
+    // Validates the polygon.
+    h3oPolygon::from_radians(polygon)?;
+    Ok(polyfill::polygon_cells(polygon, resolution)
+        .take(limit)
+        .count())
+}
+
+/// Returns the cells contained by the polygon, followed by the cells
+/// overlapping its boundary.
+fn covering_cells(
+    polygon: &Polygon<f64>,
+    resolution: Resolution,
+) -> Result<Vec<CellIndex>, H3Error> {
+    // Validates the polygon.
+    h3oPolygon::from_radians(polygon)?;
+    let mut cells =
+        polyfill::polygon_cells(polygon, resolution).collect::<Vec<_>>();
+    let mut seen = cells.iter().copied().collect::<HashSet<_>>();
+    for cell in edge_cells(&polygon_lines(polygon), resolution)? {
+        if seen.insert(cell) {
+            cells.push(cell);
+        }
+    }
+
+    Ok(cells)
+}
+
+/// Computes the fraction of the cell area inside the polygon, on the planar
+/// lat/lng projection.
+fn overlap_fraction(index: CellIndex, polygon: &Polygon<f64>) -> f64 {
+    let mut boundary = index
+        .boundary()
+        .iter()
+        .map(|ll| Coord {
+            x: ll.lng_radians(),
+            y: ll.lat_radians(),
+        })
+        .collect::<Vec<_>>();
+    let mut rings = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(|ring| ring.0.clone())
+        .collect::<Vec<_>>();
+
+    // Bring the cell and the polygon into one longitude frame, centered on
+    // the cell, so that crossing the antimeridian doesn't matter.
+    let center = h3o::LatLng::from(index).lng_radians();
+    for ring in std::iter::once(&mut boundary).chain(rings.iter_mut()) {
+        unwrap_ring(ring, center);
+    }
+
+    // Fast path: the polygon is away from the cell.
+    if !bbox_intersects(&rings[0], &boundary) {
+        return 0.;
+    }
+    // Fast path: without crossings, the cell is either fully inside or
+    // fully outside, unless a ring lies within the cell.
+    let lines = rings
+        .iter()
+        .flat_map(|ring| {
+            ring.windows(2).map(|pair| Line::new(pair[0], pair[1]))
+        })
+        .collect::<Vec<_>>();
+    let crossed = boundary.iter().zip(boundary.iter().cycle().skip(1)).any(
+        |(&start, &end)| {
+            lines
+                .iter()
+                .any(|line| lines_intersect(Line::new(start, end), *line))
+        },
+    );
+    let enclosed = rings.iter().any(|ring| {
+        ring.first()
+            .is_some_and(|&coord| ring_contains(&boundary, coord))
+    });
+    if !crossed && !enclosed {
+        let inside = ring_contains(&rings[0], boundary[0])
+            && !rings[1..]
+                .iter()
+                .any(|hole| ring_contains(hole, boundary[0]));
+        return if inside { 1. } else { 0. };
+    }
+
+    let cell_area = ring_area(&boundary).abs();
+    let exterior = ring_area(&clip_ring(&rings[0], &boundary)).abs();
+    let holes = rings[1..]
+        .iter()
+        .map(|hole| ring_area(&clip_ring(hole, &boundary)).abs())
+        .sum::<f64>();
+
+    ((exterior - holes) / cell_area).clamp(0., 1.)
+}
+
+/// Unwraps the longitudes of a ring (consecutive deltas kept within ±π), then
+/// shifts it by whole turns so that its middle is within ±π of `center`.
+fn unwrap_ring(ring: &mut [Coord], center: f64) {
+    let mut lng = center;
+    for coord in ring.iter_mut() {
+        let delta = coord.x - lng;
+        lng += (-TAU).mul_add((delta / TAU).round(), delta);
+        coord.x = lng;
+    }
+
+    let (min_x, max_x) = ring
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), coord| {
+            (min.min(coord.x), max.max(coord.x))
+        });
+    let shift = TAU * ((f64::midpoint(min_x, max_x) - center) / TAU).round();
+    for coord in ring.iter_mut() {
+        coord.x -= shift;
+    }
+}
+
+/// Returns the edges of every ring of the polygon.
+fn polygon_lines(polygon: &Polygon<f64>) -> Vec<Line<f64>> {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(LineString::lines)
+        .collect()
+}
+
+/// Returns the cells crossed by the lines (in radians).
+fn edge_cells(
+    lines: &[Line<f64>],
+    resolution: Resolution,
+) -> Result<HashSet<CellIndex>, H3Error> {
+    lines.iter().try_fold(HashSet::new(), |mut cells, line| {
+        cells.extend(line_cells(*line, resolution)?);
+        Ok(cells)
+    })
+}
+
+/// Returns the cells crossed by the line (in radians).
+fn line_cells(
+    line: Line<f64>,
+    resolution: Resolution,
+) -> Result<HashSet<CellIndex>, H3Error> {
+    let mut cells = HashSet::new();
+
+    // The cell containing the line start overlaps the line, and the cells
+    // crossed by the rest of the line are reachable from it.
+    let ll = h3o::LatLng::from_radians(line.start.y, line.start.x)?;
+    let mut queue = vec![ll.to_cell(resolution)];
+    while let Some(cell) = queue.pop() {
+        if !cells.insert(cell) {
+            continue;
+        }
+        let neighbors = cell.grid_disk::<Vec<_>>(1);
+        queue.extend(neighbors.into_iter().filter(|neighbor| {
+            !cells.contains(neighbor) && crosses_cell(*neighbor, &[line])
+        }));
+    }
+
+    Ok(cells)
+}
+
+/// Checks if any of the lines (in radians) crosses the boundary of the cell.
+fn crosses_cell(cell: CellIndex, lines: &[Line<f64>]) -> bool {
+    let boundary = cell
+        .boundary()
+        .iter()
+        .map(|ll| Coord {
+            x: ll.lng_radians(),
+            y: ll.lat_radians(),
+        })
+        .collect::<Vec<_>>();
+    let edges = boundary
+        .iter()
+        .zip(boundary.iter().cycle().skip(1))
+        .map(|(&start, &end)| Line::new(start, end));
+
+    edges
+        .into_iter()
+        .any(|edge| lines.iter().any(|line| lines_intersect(edge, *line)))
+}
+
+/// Checks if two segments intersect (touching included), on the plane.
+fn lines_intersect(a: Line<f64>, b: Line<f64>) -> bool {
+    // Sign of the turn from `p -> q` to `p -> r`.
+    fn orientation(p: Coord, q: Coord, r: Coord) -> f64 {
+        (q.x - p.x).mul_add(r.y - p.y, -((q.y - p.y) * (r.x - p.x)))
+    }
+    // Whether `r`, collinear with `p` and `q`, lies on the segment.
+    fn on_segment(p: Coord, q: Coord, r: Coord) -> bool {
+        r.x >= p.x.min(q.x)
+            && r.x <= p.x.max(q.x)
+            && r.y >= p.y.min(q.y)
+            && r.y <= p.y.max(q.y)
+    }
+
+    let d1 = orientation(b.start, b.end, a.start);
+    let d2 = orientation(b.start, b.end, a.end);
+    let d3 = orientation(a.start, a.end, b.start);
+    let d4 = orientation(a.start, a.end, b.end);
+    if d1 * d2 < 0. && d3 * d4 < 0. {
+        return true;
+    }
+
+    (d1 == 0. && on_segment(b.start, b.end, a.start))
+        || (d2 == 0. && on_segment(b.start, b.end, a.end))
+        || (d3 == 0. && on_segment(a.start, a.end, b.start))
+        || (d4 == 0. && on_segment(a.start, a.end, b.end))
+}
+
+/// Checks if the bounding boxes of two rings intersect.
+fn bbox_intersects(a: &[Coord], b: &[Coord]) -> bool {
+    let bbox = |ring: &[Coord]| {
+        ring.iter().fold(
+            (
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(min_x, max_x, min_y, max_y), coord| {
+                (
+                    min_x.min(coord.x),
+                    max_x.max(coord.x),
+                    min_y.min(coord.y),
+                    max_y.max(coord.y),
+                )
+            },
+        )
+    };
+    let (a_min_x, a_max_x, a_min_y, a_max_y) = bbox(a);
+    let (b_min_x, b_max_x, b_min_y, b_max_y) = bbox(b);
+
+    a_min_x <= b_max_x
+        && b_min_x <= a_max_x
+        && a_min_y <= b_max_y
+        && b_min_y <= a_max_y
+}
+
+/// Checks if a point is inside a ring, using ray casting.
+fn ring_contains(ring: &[Coord], point: Coord) -> bool {
+    let mut inside = false;
+    for (start, end) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+        if (start.y > point.y) != (end.y > point.y) {
+            let x = (end.x - start.x)
+                .mul_add((point.y - start.y) / (end.y - start.y), start.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Computes the signed area of a ring, positive when counter-clockwise.
+fn ring_area(ring: &[Coord]) -> f64 {
+    // Work relative to the first vertex, to avoid cancellations on small
+    // rings far from the origin.
+    let Some(&origin) = ring.first() else {
+        return 0.;
+    };
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(&a, &b)| {
+            let (a, b) = (a - origin, b - origin);
+            a.x.mul_add(b.y, -(b.x * a.y))
+        })
+        .sum::<f64>()
+        / 2.
+}
+
+/// Clips a ring by a convex ring (Sutherland-Hodgman).
+///
+/// The result may contain degenerate edges when the ring is concave, which
+/// doesn't affect its area.
+fn clip_ring(ring: &[Coord], convex: &[Coord]) -> Vec<Coord> {
+    let orientation = ring_area(convex).signum();
+    let mut output = ring.to_vec();
+    for (&start, &end) in convex.iter().zip(convex.iter().cycle().skip(1)) {
+        let side = |p: Coord| {
+            orientation
+                * (end.x - start.x).mul_add(
+                    p.y - start.y,
+                    -((end.y - start.y) * (p.x - start.x)),
+                )
+        };
+        let input = std::mem::take(&mut output);
+        for (&a, &b) in input.iter().zip(input.iter().cycle().skip(1)) {
+            let (side_a, side_b) = (side(a), side(b));
+            if side_a >= 0. {
+                output.push(a);
+            }
+            if (side_a >= 0.) != (side_b >= 0.) {
+                let t = side_a / (side_a - side_b);
+                output.push(Coord {
+                    x: (b.x - a.x).mul_add(t, a.x),
+                    y: (b.y - a.y).mul_add(t, a.y),
+                });
+            }
+        }
+    }
+    output
+}
+
+/// Builds the polygon, in degrees, of a lat/lng bounding box.
+fn bbox_to_polygon(
+    north: f64,
+    south: f64,
+    east: f64,
+    west: f64,
+) -> Result<Polygon<f64>, H3Error> {
+    let lngs = bbox_lngs(north, south, east, west)?;
+    let coords = lngs
+        .iter()
+        .map(|&lng| (lng, south))
+        .chain(lngs.iter().rev().map(|&lng| (lng, north)))
+        .chain(std::iter::once((west, south)))
+        .collect::<Vec<_>>();
+    Ok(Polygon::new(LineString::from(coords), Vec::new()))
+}
+
+/// Maximum number of cells whose center is in a lat/lng bounding box.
+///
+/// The polygon estimate is based on the diagonal of the box, which is way off
+/// for boxes wider than 180 degrees, so the box is estimated by slices.
+fn bbox_max_cells_count(
+    north: f64,
+    south: f64,
+    east: f64,
+    west: f64,
+    resolution: Resolution,
+) -> Result<usize, H3Error> {
+    bbox_lngs(north, south, east, west)?.windows(2).try_fold(
+        0,
+        |count, slice| {
+            let polygon = bbox_to_polygon(north, south, slice[1], slice[0])?;
+            let polygon = h3oPolygon::from_degrees(polygon)?;
+            Ok(count + polygon.max_cells_count(resolution))
         },
     )
 }
 
+/// Validates a lat/lng bounding box, and returns the longitudes splitting it,
+/// from `west` to `east`, into slices of at most 90 degrees.
+fn bbox_lngs(
+    north: f64,
+    south: f64,
+    east: f64,
+    west: f64,
+) -> Result<Vec<f64>, H3Error> {
+    let is_lat = |lat: f64| (-90. ..=90.).contains(&lat);
+    let is_lng = |lng: f64| (-180. ..=180.).contains(&lng);
+    if !(is_lat(north) && is_lat(south) && is_lng(east) && is_lng(west)) {
+        return Err(H3ErrorCodes::ELatlngDomain.into());
+    }
+    if north < south {
+        return Err(H3ErrorCodes::EDomain.into());
+    }
+
+    // Edges spanning more than 180 degrees of longitude are read as crossing
+    // the antimeridian, so the southern and northern edges are split: only the
+    // ones actually crossing the antimeridian end up with a longitude jump.
+    let width = if east >= west {
+        east - west
+    } else {
+        east + 360. - west
+    };
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "width is in [0, 360]"
+    )]
+    let slices = ((width / 90.).ceil() as u8).max(1);
+    Ok((0..=slices)
+        .map(|i| {
+            if i == slices {
+                return east;
+            }
+            let lng = width * f64::from(i) / f64::from(slices) + west;
+            if lng > 180. {
+                lng - 360.
+            } else {
+                lng
+            }
+        })
+        .collect())
+}
+
+/// Iterates over the non-empty polygons of a linked geo structure.
+///
+/// # Safety
+///
+/// The structure must be a well-formed linked list.
+unsafe fn linked_polygons(
+    head: Option<&LinkedGeoPolygon>,
+) -> impl Iterator<Item = &LinkedGeoPolygon> {
+    std::iter::successors(head, |polygon| polygon.next.as_ref())
+        // The head of an empty structure has no loop.
+        .filter(|polygon| !polygon.first.is_null())
+}
+
+/// Counts the polygons, loops and vertices of a linked geo structure.
+///
+/// # Safety
+///
+/// The structure must be a well-formed linked list.
+unsafe fn linked_counts(
+    head: Option<&LinkedGeoPolygon>,
+) -> (usize, usize, usize) {
+    let (mut polygons, mut loops, mut verts) = (0, 0, 0);
+    for polygon in linked_polygons(head) {
+        polygons += 1;
+        for geoloop in polygon.loops() {
+            loops += 1;
+            verts += geoloop.coords().count();
+        }
+    }
+    (polygons, loops, verts)
+}
+
 // -----------------------------------------------------------------------------
 
+/// Opaque polygon to cells configuration, see polyfillConfigNew.
+#[derive(Debug)]
+pub struct H3PolyfillConfig {
+    resolution: Resolution,
+}
+
 /// Similar to `CellBoundary`, but requires more alloc work.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -302,6 +2287,17 @@ pub struct LinkedGeoLoop {
     pub next: *mut Self,
 }
 
+impl LinkedGeoLoop {
+    /// Iterates over the coordinates of the loop.
+    ///
+    /// # Safety
+    ///
+    /// The loop must be a well-formed linked list.
+    unsafe fn coords(&self) -> impl Iterator<Item = &LinkedLatLng> {
+        std::iter::successors(self.first.as_ref(), |coord| coord.next.as_ref())
+    }
+}
+
 impl From<LineString> for LinkedGeoLoop {
     fn from(mut value: LineString) -> Self {
         let mut ring = Self {
@@ -341,6 +2337,19 @@ pub struct LinkedGeoPolygon {
     pub next: *mut Self,
 }
 
+impl LinkedGeoPolygon {
+    /// Iterates over the loops of the polygon.
+    ///
+    /// # Safety
+    ///
+    /// The polygon must be a well-formed linked list.
+    unsafe fn loops(&self) -> impl Iterator<Item = &LinkedGeoLoop> {
+        std::iter::successors(self.first.as_ref(), |geoloop| {
+            geoloop.next.as_ref()
+        })
+    }
+}
+
 impl From<MultiPolygon> for LinkedGeoPolygon {
     fn from(value: MultiPolygon) -> Self {
         let mut head = ptr::null_mut();
@@ -383,3 +2392,30 @@ impl From<Polygon> for LinkedGeoPolygon {
         polygon
     }
 }
+
+#[cfg(all(test, feature = "geojson"))]
+mod tests {
+    use super::*;
+    use geo_types::polygon;
+
+    #[test]
+    fn simple_outline() {
+        let square = polygon![
+            (x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.),
+        ];
+        assert!(is_simple_outline(&MultiPolygon::new(vec![square.clone()])));
+
+        // Vertex touching the interior of another edge.
+        let pinched = polygon![
+            (x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 1., y: 0.),
+            (x: 0., y: 2.),
+        ];
+        assert!(!is_simple_outline(&MultiPolygon::new(vec![pinched])));
+
+        // Rings of two polygons touching at a vertex.
+        let corner = polygon![
+            (x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.),
+        ];
+        assert!(!is_simple_outline(&MultiPolygon::new(vec![square, corner])));
+    }
+}
@@ -1,6 +1,118 @@
 use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index, H3_NULL};
 use h3o::{error::LocalIjError, CellIndex};
-use std::ffi::c_int;
+use std::{collections::HashSet, f64::consts::PI, ffi::c_int};
+
+/// Produces the unique parents, at the given resolution, of the cells within
+/// grid distance k of the origin cell.
+///
+/// This is the "coarse neighborhood" of the origin cell. Parents are written in
+/// no particular order.
+///
+/// @param h         origin cell
+/// @param k         k >= 0
+/// @param parentRes The resolution of the parents, coarser than (or equal to)
+///                  the resolution of the origin cell
+/// @param out       Output array for the unique parents
+/// @param count     Set to the number of parents written in `out`
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxGridDiskSize(k)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToKRingParents(
+    h: H3Index,
+    k: c_int,
+    parentRes: c_int,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        h: H3Index,
+        k: c_int,
+        parentRes: c_int,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let origin = CellIndex::try_from(h)?;
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let parent_res = convert::h3res_to_resolution(parentRes)?;
+        if parent_res > origin.resolution() {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
+
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut seen = HashSet::new();
+        let parents = origin
+            .grid_disk_safe(k)
+            .filter_map(|cell| cell.parent(parent_res))
+            .filter(|&parent| seen.insert(parent));
+        let mut count = 0;
+        for parent in parents {
+            out[count] = parent.into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(h, k, parentRes, out), count)
+}
+
+/// Returns the cells computed by the last gridDiskInto call on a scratch.
+///
+/// The view is borrowed: it is only valid until the next gridDiskInto call on
+/// the same scratch, or until the scratch is freed.
+///
+/// @param scratch Scratch buffer created by diskScratchNew
+/// @return        The cells of the last disk, or NULL if `scratch` is NULL.
+#[no_mangle]
+pub extern "C" fn diskScratchCells(
+    scratch: Option<&H3DiskScratch>,
+) -> *const H3Index {
+    scratch.map_or(std::ptr::null(), |scratch| scratch.cells.as_ptr())
+}
+
+/// Frees a scratch buffer created by diskScratchNew.
+///
+/// # Safety
+///
+/// `scratch` must be null or a scratch returned by diskScratchNew that hasn't
+/// been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn diskScratchFree(scratch: *mut H3DiskScratch) {
+    if !scratch.is_null() {
+        drop(Box::from_raw(scratch));
+    }
+}
+
+/// Creates a scratch buffer for repeated gridDisk queries with the same k.
+///
+/// The buffer is allocated once, here, and reused by every gridDiskInto call.
+///
+/// It is the responsibility of the caller to call diskScratchFree on the
+/// scratch, or its memory will not be freed.
+///
+/// @param k   k >= 0
+/// @param out Set to the newly allocated scratch
+/// @return    E_MEMORY_ALLOC if the buffer cannot be allocated.
+#[no_mangle]
+pub extern "C" fn diskScratchNew(
+    k: c_int,
+    out: Option<&mut *mut H3DiskScratch>,
+) -> H3Error {
+    fn inner(k: c_int) -> Result<*mut H3DiskScratch, H3Error> {
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+        // Large disks can be way too big to allocate.
+        let mut cells = Vec::new();
+        cells
+            .try_reserve_exact(len)
+            .map_err(|_| H3ErrorCodes::EMemoryAlloc)?;
+
+        Ok(Box::into_raw(Box::new(H3DiskScratch { k, cells })))
+    }
+
+    delegate_inner!(inner(k), out)
+}
 
 /// Produce cells within grid distance k of the origin cell.
 ///
@@ -55,12 +167,17 @@ pub unsafe extern "C" fn gridDisk(
     }
 
     // Get the expected size of the output variables.
-    let Ok(k) = u32::try_from(k) else { return H3ErrorCodes::EDomain.into() };
+    let Ok(k) = u32::try_from(k) else {
+        return H3ErrorCodes::EDomain.into();
+    };
     let size = h3o::max_grid_disk_size(k);
 
     // Convert pointers to slices.
     // This is the part that goes UB if the caller didn't respect the contract.
-    let len = usize::try_from(size).expect("overflow");
+    let len = match convert::checked_int(size) {
+        Ok(len) => len,
+        Err(err) => return err.into(),
+    };
     let slice = std::slice::from_raw_parts_mut(out, len);
 
     if let Err(err) = inner(origin, k, slice) {
@@ -109,7 +226,7 @@ pub unsafe extern "C" fn gridDiskDistances(
         for result in origin.grid_disk_distances_fast(k) {
             if let Some((index, dist)) = result {
                 cells[count] = index.into();
-                dists[count] = dist.try_into().expect("distance overflow");
+                dists[count] = convert::checked_int(dist)?;
                 count += 1;
             } else {
                 cells[..count].fill(H3_NULL);
@@ -123,7 +240,7 @@ pub unsafe extern "C" fn gridDiskDistances(
         if count == 0 {
             for (index, dist) in origin.grid_disk_distances_safe(k) {
                 cells[count] = index.into();
-                dists[count] = dist.try_into().expect("distance overflow");
+                dists[count] = convert::checked_int(dist)?;
                 count += 1;
             }
         }
@@ -132,12 +249,17 @@ pub unsafe extern "C" fn gridDiskDistances(
     }
 
     // Get the expected size of the output variables.
-    let Ok(k) = u32::try_from(k) else { return H3ErrorCodes::EDomain.into() };
+    let Ok(k) = u32::try_from(k) else {
+        return H3ErrorCodes::EDomain.into();
+    };
     let size = h3o::max_grid_disk_size(k);
 
     // Convert pointers to slices.
     // This is the part that goes UB if the caller didn't respect the contract.
-    let len = usize::try_from(size).expect("overflow");
+    let len = match convert::checked_int(size) {
+        Ok(len) => len,
+        Err(err) => return err.into(),
+    };
     let cells = std::slice::from_raw_parts_mut(out, len);
     let dists = std::slice::from_raw_parts_mut(distances, len);
 
@@ -153,6 +275,9 @@ pub unsafe extern "C" fn gridDiskDistances(
 /// Adds the origin cell to the output set (treating it as a hash set)
 /// and recurses to its neighbors, if needed.
 ///
+/// Entries past the last written cell (e.g. when crossing a pentagon) are set
+/// to H3_NULL in `out` and -1 in `distances`.
+///
 /// @param  origin      Origin cell
 /// @param  k           Maximum distance to move from the origin
 /// @param  out         Array treated as a hash set, elements being either
@@ -172,26 +297,60 @@ pub unsafe extern "C" fn gridDiskDistancesSafe(
     out: *mut H3Index,
     distances: *mut c_int,
 ) -> H3Error {
-    fn inner(
+    gridDiskDistancesSafeWithCount(origin, k, out, distances, None)
+}
+
+/// Same as gridDiskDistancesSafe, but also reports the number of cells
+/// written.
+///
+/// @param  origin      Origin cell
+/// @param  k           Maximum distance to move from the origin
+/// @param  out         Array of size maxGridDiskSize(k), the unused tail is
+///                     set to H3_NULL
+/// @param  distances   Array of size maxGridDiskSize(k), the unused tail is
+///                     set to -1
+/// @param  count       NULL or set to the number of cells written
+///
+/// # Safety
+///
+/// `out` and `distances` must points to an array of at least
+/// `maxGridDiskSize(k)` elements each.
+#[no_mangle]
+pub unsafe extern "C" fn gridDiskDistancesSafeWithCount(
+    origin: H3Index,
+    k: c_int,
+    out: *mut H3Index,
+    distances: *mut c_int,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
         origin: H3Index,
         k: c_int,
-    ) -> Result<(u64, impl Iterator<Item = (CellIndex, u32)>), H3Error> {
+        out: *mut H3Index,
+        distances: *mut c_int,
+    ) -> Result<i64, H3Error> {
         let origin = CellIndex::try_from(origin)?;
         let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
-        Ok((
-            h3o::max_grid_disk_size(k),
-            origin.grid_disk_distances_safe(k),
-        ))
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+
+        let cells = std::slice::from_raw_parts_mut(out, len);
+        let dists = std::slice::from_raw_parts_mut(distances, len);
+        let mut count = 0;
+        for (cell_index, dist) in origin.grid_disk_distances_safe(k) {
+            cells[count] = cell_index.into();
+            dists[count] = convert::checked_int(dist)?;
+            count += 1;
+        }
+        cells[count..].fill(H3_NULL);
+        dists[count..].fill(-1);
+
+        Ok(convert::checked_int(count)?)
     }
 
-    match inner(origin, k) {
-        Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
-            let cells = std::slice::from_raw_parts_mut(out, len);
-            let dists = std::slice::from_raw_parts_mut(distances, len);
-            for (i, (cell_index, dist)) in iter.enumerate() {
-                cells[i] = cell_index.into();
-                dists[i] = dist.try_into().expect("distance overflow");
+    match inner(origin, k, out, distances) {
+        Ok(value) => {
+            if let Some(count) = count {
+                *count = value;
             }
             H3ErrorCodes::ESuccess.into()
         }
@@ -199,6 +358,147 @@ pub unsafe extern "C" fn gridDiskDistancesSafe(
     }
 }
 
+/// Produce cells and their distances from the given origin cell, up to
+/// distance k, sorted by increasing distance.
+///
+/// Cells at the same distance are in no particular order. The safe algorithm
+/// is used, so the output is dense (no zero elements), even around pentagons.
+///
+/// @param  origin      origin cell
+/// @param  k           k >= 0
+/// @param  out         Output array for the cells
+/// @param  distances   Output array for the distances, parallel to `out`
+/// @param  count       Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// `out` and `distances` must points to an array of at least
+/// `maxGridDiskSize(k)` elements each.
+#[no_mangle]
+pub unsafe extern "C" fn gridDiskDistancesSorted(
+    origin: H3Index,
+    k: c_int,
+    out: *mut H3Index,
+    distances: *mut c_int,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        origin: H3Index,
+        k: c_int,
+        out: *mut H3Index,
+        distances: *mut c_int,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+        let mut cells = origin.grid_disk_distances_safe(k).collect::<Vec<_>>();
+        cells.sort_by_key(|&(_, distance)| distance);
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let distances = std::slice::from_raw_parts_mut(distances, len);
+        for (i, &(cell, distance)) in cells.iter().enumerate() {
+            out[i] = cell.into();
+            distances[i] = convert::checked_int(distance)?;
+        }
+        Ok(convert::checked_int(cells.len())?)
+    }
+
+    delegate_inner!(inner(origin, k, out, distances), count)
+}
+
+/// Produce cells within grid distance k of the origin cell, along with the
+/// great-circle distance (in kilometers) between the origin center and their
+/// centers.
+///
+/// Cells are in the same order as gridDisk, but the output is dense (no zero
+/// elements), even around pentagons.
+///
+/// @param  origin      origin cell
+/// @param  k           k >= 0
+/// @param  out         Output array for the cells
+/// @param  distances   Output array for the distances, parallel to `out`
+/// @param  count       Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// `out` and `distances` must points to an array of at least
+/// `maxGridDiskSize(k)` elements each.
+#[no_mangle]
+pub unsafe extern "C" fn gridDiskGeoDistancesKm(
+    origin: H3Index,
+    k: c_int,
+    out: *mut H3Index,
+    distances: *mut f64,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        origin: H3Index,
+        k: c_int,
+        out: *mut H3Index,
+        distances: *mut f64,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+        let center = h3o::LatLng::from(origin);
+        let cells = origin.grid_disk::<Vec<_>>(k);
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let distances = std::slice::from_raw_parts_mut(distances, len);
+        for (i, &cell) in cells.iter().enumerate() {
+            out[i] = cell.into();
+            distances[i] = center.distance_km(h3o::LatLng::from(cell));
+        }
+        Ok(convert::checked_int(cells.len())?)
+    }
+
+    delegate_inner!(inner(origin, k, out, distances), count)
+}
+
+/// Produces a k such that gridDisk(origin, k) contains every cell whose center
+/// is within `radiusKm` of the origin center.
+///
+/// Cells at grid distance k are at least `1.5 * k` edge lengths away from the
+/// origin, so k is computed with a lower bound of the edge lengths at the
+/// origin resolution (edges around pentagons are shorter than average). This is
+/// a conservative upper bound, not the smallest such k: away from the shortest
+/// edges, it can be up to about twice as large.
+///
+/// @param origin   origin cell
+/// @param radiusKm radius, in kilometers (> 0)
+/// @param out      Set to the k value
+/// @return         E_DOMAIN if the radius isn't positive.
+#[no_mangle]
+pub extern "C" fn kForRadiusKm(
+    origin: H3Index,
+    radiusKm: f64,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(origin: H3Index, radiusKm: f64) -> Result<c_int, H3Error> {
+        // Ratio of the shortest edge to the average edge length: measured over
+        // every edge from res 0 to 7, it bottoms out at 0.70 on the odd
+        // resolutions (Class III), rounded down for the finer ones.
+        const MIN_EDGE_RATIO: f64 = 0.65;
+
+        let origin = CellIndex::try_from(origin)?;
+        if !(radiusKm > 0. && radiusKm.is_finite()) {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        // No cell is farther than half the circumference.
+        let radius = radiusKm.min(PI * h3o::EARTH_RADIUS_KM);
+        let min_edge = origin.resolution().edge_length_km() * MIN_EDGE_RATIO;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "at most ~1e8, for half the circumference at res 15"
+        )]
+        let k = (radius / (1.5 * min_edge)).ceil() as c_int;
+
+        Ok(k)
+    }
+
+    delegate_inner!(inner(origin, radiusKm), out)
+}
+
 /// gridDiskDistancesUnsafe produces indexes within k distance of the origin
 /// index. Output behavior is undefined when one of the indexes returned by this
 /// function is a pentagon or is in the pentagon distortion area.
@@ -242,13 +542,19 @@ pub unsafe extern "C" fn gridDiskDistancesUnsafe(
 
     match inner(origin, k) {
         Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
+            let len = match convert::checked_int(len) {
+                Ok(len) => len,
+                Err(err) => return err.into(),
+            };
             let cells = std::slice::from_raw_parts_mut(out, len);
             let dists = std::slice::from_raw_parts_mut(distances, len);
             for (i, item) in iter.enumerate() {
                 if let Some((cell_index, dist)) = item {
                     cells[i] = cell_index.into();
-                    dists[i] = dist.try_into().expect("distance overflow");
+                    match convert::checked_int(dist) {
+                        Ok(dist) => dists[i] = dist,
+                        Err(err) => return err.into(),
+                    }
                 } else {
                     return H3ErrorCodes::EPentagon.into();
                 }
@@ -259,6 +565,114 @@ pub unsafe extern "C" fn gridDiskDistancesUnsafe(
     }
 }
 
+/// Produces cells within grid distance k of the origin cell, into a reusable
+/// scratch buffer.
+///
+/// The k is the one the scratch was created with. Unlike gridDisk, the output
+/// is dense (no zero elements): read it through diskScratchCells.
+///
+/// @param scratch Scratch buffer created by diskScratchNew
+/// @param origin  origin cell
+/// @param count   Set to the number of cells in the disk
+#[no_mangle]
+pub extern "C" fn gridDiskInto(
+    scratch: Option<&mut H3DiskScratch>,
+    origin: H3Index,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(
+        scratch: Option<&mut H3DiskScratch>,
+        origin: H3Index,
+    ) -> Result<c_int, H3Error> {
+        let scratch = scratch.ok_or(H3ErrorCodes::EFailed)?;
+        let origin = CellIndex::try_from(origin)?;
+
+        // Try fast version first.
+        scratch.cells.clear();
+        for result in origin.grid_disk_fast(scratch.k) {
+            if let Some(index) = result {
+                scratch.cells.push(index.into());
+            } else {
+                scratch.cells.clear();
+                break;
+            }
+        }
+
+        // Fast version failed, fallback on the slower (but safer) approach.
+        if scratch.cells.is_empty() {
+            scratch
+                .cells
+                .extend(origin.grid_disk_safe(scratch.k).map(H3Index::from));
+        }
+
+        Ok(convert::checked_int(scratch.cells.len())?)
+    }
+
+    delegate_inner!(inner(scratch, origin), count)
+}
+
+/// Produces cells within grid distance k of the origin cell, as a spiral.
+///
+/// The output starts with the origin, followed by the ring at distance 1
+/// walked around the origin, then the ring at distance 2, and so on: cells are
+/// sorted by increasing distance, and the traversal is deterministic.
+///
+/// Pentagon distortion can leave gaps in the disk: the output is then shorter
+/// than maxGridDiskSize(k), and the cells of a ring crossing the distortion
+/// area are in no particular order (but still between the previous and the
+/// next rings).
+///
+/// @param origin Origin location.
+/// @param k k >= 0
+/// @param out Array which must be of size maxGridDiskSize(k)
+/// @param count Set to the number of cells written in `out`.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxGridDiskSize(k)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn gridDiskSpiral(
+    origin: H3Index,
+    k: c_int,
+    out: *mut H3Index,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        origin: H3Index,
+        k: c_int,
+        out: *mut H3Index,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        out[0] = origin.into();
+        let mut count = 1;
+        for ring in 1..=k {
+            let cells = origin
+                .grid_ring_fast(ring)
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_else(|| {
+                    // Fast walk failed, fallback on the safe ring.
+                    origin
+                        .grid_disk_distances_safe(ring)
+                        .filter_map(|(cell, dist)| {
+                            (dist == ring).then_some(cell)
+                        })
+                        .collect()
+                });
+            for cell_index in cells {
+                out[count] = cell_index.into();
+                count += 1;
+            }
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(origin, k, out), count)
+}
+
 /// gridDiskUnsafe produces indexes within k distance of the origin index.
 /// Output behavior is undefined when one of the indexes returned by this
 /// function is a pentagon or is in the pentagon distortion area.
@@ -294,7 +708,10 @@ pub unsafe extern "C" fn gridDiskUnsafe(
 
     match inner(origin, k) {
         Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
+            let len = match convert::checked_int(len) {
+                Ok(len) => len,
+                Err(err) => return err.into(),
+            };
             let slice = std::slice::from_raw_parts_mut(out, len);
             for (i, item) in iter.enumerate() {
                 if let Some(cell_index) = item {
@@ -332,33 +749,96 @@ pub unsafe extern "C" fn gridDisksUnsafe(
     k: c_int,
     out: *mut H3Index,
 ) -> H3Error {
-    unsafe fn inner(
+    gridDisksUnsafeWithOffsets(h3Set, length, k, out, std::ptr::null_mut())
+}
+
+/// Same as gridDisksUnsafe, but also reports where each origin's block starts
+/// in the output array.
+///
+/// @param h3Set A pointer to an array of H3Indexes
+/// @param length The total number of H3Indexes in h3Set
+/// @param k The number of rings to generate
+/// @param out A pointer to the output memory to dump the new set of H3Indexes to
+///            The memory block should be equal to maxGridDiskSize(k) * length
+/// @param offsets NULL or an array of size length + 1: `offsets[i]` is the
+///                start index in `out` of the block of `h3Set[i]`, and the
+///                last entry is the total number of cells written.
+/// @return 0 if no pentagon is encountered. Cannot trust output otherwise.
+///         E_FAILED if `offsets` is given and the output size doesn't fit in
+///         an int.
+///
+/// # Safety
+///
+/// - `h3Set` must points to an array of at least `length` elements.
+/// - `out` must points to an array of at least `length * maxGridDiskSize(k)`
+///   elements.
+/// - `offsets` must be null or points to an array of at least `length + 1`
+///   elements.
+#[no_mangle]
+pub unsafe extern "C" fn gridDisksUnsafeWithOffsets(
+    h3Set: *mut H3Index,
+    length: c_int,
+    k: c_int,
+    out: *mut H3Index,
+    offsets: *mut c_int,
+) -> H3Error {
+    unsafe fn inner<'a>(
         h3Set: *mut H3Index,
         length: c_int,
         k: c_int,
-    ) -> Result<(u64, impl Iterator<Item = Option<CellIndex>>), H3Error> {
+        with_offsets: bool,
+    ) -> Result<(u64, &'a [CellIndex], u32), H3Error> {
         let indexes = convert::h3ptr_to_h3oslice_mut(h3Set, length)?;
         let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
-        let count = u64::try_from(indexes.len()).expect("index count overflow");
-        Ok((
-            h3o::max_grid_disk_size(k) * count,
-            CellIndex::grid_disks_fast(indexes.iter().copied(), k),
-        ))
+        let count = convert::checked_int(indexes.len())?;
+        let len = h3o::max_grid_disk_size(k)
+            .checked_mul(count)
+            .ok_or(H3ErrorCodes::EFailed)?;
+        // Make sure that every offset is representable.
+        if with_offsets {
+            convert::checked_int::<_, c_int>(len)?;
+        }
+        Ok((len, indexes, k))
     }
 
     if length == 0 {
+        if !offsets.is_null() {
+            *offsets = 0;
+        }
         return H3ErrorCodes::ESuccess.into();
     }
 
-    match inner(h3Set, length, k) {
-        Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
+    match inner(h3Set, length, k, !offsets.is_null()) {
+        Ok((len, indexes, k)) => {
+            let len = match convert::checked_int(len) {
+                Ok(len) => len,
+                Err(err) => return err.into(),
+            };
             let slice = std::slice::from_raw_parts_mut(out, len);
-            for (i, item) in iter.enumerate() {
-                if let Some(cell_index) = item {
-                    slice[i] = cell_index.into();
-                } else {
-                    return H3ErrorCodes::EPentagon.into();
+            let mut offsets = (!offsets.is_null()).then(|| {
+                std::slice::from_raw_parts_mut(offsets, indexes.len() + 1)
+            });
+            let mut count = 0;
+            for (i, index) in indexes.iter().enumerate() {
+                if let Some(ref mut offsets) = offsets {
+                    match convert::checked_int(count) {
+                        Ok(offset) => offsets[i] = offset,
+                        Err(err) => return err.into(),
+                    }
+                }
+                for item in index.grid_disk_fast(k) {
+                    if let Some(cell_index) = item {
+                        slice[count] = cell_index.into();
+                        count += 1;
+                    } else {
+                        return H3ErrorCodes::EPentagon.into();
+                    }
+                }
+            }
+            if let Some(offsets) = offsets {
+                match convert::checked_int(count) {
+                    Ok(offset) => offsets[indexes.len()] = offset,
+                    Err(err) => return err.into(),
                 }
             }
             H3ErrorCodes::ESuccess.into()
@@ -393,6 +873,219 @@ pub extern "C" fn gridDistance(
     delegate_inner!(inner(origin, h3), distance)
 }
 
+/// Produces the pairwise grid distances of a set of cells.
+///
+/// `out` is filled as a `n * n` row-major matrix, where `out[i * n + j]` is the
+/// grid distance between `cells[i]` and `cells[j]`, or -1 if it cannot be
+/// computed (see gridDistance). The matrix is symmetric, with a zero diagonal.
+///
+/// @param cells Set of cells
+/// @param n     Number of cells in `cells`
+/// @param out   Output matrix
+/// @return      E_DOMAIN if `n` is negative, E_CELL_INVALID if any of the
+///              cells is invalid.
+///
+/// # Safety
+///
+/// `cells` must points to an array of at least `n` elements, and `out` to an
+/// array of at least `n * n` elements.
+#[no_mangle]
+pub unsafe extern "C" fn gridDistanceMatrix(
+    cells: *const H3Index,
+    n: i64,
+    out: *mut i64,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        out: *mut i64,
+    ) -> Result<(), H3Error> {
+        if n < 0 {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        if n == 0 {
+            return Ok(());
+        }
+        let cells = convert::h3ptr_to_h3oslice(cells, n)?;
+        let len = cells
+            .len()
+            .checked_mul(cells.len())
+            .ok_or(H3ErrorCodes::EMemoryBounds)?;
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (i, &origin) in cells.iter().enumerate() {
+            out[i * cells.len() + i] = 0;
+            for (j, &target) in cells.iter().enumerate().skip(i + 1) {
+                let distance =
+                    origin.grid_distance(target).map_or(-1, i64::from);
+                out[i * cells.len() + j] = distance;
+                out[j * cells.len() + i] = distance;
+            }
+        }
+        Ok(())
+    }
+
+    inner(cells, n, out)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Produces the grid distance between two indexes, if it's at most `maxK`.
+///
+/// Unlike gridDistance, this never fails because the indexes are too far apart
+/// or on opposite sides of a pentagon: when the local IJ distance cannot be
+/// computed, the search falls back on exploring at most `maxK` rings around
+/// the origin.
+///
+/// @param origin Index to find the distance from.
+/// @param target Index to find the distance to.
+/// @param maxK   Maximum distance to look for, must be >= 0.
+/// @param out    Set to the distance, or -1 if the target isn't within `maxK`
+///               rings of the origin.
+#[no_mangle]
+pub extern "C" fn gridDistanceBounded(
+    origin: H3Index,
+    target: H3Index,
+    maxK: c_int,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(
+        origin: H3Index,
+        target: H3Index,
+        maxK: c_int,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let target = CellIndex::try_from(target)?;
+        let k = u32::try_from(maxK).map_err(|_| H3ErrorCodes::EDomain)?;
+        if origin.resolution() != target.resolution() {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
+
+        let distance = if let Ok(distance) = origin.grid_distance(target) {
+            Some(distance).filter(|&distance| distance <= maxK)
+        } else {
+            origin
+                .grid_disk_distances_safe(k)
+                .find_map(|(cell, distance)| {
+                    (cell == target).then_some(distance)
+                })
+                .map(convert::checked_int)
+                .transpose()?
+        };
+
+        Ok(distance.unwrap_or(-1))
+    }
+
+    delegate_inner!(inner(origin, target, maxK), out)
+}
+
+/// Produces the grid distance between two indexes, or an estimate of it.
+///
+/// Unlike gridDistance, this never fails because the indexes are too far apart
+/// or on opposite sides of a pentagon: when the exact distance cannot be
+/// computed, it is estimated from the great-circle distance between the cell
+/// centers, divided by the average distance between neighboring cell centers
+/// (i.e. `sqrt(3)` times the average hexagon edge length) at the resolution.
+///
+/// @param origin  Index to find the distance from.
+/// @param target  Index to find the distance to.
+/// @param out     Set to the (exact or approximate) distance.
+/// @param isExact Set to 1 if the distance is exact, 0 if it's an estimate
+///                (optional).
+#[no_mangle]
+pub extern "C" fn gridDistanceApprox(
+    origin: H3Index,
+    target: H3Index,
+    out: Option<&mut i64>,
+    isExact: Option<&mut c_int>,
+) -> H3Error {
+    fn inner(origin: H3Index, target: H3Index) -> Result<(i64, bool), H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let target = CellIndex::try_from(target)?;
+        if origin.resolution() != target.resolution() {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
+
+        if let Ok(distance) = origin.grid_distance(target) {
+            return Ok((distance.into(), true));
+        }
+        let spacing = 3_f64.sqrt() * origin.resolution().edge_length_m();
+        let distance = h3o::LatLng::from(origin)
+            .distance_m(h3o::LatLng::from(target))
+            / spacing;
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "at most ~2.6e7, for antipodal cells at res 15"
+        )]
+        let distance = distance.round() as i64;
+
+        Ok((distance, false))
+    }
+
+    match inner(origin, target) {
+        Ok((distance, exact)) => {
+            if let Some(out) = out {
+                *out = distance;
+            }
+            if let Some(isExact) = isExact {
+                *isExact = exact.into();
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// Produces the frontier of a flood-fill from the origin cell: the cells at
+/// grid distance k that aren't within distance k-1.
+///
+/// The frontier is computed as the difference of the safe disks k and k-1, so
+/// it never fails on pentagon distortion (unlike gridRingUnsafe). This makes
+/// it suited to breadth-first traversals.
+///
+/// @param origin origin cell
+/// @param k      k >= 0
+/// @param out    Output array for the frontier cells
+/// @param count  Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxGridDiskSize(k)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn gridFrontier(
+    origin: H3Index,
+    k: c_int,
+    out: *mut H3Index,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        origin: H3Index,
+        k: c_int,
+        out: *mut H3Index,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+        let inside = k
+            .checked_sub(1)
+            .map(|k| origin.grid_disk_safe(k).collect::<HashSet<_>>())
+            .unwrap_or_default();
+        let cells = origin
+            .grid_disk_safe(k)
+            .filter(|cell| !inside.contains(cell));
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut count = 0;
+        for cell_index in cells {
+            out[count] = cell_index.into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(origin, k, out), count)
+}
+
 /// Given two H3 indexes, return the line of indexes between them (inclusive).
 ///
 /// This function may fail to find the line between two indexes, for
@@ -440,7 +1133,10 @@ pub unsafe extern "C" fn gridPathCells(
 
     match inner(start, end) {
         Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
+            let len = match convert::checked_int(len) {
+                Ok(len) => len,
+                Err(err) => return err.into(),
+            };
             let slice = std::slice::from_raw_parts_mut(out, len);
             for (i, item) in iter.enumerate() {
                 match item {
@@ -454,6 +1150,59 @@ pub unsafe extern "C" fn gridPathCells(
     }
 }
 
+/// Same as gridPathCells, but accepts endpoints at different resolutions.
+///
+/// The finer endpoint is first replaced by its parent at the resolution of the
+/// coarser one: the line is drawn at the coarser resolution.
+///
+/// When `out` is null, only the number of cells in the line is reported, which
+/// can be used for allocating memory.
+///
+/// @param start Start index of the line
+/// @param end End index of the line
+/// @param out Output array, or null
+/// @param count Set to the number of cells in the line
+/// @return 0 on success, or another value on failure.
+///
+/// # Safety
+///
+/// `out` must be null or points to an array of at least `count` elements.
+#[no_mangle]
+pub unsafe extern "C" fn gridPathCellsBetweenRes(
+    start: H3Index,
+    end: H3Index,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        start: H3Index,
+        end: H3Index,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let start = CellIndex::try_from(start)?;
+        let end = CellIndex::try_from(end)?;
+
+        // Roll the finer endpoint up to the coarser resolution.
+        let resolution = start.resolution().min(end.resolution());
+        let start = start.parent(resolution).ok_or(H3ErrorCodes::EFailed)?;
+        let end = end.parent(resolution).ok_or(H3ErrorCodes::EFailed)?;
+
+        let len = start.grid_path_cells_size(end)?;
+        if out.is_null() {
+            return Ok(len.into());
+        }
+
+        let out =
+            std::slice::from_raw_parts_mut(out, convert::checked_int(len)?);
+        for (i, item) in start.grid_path_cells(end)?.enumerate() {
+            out[i] = item?.into();
+        }
+        Ok(len.into())
+    }
+
+    delegate_inner!(inner(start, end, out), count)
+}
+
 /// Number of indexes in a line from the start index to the end index,
 /// to be used for allocating memory. Returns a negative number if the
 /// line cannot be computed.
@@ -478,6 +1227,52 @@ pub extern "C" fn gridPathCellsSize(
     delegate_inner!(inner(start, end), size)
 }
 
+/// Returns the "hollow" ring of cells at exactly grid distance k from the
+/// origin cell. In particular, k=0 returns just the origin cell.
+///
+/// Unlike gridRingUnsafe, this function doesn't fail when a pentagon is
+/// encountered: the ring is then shorter than `6 * k`, and its actual size is
+/// reported in `count`.
+///
+/// @param origin Origin location.
+/// @param k k >= 0
+/// @param out Array which must be of size maxGridDiskSize(k)
+/// @param count Set to the number of cells written in `out`.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `maxGridDiskSize(k)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn gridRing(
+    origin: H3Index,
+    k: c_int,
+    out: *mut H3Index,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        origin: H3Index,
+        k: c_int,
+        out: *mut H3Index,
+    ) -> Result<c_int, H3Error> {
+        let origin = CellIndex::try_from(origin)?;
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        let len = convert::checked_int(h3o::max_grid_disk_size(k))?;
+        let cells = origin
+            .grid_disk_distances_safe(k)
+            .filter_map(|(cell, dist)| (dist == k).then_some(cell));
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut count = 0;
+        for cell_index in cells {
+            out[count] = cell_index.into();
+            count += 1;
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(origin, k, out), count)
+}
+
 /// Returns the "hollow" ring of hexagons at exactly grid distance k from
 /// the origin hexagon. In particular, k=0 returns just the origin hexagon.
 ///
@@ -512,7 +1307,10 @@ pub unsafe extern "C" fn gridRingUnsafe(
 
     match inner(origin, k) {
         Ok((len, iter)) => {
-            let len = usize::try_from(len).expect("overflow");
+            let len = match convert::checked_int(len) {
+                Ok(len) => len,
+                Err(err) => return err.into(),
+            };
             let slice = std::slice::from_raw_parts_mut(out, len);
             for (i, item) in iter.enumerate() {
                 if let Some(cell_index) = item {
@@ -530,17 +1328,27 @@ pub unsafe extern "C" fn gridRingUnsafe(
 /// Maximum number of cells that result from the gridDisk algorithm with the
 /// given k. Formula source and proof: `<https://oeis.org/A003215>`
 ///
+/// The size is capped to the number of cells at resolution 15, since no disk
+/// can contain more cells than the whole grid.
+///
 /// @param   k   k value, k >= 0.
 /// @param out   size in indexes
+/// @return      E_DOMAIN if k is negative.
 #[no_mangle]
 pub extern "C" fn maxGridDiskSize(k: c_int, out: Option<&mut i64>) -> H3Error {
     fn inner(k: c_int) -> Result<i64, H3Error> {
-        Ok(u32::try_from(k)
-            .map_err(|_| H3ErrorCodes::EDomain)
-            .map(h3o::max_grid_disk_size)?
-            .try_into()
-            .expect("grid disk size overflow"))
+        let k = u32::try_from(k).map_err(|_| H3ErrorCodes::EDomain)?;
+        Ok(convert::checked_int(h3o::max_grid_disk_size(k))?)
     }
 
     delegate_inner!(inner(k), out)
 }
+
+// -----------------------------------------------------------------------------
+
+/// Opaque, reusable gridDisk buffer, see diskScratchNew.
+#[derive(Debug)]
+pub struct H3DiskScratch {
+    k: u32,
+    cells: Vec<H3Index>,
+}
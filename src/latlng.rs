@@ -1,5 +1,6 @@
 use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index};
-use std::ffi::c_int;
+use h3o::{CellIndex, Resolution};
+use std::{collections::HashMap, f64::consts::FRAC_PI_2, ffi::c_int};
 
 /// Latitude/longitude in radians.
 #[repr(C)]
@@ -46,6 +47,155 @@ impl TryFrom<LatLng> for h3o::LatLng {
     }
 }
 
+/// Finds the finest cell that contains all the given points.
+///
+/// Resolutions are tried from the finest to the coarsest, and the first one at
+/// which every point maps to the same cell is returned. For points spanning a
+/// large area, this may be resolution 0.
+///
+/// @param points Set of points, in degrees
+/// @param n      Number of points, must be > 0
+/// @param out    Set to the enclosing cell
+/// @param res    Set to the resolution of the enclosing cell (optional)
+/// @return       E_FAILED if no single cell contains all the points (they
+///               span several base cells) or for a non-finite point,
+///               E_LATLNG_DOMAIN for a latitude beyond the poles.
+///
+/// # Safety
+///
+/// `points` must points to an array of at least `n` elements.
+#[no_mangle]
+pub unsafe extern "C" fn pointsToEnclosingCell(
+    points: *const LatLng,
+    n: i64,
+    out: Option<&mut H3Index>,
+    res: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        points: *const LatLng,
+        n: i64,
+    ) -> Result<CellIndex, H3Error> {
+        let len = usize::try_from(n)
+            .ok()
+            .filter(|&len| len > 0)
+            .ok_or(H3ErrorCodes::EDomain)?;
+        if points.is_null() {
+            return Err(H3ErrorCodes::EFailed.into());
+        }
+        let points = std::slice::from_raw_parts(points, len)
+            .iter()
+            .map(convert::latlng_from_degrees)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Resolution::range(Resolution::Zero, Resolution::Fifteen)
+            .rev()
+            .find_map(|resolution| {
+                let cell = points[0].to_cell(resolution);
+                points[1..]
+                    .iter()
+                    .all(|point| point.to_cell(resolution) == cell)
+                    .then_some(cell)
+            })
+            .ok_or_else(|| H3ErrorCodes::EFailed.into())
+    }
+
+    match inner(points, n) {
+        Ok(cell) => {
+            if let Some(out) = out {
+                *out = cell.into();
+            }
+            if let Some(res) = res {
+                *res = u8::from(cell.resolution()).into();
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// Counts how many points fall in each cell, at a given resolution.
+///
+/// Each point is encoded to its containing cell, and the points are aggregated
+/// into one bin per distinct cell: `cellsOut[i]` contains `countsOut[i]`
+/// points. Bins are written in order of first appearance. Invalid points (off
+/// the globe or non-finite) are skipped.
+///
+/// @param points    Set of points, in degrees
+/// @param n         Number of points
+/// @param res       Resolution of the bins
+/// @param cellsOut  Output array for the cells of the bins
+/// @param countsOut Output array for the number of points of the bins
+/// @param cap       Capacity of `cellsOut` and `countsOut`
+/// @param numBins   Set to the number of bins
+/// @return          E_DOMAIN if `n` or `cap` is negative, E_MEMORY_BOUNDS if
+///                  there are more than `cap` bins.
+///
+/// # Safety
+///
+/// `points` must points to an array of at least `n` elements, `cellsOut` and
+/// `countsOut` to arrays of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn binPointsToCells(
+    points: *const LatLng,
+    n: i64,
+    res: c_int,
+    cellsOut: *mut H3Index,
+    countsOut: *mut i64,
+    cap: i64,
+    numBins: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        points: *const LatLng,
+        n: i64,
+        res: c_int,
+        cellsOut: *mut H3Index,
+        countsOut: *mut i64,
+        cap: i64,
+    ) -> Result<i64, H3Error> {
+        let len = usize::try_from(n).map_err(|_| H3ErrorCodes::EDomain)?;
+        let cap = usize::try_from(cap).map_err(|_| H3ErrorCodes::EDomain)?;
+        let resolution = convert::h3res_to_resolution(res)?;
+        if len == 0 {
+            return Ok(0);
+        }
+        if points.is_null() {
+            return Err(H3ErrorCodes::EFailed.into());
+        }
+
+        let mut bins = Vec::<(CellIndex, i64)>::new();
+        let mut positions = HashMap::new();
+        for point in std::slice::from_raw_parts(points, len) {
+            let Ok(ll) = convert::latlng_from_degrees(point) else {
+                continue;
+            };
+            let cell = ll.to_cell(resolution);
+            let position = *positions.entry(cell).or_insert_with(|| {
+                bins.push((cell, 0));
+                bins.len() - 1
+            });
+            bins[position].1 += 1;
+        }
+        if bins.len() > cap {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        if bins.is_empty() {
+            return Ok(0);
+        }
+
+        let cells = std::slice::from_raw_parts_mut(cellsOut, bins.len());
+        let counts = std::slice::from_raw_parts_mut(countsOut, bins.len());
+        for ((cell, count), &(bin_cell, bin_count)) in
+            cells.iter_mut().zip(counts.iter_mut()).zip(&bins)
+        {
+            *cell = bin_cell.into();
+            *count = bin_count;
+        }
+        Ok(convert::checked_int(bins.len())?)
+    }
+
+    delegate_inner!(inner(points, n, res, cellsOut, countsOut, cap), numBins)
+}
+
 // -----------------------------------------------------------------------------
 
 /// The great circle distance in kilometers between two spherical coordinates.
@@ -109,6 +259,43 @@ pub extern "C" fn greatCircleDistanceRads(
         .unwrap_or(f64::NAN)
 }
 
+/// Returns the latitude of a coordinate, in degrees.
+///
+/// @param g The coordinate.
+/// @return  The latitude in degrees, or NaN if `g` is NULL.
+#[no_mangle]
+pub extern "C" fn latLngGetLatDegs(g: Option<&LatLng>) -> f64 {
+    g.map_or(f64::NAN, |ll| ll.lat.to_degrees())
+}
+
+/// Returns the longitude of a coordinate, in degrees.
+///
+/// @param g The coordinate.
+/// @return  The longitude in degrees, or NaN if `g` is NULL.
+#[no_mangle]
+pub extern "C" fn latLngGetLngDegs(g: Option<&LatLng>) -> f64 {
+    g.map_or(f64::NAN, |ll| ll.lng.to_degrees())
+}
+
+/// Sets a coordinate from a latitude and a longitude in degrees.
+///
+/// The coordinate is stored in radians.
+///
+/// @param g   The coordinate to set.
+/// @param lat The latitude in degrees.
+/// @param lng The longitude in degrees.
+#[no_mangle]
+pub const extern "C" fn latLngSetDegs(
+    g: Option<&mut LatLng>,
+    lat: f64,
+    lng: f64,
+) {
+    if let Some(ll) = g {
+        ll.lat = lat.to_radians();
+        ll.lng = lng.to_radians();
+    }
+}
+
 /// Encodes a coordinate on the sphere to the H3 index of the containing cell at
 /// the specified resolution.
 ///
@@ -132,3 +319,158 @@ pub extern "C" fn latLngToCell(
 
     delegate_inner!(inner(*g.expect("null pointer"), res), out)
 }
+
+/// Snaps a coordinate, in degrees, to the center of its containing cell.
+///
+/// @param in   The coordinate to snap, in degrees.
+/// @param res  The resolution of the containing cell.
+/// @param out  Set to the center of the containing cell, in degrees.
+/// @param cell Set to the containing cell.
+/// @return     E_SUCCESS on success, E_RES_DOMAIN for an invalid resolution,
+///             E_LATLNG_DOMAIN for a coordinate off the globe or E_FAILED for
+///             a non-finite one.
+#[no_mangle]
+pub extern "C" fn snapLatLngToCellCenterDegs(
+    r#in: Option<&LatLng>,
+    res: c_int,
+    out: Option<&mut LatLng>,
+    cell: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(
+        ll: Option<&LatLng>,
+        res: c_int,
+    ) -> Result<(LatLng, H3Index), H3Error> {
+        let ll = ll.ok_or(H3ErrorCodes::EFailed)?;
+        let resolution = convert::h3res_to_resolution(res)?;
+        let index = convert::latlng_from_degrees(ll)?.to_cell(resolution);
+        let center = h3o::LatLng::from(index);
+
+        Ok((
+            LatLng {
+                lat: center.lat(),
+                lng: center.lng(),
+            },
+            index.into(),
+        ))
+    }
+
+    match inner(r#in, res) {
+        Ok((center, index)) => {
+            if let Some(out) = out {
+                *out = center;
+            }
+            if let Some(cell) = cell {
+                *cell = index;
+            }
+            H3ErrorCodes::ESuccess.into()
+        }
+        Err(err) => err,
+    }
+}
+
+/// Produces the cells along the great-circle arc between two cell centers.
+///
+/// Unlike gridPathCells, which follows the grid, this path is geographically
+/// straight: the arc is sampled at intervals of roughly the average edge
+/// length at `res`, and each sample is encoded to a cell at `res`
+/// (consecutive duplicates are skipped).
+///
+/// @param start Cell to start the path from
+/// @param end   Cell to end the path at
+/// @param res   Resolution of the path cells
+/// @param out   Output array for the path cells
+/// @param cap   Capacity of `out`
+/// @param count Set to the number of cells written in `out`
+/// @return      E_DOMAIN if the cell centers are antipodal (the arc is
+///              undefined), E_MEMORY_BOUNDS if `out` is too small.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn geoPathCells(
+    start: H3Index,
+    end: H3Index,
+    res: c_int,
+    out: *mut H3Index,
+    cap: c_int,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        start: H3Index,
+        end: H3Index,
+        res: c_int,
+        out: *mut H3Index,
+        cap: c_int,
+    ) -> Result<c_int, H3Error> {
+        let start = h3o::LatLng::from(CellIndex::try_from(start)?);
+        let end = h3o::LatLng::from(CellIndex::try_from(end)?);
+        let resolution = convert::h3res_to_resolution(res)?;
+        let cap = usize::try_from(cap).map_err(|_| H3ErrorCodes::EDomain)?;
+
+        let angle = start.distance_rads(end);
+        if angle.sin().abs() < f64::EPSILON && angle > FRAC_PI_2 {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        let length = start.distance_m(end) / resolution.edge_length_m();
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "at most ~4e7 steps, for antipodal cells at res 15"
+        )]
+        let steps = length.ceil().max(1.) as u32;
+
+        let (a, b) = (to_vec3(start), to_vec3(end));
+        let out = if cap == 0 {
+            &mut []
+        } else {
+            std::slice::from_raw_parts_mut(out, cap)
+        };
+        let mut last = None;
+        let mut count = 0;
+        for step in 0..=steps {
+            let t = f64::from(step) / f64::from(steps);
+            let point = slerp(a, b, angle, t);
+            let cell = from_vec3(point)?.to_cell(resolution);
+            if last != Some(cell) {
+                *out.get_mut(count).ok_or(H3ErrorCodes::EMemoryBounds)? =
+                    cell.into();
+                last = Some(cell);
+                count += 1;
+            }
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(start, end, res, out, cap), count)
+}
+
+// -----------------------------------------------------------------------------
+
+/// Converts a coordinate to a point on the unit sphere.
+pub fn to_vec3(ll: h3o::LatLng) -> [f64; 3] {
+    let (lat, lng) = (ll.lat_radians(), ll.lng_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+/// Converts a point on the unit sphere back to a coordinate.
+pub fn from_vec3([x, y, z]: [f64; 3]) -> Result<h3o::LatLng, H3Error> {
+    let lat = z.atan2(x.hypot(y));
+    let lng = y.atan2(x);
+    Ok(h3o::LatLng::from_radians(lat, lng)?)
+}
+
+/// Spherical linear interpolation between `a` and `b`, `angle` apart.
+pub fn slerp(a: [f64; 3], b: [f64; 3], angle: f64, t: f64) -> [f64; 3] {
+    // Points are too close for the interpolation to be stable.
+    if angle.sin().abs() < f64::EPSILON {
+        return a;
+    }
+    let wa = ((1. - t) * angle).sin() / angle.sin();
+    let wb = (t * angle).sin() / angle.sin();
+    [
+        wa.mul_add(a[0], wb * b[0]),
+        wa.mul_add(a[1], wb * b[1]),
+        wa.mul_add(a[2], wb * b[2]),
+    ]
+}
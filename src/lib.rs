@@ -118,6 +118,8 @@
 use h3o::{CellIndex, DirectedEdgeIndex, VertexIndex};
 use std::ffi::{c_char, CStr};
 
+/// cbindgen:ignore
+mod bits;
 mod boundary;
 mod cell;
 mod compact;
@@ -128,7 +130,11 @@ mod geom;
 mod grid;
 mod latlng;
 mod localij;
+mod polyfill;
 mod resolution;
+mod sampling;
+mod serialization;
+mod setops;
 mod vertex;
 
 // TODO: find why cbindgen can't generate #define for those...
@@ -141,41 +147,94 @@ pub const H3O_VERSION_MINOR: u8 = 3;
 pub const H3O_VERSION_PATCH: u8 = 0;
 
 pub use boundary::{CellBoundary, MAX_CELL_BNDRY_VERTS};
+#[cfg(feature = "rayon")]
+pub use cell::cellsToChildrenParallel;
 pub use cell::{
-    cellAreaKm2, cellAreaM2, cellAreaRads2, cellToBoundary, cellToCenterChild,
-    cellToChildPos, cellToChildren, cellToChildrenSize, cellToLatLng,
-    cellToParent, childPosToCell, getBaseCellNumber, getIcosahedronFaces,
-    getResolution, isPentagon, isValidCell, maxFaceCount,
+    cellAntipode, cellAreaKm2, cellAreaM2, cellAreaRads2,
+    cellAtBearingDistanceKm, cellBoundaryHasDistortion, cellContainsLatLng,
+    cellDecode, cellInfo, cellIntersectsSegment, cellPerimeterKm,
+    cellPerimeterM, cellPerimeterRads, cellShapeMetrics, cellToBoundary,
+    cellToBoundaryDensified, cellToBoundaryProjected, cellToBoundaryWinding,
+    cellToCellBearingDegs, cellToCenterChild, cellToChildPos, cellToChildren,
+    cellToChildrenOrdered, cellToChildrenSize, cellToChildrenUnchecked,
+    cellToDigits, cellToFan, cellToHexagonChildren, cellToLatLng,
+    cellToLatLngAltDegs, cellToParent, cellToPentagonChildren, cellsAreaM2,
+    cellsToBoundariesFlat, cellsToParents, cellsToParentsUnique,
+    childPosToCell, digitsToCell, faceCenterLatLng, getBaseCellNumber,
+    getIcosahedronFaces, getResolution, isPentagon, isValidCell, maxFaceCount,
+    repairCellIndex, CellDecoded, CellInfo, H3Projection, LatLngAlt,
+    ShapeMetrics,
+};
+pub use compact::{
+    cellSetResolutions, compactCells, compactCellsWithGroups,
+    compactedCellsAreaM2, compactedSetContains, uncompactCells,
+    uncompactCellsSize,
 };
-pub use compact::{compactCells, uncompactCells, uncompactCellsSize};
 pub use directed_edge::{
-    areNeighborCells, cellsToDirectedEdge, directedEdgeToBoundary,
-    directedEdgeToCells, edgeLengthKm, edgeLengthM, edgeLengthRads,
-    getDirectedEdgeDestination, getDirectedEdgeOrigin, isValidDirectedEdge,
-    originToDirectedEdges,
+    areNeighborCells, areWithinGridDistance, cellAdjacencyType,
+    cellToNeighborEdges, cellsToDirectedEdge, directedEdgeMidpointDegs,
+    directedEdgeToBoundary, directedEdgeToCells, edgeLengthKm, edgeLengthM,
+    edgeLengthRads, getDirectedEdgeDestination, getDirectedEdgeOrigin,
+    isValidDirectedEdge, originToDirectedEdges,
+};
+pub use error::{
+    h3ErrorCode, h3ErrorIsSuccess, h3ErrorToExitCode, H3Error, H3ErrorCodes,
 };
-pub use error::{H3Error, H3ErrorCodes};
 pub use geom::{
-    cellsToLinkedMultiPolygon, destroyLinkedMultiPolygon,
-    maxPolygonToCellsSize, polygonToCells, GeoLoop, GeoMultiPolygon,
-    GeoPolygon, LinkedGeoLoop, LinkedGeoPolygon, LinkedLatLng,
+    bboxToCells, cellPolygonOverlapFraction, cellToGeoLoop,
+    cellToLinkedGeoLoop, cellsCentroid, cellsOutlinePerimeterM,
+    cellsToLinkedMultiPolygon, cellsToMesh, destroyLinkedGeoLoop,
+    destroyLinkedMultiPolygon, linkedMultiPolygonCounts,
+    linkedMultiPolygonToFlat, maxBboxToCellsSize, maxPolygonToCellsSize,
+    normalizeGeoPolygon, polyfillConfigFree, polyfillConfigNew, polygonToCells,
+    polygonToCellsClipped, polygonToCellsCount, polygonToCellsCovering,
+    polygonToCellsProgress, polygonToCellsThreshold, polygonToCellsWithConfig,
+    resolutionForCellBudget, GeoLoop, GeoMultiPolygon, GeoPolygon,
+    H3PolyfillConfig, LinkedGeoLoop, LinkedGeoPolygon, LinkedLatLng,
+};
+#[cfg(feature = "geojson")]
+pub use geom::{
+    cellsToSimplifiedPolygon, geoJsonToCells, maxGeoJsonToCellsSize,
 };
 pub use grid::{
+    cellToKRingParents, diskScratchCells, diskScratchFree, diskScratchNew,
     gridDisk, gridDiskDistances, gridDiskDistancesSafe,
-    gridDiskDistancesUnsafe, gridDiskUnsafe, gridDisksUnsafe, gridDistance,
-    gridPathCells, gridPathCellsSize, gridRingUnsafe, maxGridDiskSize,
+    gridDiskDistancesSafeWithCount, gridDiskDistancesSorted,
+    gridDiskDistancesUnsafe, gridDiskGeoDistancesKm, gridDiskInto,
+    gridDiskSpiral, gridDiskUnsafe, gridDisksUnsafe,
+    gridDisksUnsafeWithOffsets, gridDistance, gridDistanceApprox,
+    gridDistanceBounded, gridDistanceMatrix, gridFrontier, gridPathCells,
+    gridPathCellsBetweenRes, gridPathCellsSize, gridRing, gridRingUnsafe,
+    kForRadiusKm, maxGridDiskSize, H3DiskScratch,
 };
 pub use latlng::{
-    greatCircleDistanceKm, greatCircleDistanceM, greatCircleDistanceRads,
-    latLngToCell, LatLng,
+    binPointsToCells, geoPathCells, greatCircleDistanceKm,
+    greatCircleDistanceM, greatCircleDistanceRads, latLngGetLatDegs,
+    latLngGetLngDegs, latLngSetDegs, latLngToCell, pointsToEnclosingCell,
+    snapLatLngToCellCenterDegs, LatLng,
+};
+pub use localij::{
+    cellToLocalIj, localIjRectToCells, localIjToCell, localIjToCellStrict,
+    CoordIJ,
 };
-pub use localij::{cellToLocalIj, localIjToCell, CoordIJ};
 pub use resolution::{
-    getHexagonAreaAvgKm2, getHexagonAreaAvgM2, getHexagonEdgeLengthAvgKm,
-    getHexagonEdgeLengthAvgM, getNumCells, getPentagons, getRes0Cells,
-    isResClassIII, pentagonCount, res0CellCount,
+    getCumulativeNumCells, getHexagonAreaAvgKm2, getHexagonAreaAvgM2,
+    getHexagonEdgeLengthAvgKm, getHexagonEdgeLengthAvgM, getNumCells,
+    getPentagonBaseCells, getPentagons, getRes0Cells, isResClassIII,
+    pentagonCount, res0CellCount, resolutionForAreaM2,
+    resolutionForEdgeLengthM,
+};
+pub use sampling::sampleCells;
+pub use serialization::{cellsToChildBitset, childBitsetToCells};
+pub use setops::{
+    cellSetBoundaryCells, cellSetContains, cellSetDifference,
+    cellSetIntersection, cellSetUnion, cellSetsEqual,
+};
+pub use vertex::{
+    canonicalVertex, cellToVertex, cellToVertexByDirection, cellToVertexes,
+    cellToVertexesWithCoords, isValidVertex, vertexToLatLng, vertexToOwnerCell,
+    H3IndexLatLng,
 };
-pub use vertex::{cellToVertex, cellToVertexes, isValidVertex, vertexToLatLng};
 
 // -----------------------------------------------------------------------------
 
@@ -189,6 +248,12 @@ pub type H3Index = u64;
 /// floating point.
 pub const H3_NULL: H3Index = 0;
 
+/// h3ToStringFmt flag: use uppercase hexadecimal digits.
+pub const H3_STRING_UPPERCASE: u32 = 1;
+
+/// h3ToStringFmt flag: prefix the representation with `0x`.
+pub const H3_STRING_PREFIX: u32 = 2;
+
 // -----------------------------------------------------------------------------
 
 /// Convert from decimal degrees to radians.
@@ -229,6 +294,111 @@ pub unsafe extern "C" fn h3ToString(
     H3ErrorCodes::ESuccess.into()
 }
 
+/// Converts an H3 index into a string representation, with formatting options.
+///
+/// Without flags, the output is the same as h3ToString.
+///
+/// @param h The H3 index to convert.
+/// @param s The string representation of the H3 index.
+/// @param sz Size of the buffer `s`
+/// @param flags Bitwise OR of `H3_STRING_UPPERCASE` and `H3_STRING_PREFIX`
+/// @return E_MEMORY_BOUNDS if the buffer is too small for the representation
+/// (and its null terminator), E_OPTION_INVALID for unknown flags.
+///
+/// # Safety
+///
+/// `s` must points to an array of at least `sz` elements.
+#[no_mangle]
+pub unsafe extern "C" fn h3ToStringFmt(
+    h: H3Index,
+    s: *mut c_char,
+    sz: usize,
+    flags: u32,
+) -> H3Error {
+    if flags & !(H3_STRING_UPPERCASE | H3_STRING_PREFIX) != 0 {
+        return H3ErrorCodes::EOptionInvalid.into();
+    }
+
+    let uppercase = flags & H3_STRING_UPPERCASE != 0;
+    let prefix = if flags & H3_STRING_PREFIX == 0 {
+        ""
+    } else {
+        "0x"
+    };
+    let string = if uppercase {
+        format!("{prefix}{h:X}")
+    } else {
+        format!("{prefix}{h:x}")
+    }
+    .into_bytes();
+    // Keep room for the null terminator.
+    if sz <= string.len() {
+        return H3ErrorCodes::EMemoryBounds.into();
+    }
+
+    let slice = std::slice::from_raw_parts_mut(s.cast::<u8>(), sz);
+    slice[..string.len()].copy_from_slice(&string);
+    slice[string.len()] = 0;
+    H3ErrorCodes::ESuccess.into()
+}
+
+/// Converts a set of H3 indexes into string representations, written into a
+/// single buffer with a fixed stride.
+///
+/// The (null-terminated) string of `hs[i]` starts at `out + i * stride`.
+/// Invalid indexes are still converted, as hexadecimal numbers.
+///
+/// @param hs The H3 indexes to convert.
+/// @param n Number of indexes in `hs`.
+/// @param out Output buffer for the string representations.
+/// @param stride Distance between two consecutive strings, at least 17.
+/// @return E_MEMORY_BOUNDS if `stride` is too small, E_DOMAIN for a negative
+/// `n`.
+///
+/// # Safety
+///
+/// - `hs` must points to an array of at least `n` elements.
+/// - `out` must points to an array of at least `n * stride` elements.
+#[no_mangle]
+pub unsafe extern "C" fn h3ToStrings(
+    hs: *const H3Index,
+    n: i64,
+    out: *mut c_char,
+    stride: usize,
+) -> H3Error {
+    unsafe fn inner(
+        hs: *const H3Index,
+        n: i64,
+        out: *mut c_char,
+        stride: usize,
+    ) -> Result<(), H3Error> {
+        let len = usize::try_from(n).map_err(|_| H3ErrorCodes::EDomain)?;
+        // An unsigned 64 bit integer will be expressed in at most
+        // 16 digits plus 1 for the null terminator.
+        if stride < 17 {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        if len == 0 {
+            return Ok(());
+        }
+        let size =
+            len.checked_mul(stride).ok_or(H3ErrorCodes::EMemoryBounds)?;
+
+        let hs = std::slice::from_raw_parts(hs, len);
+        let out = std::slice::from_raw_parts_mut(out.cast::<u8>(), size);
+        for (&h, row) in hs.iter().zip(out.chunks_exact_mut(stride)) {
+            let string = format!("{h:x}").into_bytes();
+            row[..string.len()].copy_from_slice(&string);
+            row[string.len()] = 0;
+        }
+        Ok(())
+    }
+
+    inner(hs, n, out, stride)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
 /// Convert from radians to decimal degrees.
 ///
 /// @param radians The radians.
@@ -282,3 +452,98 @@ macro_rules! delegate_inner {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use h3o::{CellIndex, DirectedEdgeIndex, Resolution, VertexIndex};
+    use proptest::{prelude::*, sample};
+
+    /// Generates valid cells, at every resolution and on every base cell.
+    fn cells() -> impl Strategy<Value = CellIndex> {
+        (0..CellIndex::base_cells().count(), 0..=15_u8, any::<u64>()).prop_map(
+            |(base_cell, resolution, position)| {
+                let resolution =
+                    Resolution::try_from(resolution).expect("valid resolution");
+                let base_cell = CellIndex::base_cells()
+                    .nth(base_cell)
+                    .expect("valid base cell");
+                let position = position % base_cell.children_count(resolution);
+                base_cell
+                    .child_at(position, resolution)
+                    .expect("valid child position")
+            },
+        )
+    }
+
+    /// Generates valid directed edges.
+    fn edges() -> impl Strategy<Value = DirectedEdgeIndex> {
+        cells().prop_flat_map(|cell| {
+            sample::select(cell.edges().collect::<Vec<_>>())
+        })
+    }
+
+    /// Generates valid vertexes.
+    fn vertexes() -> impl Strategy<Value = VertexIndex> {
+        cells().prop_flat_map(|cell| {
+            sample::select(cell.vertexes().collect::<Vec<_>>())
+        })
+    }
+
+    /// Stringifies an index into a buffer of the given size and parses it back.
+    fn round_trip(index: H3Index, size: usize) {
+        // Pre-fill with a sentinel to catch misplaced terminators.
+        let mut buf = vec![c_char::MAX; size];
+        // SAFETY: `buf` holds `size` elements.
+        let err = unsafe { h3ToString(index, buf.as_mut_ptr(), size) };
+        assert_eq!(h3ErrorCode(err), 0, "h3ToString({index:x})");
+
+        let expected = format!("{index:x}");
+        let len = expected.len();
+        assert_eq!(buf[len], 0, "null terminator of {expected}");
+        assert!(buf[..len].iter().all(|&c| c != 0), "no early terminator");
+        assert!(
+            buf[len + 1..].iter().all(|&c| c == c_char::MAX),
+            "nothing written after the terminator of {expected}"
+        );
+
+        let mut parsed = 0;
+        let err = stringToH3(buf.as_ptr(), Some(&mut parsed));
+        assert_eq!(h3ErrorCode(err), 0, "stringToH3({expected})");
+        assert_eq!(parsed, index, "round-trip of {expected}");
+    }
+
+    // Every index is round-tripped with the minimum buffer size, then with
+    // some slack.
+    proptest! {
+        #[test]
+        fn cell_string_round_trip(cell in cells(), size in 17_usize..=32) {
+            round_trip(cell.into(), 17);
+            round_trip(cell.into(), size);
+        }
+
+        #[test]
+        fn edge_string_round_trip(edge in edges(), size in 17_usize..=32) {
+            round_trip(edge.into(), 17);
+            round_trip(edge.into(), size);
+        }
+
+        #[test]
+        fn vertex_string_round_trip(
+            vertex in vertexes(),
+            size in 17_usize..=32,
+        ) {
+            round_trip(vertex.into(), 17);
+            round_trip(vertex.into(), size);
+        }
+    }
+
+    #[test]
+    fn string_buffer_too_small() {
+        let mut buf = [0; 16];
+        // SAFETY: `buf` holds 16 elements.
+        let err =
+            unsafe { h3ToString(0x85283473fffffff, buf.as_mut_ptr(), 16) };
+        assert_eq!(h3ErrorCode(err), H3ErrorCodes::EFailed as u32);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{delegate_inner, H3Error, H3ErrorCodes, H3Index};
+use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index};
 use h3o::CellIndex;
 use std::ffi::c_int;
 
@@ -98,3 +98,113 @@ pub extern "C" fn localIjToCell(
 
     delegate_inner!(inner(origin, *ij.expect("null pointer"), mode), out)
 }
+
+/// Same as localIjToCell, but also checks that the cell round-trips to the
+/// input ij coordinates through cellToLocalIj.
+///
+/// Due to pentagonal distortion, localIjToCell may produce an index whose
+/// coordinates, relative to the same origin, aren't the input ones (or cannot
+/// be computed at all): such cases are reported as failures here.
+///
+/// @param origin An anchoring index for the ij coordinate system.
+/// @param ij ij coordinates to index.
+/// @param mode Mode, must be 0
+/// @param out Index will be placed here on success.
+/// @return 0 on success, E_FAILED if the round-trip doesn't match, or another
+/// value on failure.
+#[no_mangle]
+pub extern "C" fn localIjToCellStrict(
+    origin: H3Index,
+    ij: Option<&CoordIJ>,
+    mode: u32,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(
+        origin: H3Index,
+        ij: Option<&CoordIJ>,
+        mode: u32,
+    ) -> Result<H3Index, H3Error> {
+        let ij = ij.ok_or(H3ErrorCodes::EFailed)?;
+        if mode != 0 {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        let origin = CellIndex::try_from(origin)?;
+        let localij = h3o::LocalIJ::new_unchecked(origin, ij.i, ij.j);
+        let index = CellIndex::try_from(localij)?;
+
+        let roundtrip = index
+            .to_local_ij(origin)
+            .is_ok_and(|localij| localij.i() == ij.i && localij.j() == ij.j);
+        if !roundtrip {
+            return Err(H3ErrorCodes::EFailed.into());
+        }
+
+        Ok(index.into())
+    }
+
+    delegate_inner!(inner(origin, ij, mode), out)
+}
+
+/// Produces the cells of a rectangle of ij coordinates anchored by an origin.
+///
+/// Coordinates are in the same space as localIjToCell, bounds included.
+/// Coordinates that cannot be converted to a cell (e.g. on the other side of a
+/// pentagon) are skipped: valid cells are written contiguously at the start of
+/// `out`, in row-major order (`i`, then `j`).
+///
+/// @param origin An anchoring index for the ij coordinate system.
+/// @param iMin   Lower bound of the i component.
+/// @param iMax   Upper bound of the i component, must be >= `iMin`.
+/// @param jMin   Lower bound of the j component.
+/// @param jMax   Upper bound of the j component, must be >= `jMin`.
+/// @param out    Output array for the cells.
+/// @param count  Set to the number of cells written in `out`.
+/// @return 0 on success, E_DOMAIN if the rectangle is invalid.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least
+/// `(iMax - iMin + 1) * (jMax - jMin + 1)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn localIjRectToCells(
+    origin: H3Index,
+    iMin: c_int,
+    iMax: c_int,
+    jMin: c_int,
+    jMax: c_int,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        origin: H3Index,
+        (iMin, iMax): (c_int, c_int),
+        (jMin, jMax): (c_int, c_int),
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        if iMin > iMax || jMin > jMax {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        let origin = CellIndex::try_from(origin)?;
+        let width = i64::from(iMax) - i64::from(iMin) + 1;
+        let height = i64::from(jMax) - i64::from(jMin) + 1;
+        let len = width
+            .checked_mul(height)
+            .and_then(|len| usize::try_from(len).ok())
+            .ok_or(H3ErrorCodes::EDomain)?;
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        let mut count = 0;
+        for i in iMin..=iMax {
+            for j in jMin..=jMax {
+                let localij = h3o::LocalIJ::new_unchecked(origin, i, j);
+                if let Ok(cell) = CellIndex::try_from(localij) {
+                    out[count] = cell.into();
+                    count += 1;
+                }
+            }
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(origin, (iMin, iMax), (jMin, jMax), out), count)
+}
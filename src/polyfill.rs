@@ -0,0 +1,363 @@
+//! Polygon to cells, with a deterministic rule for the boundary.
+//!
+//! This follows the h3o algorithm: the outlines of the rings are traced with
+//! cells, whose neighbors are tested to be contained by the polygon, then the
+//! fill propagates inwards from them.
+//!
+//! Unlike h3o, the containment test includes the cells whose center lies on
+//! the boundary of the polygon (holes included), instead of leaving them to the
+//! rounding of the ray casting.
+
+use ahash::{HashSet, HashSetExt};
+use geo_types::{Coord, Line, LineString, Polygon};
+use h3o::{CellIndex, LatLng, Resolution};
+use std::f64::consts::{PI, TAU};
+
+/// Distance, in radians, under which a point is on the boundary.
+///
+/// About 6µm on Earth: way below the size of the finest cells, and way above
+/// the rounding errors on cell centers and polygon vertices.
+const BOUNDARY_TOLERANCE: f64 = 1e-12;
+
+/// Returns the cells whose center is inside the polygon (in radians), or on
+/// its boundary.
+///
+/// The polygon must have been validated beforehand (e.g. by h3o).
+pub fn polygon_cells(
+    polygon: &Polygon<f64>,
+    resolution: Resolution,
+) -> impl Iterator<Item = CellIndex> + '_ {
+    let shape = Shape::new(polygon);
+    // Set used for dedup.
+    let mut seen = HashSet::new();
+    // Cell itself + at most 6 neighbors = 7.
+    let mut scratchpad = Vec::with_capacity(7);
+
+    // First, compute the outline: the traced cells are a rough approximation,
+    // so their immediate neighbors are tested too.
+    let traced = shape
+        .rings()
+        .flat_map(|ring| ring.traced_cells(resolution))
+        .filter(|cell| seen.insert(*cell))
+        .collect::<Vec<_>>();
+    seen.clear();
+    let outlines =
+        shape.contained_neighbors(&traced, &mut seen, &mut scratchpad);
+
+    // Next, compute the outermost layer of inner cells to seed the propagation
+    // step: they are the last ones requiring a containment test.
+    let mut candidates =
+        shape.contained_neighbors(&outlines, &mut seen, &mut scratchpad);
+    let mut next_gen = Vec::with_capacity(candidates.len() * 7);
+    let mut new_seen = HashSet::with_capacity(seen.len());
+
+    // Last step: inward propagation from the outermost layers, bounded by the
+    // outlines.
+    let inward_propagation = std::iter::from_fn(move || {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        for &cell in &candidates {
+            neighbors(cell, &mut scratchpad);
+            next_gen.extend(scratchpad.iter().filter(|&&neighbor| {
+                new_seen.insert(neighbor);
+                seen.insert(neighbor)
+            }));
+        }
+        let curr_gen = candidates.clone();
+
+        std::mem::swap(&mut next_gen, &mut candidates);
+        next_gen.clear();
+
+        std::mem::swap(&mut new_seen, &mut seen);
+        new_seen.clear();
+
+        Some(curr_gen.into_iter())
+    });
+
+    outlines.into_iter().chain(inward_propagation.flatten())
+}
+
+// ----------------------------------------------------------------------------
+
+/// Position of a point relative to a ring.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+/// A polygon (in radians), ready for containment tests.
+struct Shape<'a> {
+    exterior: Ring<'a>,
+    interiors: Vec<Ring<'a>>,
+}
+
+impl<'a> Shape<'a> {
+    fn new(polygon: &'a Polygon<f64>) -> Self {
+        Self {
+            exterior: Ring::new(polygon.exterior()),
+            interiors: polygon.interiors().iter().map(Ring::new).collect(),
+        }
+    }
+
+    fn rings(&self) -> impl Iterator<Item = &Ring<'a>> {
+        self.interiors.iter().chain(std::iter::once(&self.exterior))
+    }
+
+    /// Checks if the center of the cell is inside the polygon, or on its
+    /// boundary.
+    fn contains(&self, cell: CellIndex) -> bool {
+        let ll = LatLng::from(cell);
+        let coord = Coord {
+            x: ll.lng_radians(),
+            y: ll.lat_radians(),
+        };
+
+        match self.exterior.locate(coord) {
+            Location::Outside => false,
+            Location::Boundary => true,
+            Location::Inside => self
+                .interiors
+                .iter()
+                .all(|ring| ring.locate(coord) != Location::Inside),
+        }
+    }
+
+    /// Returns the neighbors of the cells, not seen yet, that are contained by
+    /// the polygon.
+    fn contained_neighbors(
+        &self,
+        cells: &[CellIndex],
+        seen: &mut HashSet<CellIndex>,
+        scratchpad: &mut Vec<CellIndex>,
+    ) -> Vec<CellIndex> {
+        cells.iter().fold(Vec::new(), |mut acc, &cell| {
+            neighbors(cell, scratchpad);
+            acc.extend(scratchpad.iter().filter(|&&neighbor| {
+                seen.insert(neighbor) && self.contains(neighbor)
+            }));
+            acc
+        })
+    }
+}
+
+/// A closed ring (in radians) and its bounding box.
+struct Ring<'a> {
+    coords: &'a LineString<f64>,
+    min: Coord,
+    max: Coord,
+}
+
+impl<'a> Ring<'a> {
+    fn new(coords: &'a LineString<f64>) -> Self {
+        // Longitudes crossing the antimeridian are shifted by a whole turn.
+        let is_transmeridian = is_transmeridian(coords);
+        let (min, max) = coords.coords().fold(
+            (
+                Coord {
+                    x: f64::MAX,
+                    y: f64::MAX,
+                },
+                Coord {
+                    x: f64::MIN,
+                    y: f64::MIN,
+                },
+            ),
+            |(min, max), coord| {
+                let x = shift_lng(coord.x, is_transmeridian);
+                (
+                    Coord {
+                        x: min.x.min(x),
+                        y: min.y.min(coord.y),
+                    },
+                    Coord {
+                        x: max.x.max(x),
+                        y: max.y.max(coord.y),
+                    },
+                )
+            },
+        );
+
+        Self { coords, min, max }
+    }
+
+    /// Locates a point relative to the ring, using ray casting (as h3o does)
+    /// and a distance test for the boundary.
+    #[allow(clippy::float_cmp, reason = "exact matches are nudged on purpose")]
+    fn locate(&self, point: Coord) -> Location {
+        let is_transmeridian = self.max.x > PI;
+        let point = Coord {
+            x: shift_lng(point.x, is_transmeridian),
+            y: point.y,
+        };
+
+        if point.x < self.min.x - BOUNDARY_TOLERANCE
+            || point.x > self.max.x + BOUNDARY_TOLERANCE
+            || point.y < self.min.y - BOUNDARY_TOLERANCE
+            || point.y > self.max.y + BOUNDARY_TOLERANCE
+        {
+            return Location::Outside;
+        }
+
+        let mut coord = point;
+        let mut inside = false;
+        for Line { mut start, mut end } in self.coords.lines() {
+            start.x = shift_lng(start.x, is_transmeridian);
+            end.x = shift_lng(end.x, is_transmeridian);
+            if is_on_segment(point, start, end) {
+                return Location::Boundary;
+            }
+
+            // Ray casting requires the second point to always be higher than
+            // the first, so swap if needed.
+            if start.y > end.y {
+                (start, end) = (end, start);
+            }
+            // Avoid the ray passing through a vertex twice, on successive
+            // edges, by adjusting the latitude northward.
+            if coord.y == start.y || coord.y == end.y {
+                coord.y += f64::EPSILON;
+            }
+            if coord.y < start.y || coord.y > end.y {
+                continue;
+            }
+            // Bias westerly when the longitude matches a vertex.
+            if start.x == coord.x || end.x == coord.x {
+                coord.x -= f64::EPSILON;
+            }
+
+            let ratio = (coord.y - start.y) / (end.y - start.y);
+            if (end.x - start.x).mul_add(ratio, start.x) > coord.x {
+                inside = !inside;
+            }
+        }
+
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Returns the cells tracing the ring (rough approximation).
+    fn traced_cells(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = CellIndex> + 'a {
+        let is_transmeridian = is_transmeridian(self.coords);
+
+        self.coords.lines().flat_map(move |line| {
+            let count = line_hex_estimate(line, resolution);
+            let start = Coord {
+                x: shift_lng(line.start.x, is_transmeridian),
+                y: line.start.y,
+            };
+            let end = Coord {
+                x: shift_lng(line.end.x, is_transmeridian),
+                y: line.end.y,
+            };
+
+            (0..count).filter_map(move |i| {
+                #[allow(
+                    clippy::cast_precision_loss,
+                    reason = "cell counts are way below 2^52"
+                )]
+                let (i, count) = (i as f64, count as f64);
+                let lat = (start.y * (count - i) / count) + (end.y * i / count);
+                let lng = (start.x * (count - i) / count) + (end.x * i / count);
+
+                LatLng::from_radians(lat, lng)
+                    .ok()
+                    .map(|ll| ll.to_cell(resolution))
+            })
+        })
+    }
+}
+
+/// Checks if a ring crosses the antimeridian (an edge spans over 180°).
+fn is_transmeridian(ring: &LineString<f64>) -> bool {
+    ring.lines()
+        .any(|line| (line.start.x - line.end.x).abs() > PI)
+}
+
+/// Shifts western longitudes by a whole turn, for transmeridian rings.
+fn shift_lng(lng: f64, is_transmeridian: bool) -> f64 {
+    if is_transmeridian && lng < 0. {
+        lng + TAU
+    } else {
+        lng
+    }
+}
+
+/// Checks if a point is within `BOUNDARY_TOLERANCE` of a segment.
+fn is_on_segment(point: Coord, start: Coord, end: Coord) -> bool {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let (px, py) = (point.x - start.x, point.y - start.y);
+    let length2 = dx.mul_add(dx, dy * dy);
+    // Position of the closest point, along the segment.
+    let t = if length2 > 0. {
+        (px.mul_add(dx, py * dy) / length2).clamp(0., 1.)
+    } else {
+        0.
+    };
+
+    let (x, y) = (dx.mul_add(t, -px), dy.mul_add(t, -py));
+    x.mul_add(x, y * y) <= BOUNDARY_TOLERANCE * BOUNDARY_TOLERANCE
+}
+
+/// Fills the scratchpad with the cell and its immediate neighbors.
+fn neighbors(cell: CellIndex, scratchpad: &mut Vec<CellIndex>) {
+    scratchpad.clear();
+    for candidate in cell.grid_disk_fast(1) {
+        // Fast version failed (pentagon), fallback on the safe version.
+        let Some(neighbor) = candidate else {
+            scratchpad.clear();
+            scratchpad.extend(cell.grid_disk_safe(1));
+            return;
+        };
+        scratchpad.push(neighbor);
+    }
+}
+
+/// Returns an estimated number of cells tracing the planar line.
+fn line_hex_estimate(line: Line<f64>, resolution: Resolution) -> u64 {
+    // Diameter of the pentagon, the maximally-distorted cell.
+    const PENT_DIAMETER_RADS: [f64; 16] = [
+        0.32549355508382627,
+        0.11062000431697926,
+        0.0431531246375496,
+        0.015280278825461551,
+        0.006095981694441515,
+        0.00217237586248339,
+        0.0008694532999397082,
+        0.0003101251537809772,
+        0.00012417902430910614,
+        0.00004429922220615181,
+        0.00001773927716796858,
+        0.000006328371112691009,
+        0.0000025341705472716865,
+        0.0000009040511973807097,
+        0.00000036202412300873475,
+        0.00000012915013523209886,
+    ];
+    let diameter = PENT_DIAMETER_RADS[usize::from(resolution)];
+
+    let distance = LatLng::from_radians(line.start.y, line.start.x)
+        .and_then(|start| {
+            let end = LatLng::from_radians(line.end.y, line.end.x)?;
+            Ok(start.distance_rads(end))
+        })
+        .unwrap_or_default();
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "distances are finite and positive"
+    )]
+    let estimate = (distance / diameter).ceil() as u64;
+
+    estimate.max(1)
+}
@@ -73,6 +73,31 @@ pub extern "C" fn getNumCells(res: c_int, out: Option<&mut i64>) -> H3Error {
     delegate_inner!(inner(res), out)
 }
 
+/// Cumulative number of cells (hexagons and pentagons), from resolution 0 up to
+/// a given resolution (included).
+///
+/// Useful to size multi-resolution stores.
+///
+/// @param   res  H3 cell resolution
+/// @return  number of cells at resolutions 0 through `res`
+#[no_mangle]
+pub extern "C" fn getCumulativeNumCells(
+    res: c_int,
+    out: Option<&mut i64>,
+) -> H3Error {
+    fn inner(res: c_int) -> Result<i64, H3Error> {
+        let resolution = convert::h3res_to_resolution(res)?;
+        let count = Resolution::range(Resolution::Zero, resolution)
+            .try_fold(0_u64, |total, resolution| {
+                total.checked_add(resolution.cell_count())
+            })
+            .ok_or(H3ErrorCodes::EFailed)?;
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(res), out)
+}
+
 /// Generates all pentagons at the specified resolution
 ///
 /// @param res The resolution to produce pentagons at.
@@ -105,6 +130,29 @@ pub unsafe extern "C" fn getPentagons(
     }
 }
 
+/// Generates the numbers (0-121) of the 12 pentagon base cells.
+///
+/// Unlike getPentagons, this is resolution-independent: the pentagons at every
+/// resolution are the center children of these base cells.
+///
+/// @param out Output array.
+///
+/// # Safety
+///
+/// `out` must points to an array of at least `pentagonCount` elements.
+#[no_mangle]
+pub unsafe extern "C" fn getPentagonBaseCells(out: *mut c_int) -> H3Error {
+    if out.is_null() {
+        return H3ErrorCodes::EFailed.into();
+    }
+    let len = Resolution::pentagon_count().into();
+    let slice = std::slice::from_raw_parts_mut(out, len);
+    for (i, pentagon) in Resolution::Zero.pentagons().enumerate() {
+        slice[i] = u8::from(pentagon.base_cell()).into();
+    }
+    H3ErrorCodes::ESuccess.into()
+}
+
 /// getRes0Cells generates all base cells storing them into the provided
 /// memory pointer.
 ///
@@ -154,3 +202,60 @@ pub extern "C" fn pentagonCount() -> c_int {
 pub extern "C" fn res0CellCount() -> c_int {
     BaseCell::count().into()
 }
+
+/// Resolution whose average hexagon area is the closest to the given area (in
+/// square meters).
+///
+/// Areas are compared on a logarithmic scale (i.e. by ratio), and values
+/// beyond the coarsest or finest resolution are clamped to 0 or 15.
+///
+/// @param m2  Target area, in square meters
+/// @param out Closest resolution
+/// @return    E_DOMAIN if the area isn't strictly positive.
+#[no_mangle]
+pub extern "C" fn resolutionForAreaM2(
+    m2: f64,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    delegate_inner!(closest_resolution(m2, Resolution::area_m2), out)
+}
+
+/// Resolution whose average hexagon edge length is the closest to the given
+/// length (in meters).
+///
+/// Lengths are compared on a logarithmic scale (i.e. by ratio), and values
+/// beyond the coarsest or finest resolution are clamped to 0 or 15.
+///
+/// @param meters Target edge length, in meters
+/// @param out    Closest resolution
+/// @return       E_DOMAIN if the length isn't strictly positive.
+#[no_mangle]
+pub extern "C" fn resolutionForEdgeLengthM(
+    meters: f64,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    delegate_inner!(closest_resolution(meters, Resolution::edge_length_m), out)
+}
+
+/// Finds the resolution whose metric is the closest to the target, by ratio.
+fn closest_resolution(
+    target: f64,
+    metric: fn(Resolution) -> f64,
+) -> Result<c_int, H3Error> {
+    // Also rejects NaN.
+    if !(target > 0. && target.is_finite()) {
+        return Err(H3ErrorCodes::EDomain.into());
+    }
+
+    let target = target.ln();
+    let resolution = (0..=15)
+        .filter_map(|res| Resolution::try_from(res).ok())
+        .min_by(|&a, &b| {
+            let dist_a = (metric(a).ln() - target).abs();
+            let dist_b = (metric(b).ln() - target).abs();
+            dist_a.total_cmp(&dist_b)
+        })
+        .ok_or(H3ErrorCodes::EFailed)?;
+
+    Ok(u8::from(resolution).into())
+}
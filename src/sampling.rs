@@ -0,0 +1,70 @@
+use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index};
+
+/// Selects a deterministic pseudo-random subset of a set of cells.
+///
+/// Each cell is independently kept with probability `fraction`, based on a
+/// seeded hash of its index: the same cells, seed and fraction always yield
+/// the same subset, across runs and platforms. Selected cells are written in
+/// input order. Indexes are sampled as is, without validation.
+///
+/// @param cells    Set of cells
+/// @param n        Number of cells in `cells`
+/// @param fraction Probability of selecting each cell, in [0, 1]
+/// @param seed     Seed of the selection hash
+/// @param out      Output array for the selected cells
+/// @param count    Set to the number of cells written in `out`
+/// @return         E_DOMAIN if `n` is negative or `fraction` outside [0, 1].
+///
+/// # Safety
+///
+/// `cells` and `out` must points to an array of at least `n` elements.
+#[no_mangle]
+pub unsafe extern "C" fn sampleCells(
+    cells: *const H3Index,
+    n: i64,
+    fraction: f64,
+    seed: u64,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        fraction: f64,
+        seed: u64,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let len = usize::try_from(n).map_err(|_| H3ErrorCodes::EDomain)?;
+        if !(0. ..=1.).contains(&fraction) {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let cells = std::slice::from_raw_parts(cells, len);
+        let out = std::slice::from_raw_parts_mut(out, len);
+        // Compare the upper half of the hash against the fraction, scaled to
+        // 2^32.
+        let threshold = fraction * 4_294_967_296.;
+        let mut count = 0;
+        for &cell in cells {
+            let draw = u32::try_from(mix(cell ^ seed) >> 32).expect("32-bit");
+            if f64::from(draw) < threshold {
+                out[count] = cell;
+                count += 1;
+            }
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(cells, n, fraction, seed, out), count)
+}
+
+/// SplitMix64 finalizer, a fast and well-distributed 64-bit mixing function.
+const fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
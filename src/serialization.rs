@@ -0,0 +1,160 @@
+use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index};
+use h3o::{CellIndex, Resolution};
+use std::ffi::c_int;
+
+/// Encodes a set of cells as a bitset of the children of a common parent.
+///
+/// Bit `childPos` (the position of the cell among the children of `parent` at
+/// `childRes`, see cellToChildPos) is set for each cell, with positions stored
+/// least significant bit first: bit `k` is `bitset[k / 8] & (1 << (k % 8))`.
+/// The bitset is cleared first, and duplicate cells are ignored.
+///
+/// @param parent    The common parent
+/// @param childRes  The resolution of the cells
+/// @param cells     Set of cells
+/// @param n         Number of cells in `cells`
+/// @param bitset    Output bitset
+/// @param bitsetCap Size of `bitset`, in bytes
+/// @return          E_DOMAIN if `n` is negative, E_RES_MISMATCH if a cell
+///                  isn't a child of `parent` at `childRes`, E_MEMORY_BOUNDS
+///                  if `bitset` is smaller than `ceil(children / 8)` bytes.
+///
+/// # Safety
+///
+/// `cells` must points to an array of at least `n` elements, and `bitset` to
+/// an array of at least `bitsetCap` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn cellsToChildBitset(
+    parent: H3Index,
+    childRes: c_int,
+    cells: *const H3Index,
+    n: i64,
+    bitset: *mut u8,
+    bitsetCap: usize,
+) -> H3Error {
+    unsafe fn inner(
+        parent: H3Index,
+        childRes: c_int,
+        cells: *const H3Index,
+        n: i64,
+        bitset: *mut u8,
+        bitsetCap: usize,
+    ) -> Result<(), H3Error> {
+        let (parent, child_res, len) = bitset_layout(parent, childRes)?;
+        if n < 0 {
+            return Err(H3ErrorCodes::EDomain.into());
+        }
+        if bitsetCap < len {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+        let cells = if n == 0 {
+            &[]
+        } else {
+            convert::h3ptr_to_h3oslice(cells, n)?
+        };
+
+        let bitset = std::slice::from_raw_parts_mut(bitset, len);
+        bitset.fill(0);
+        for cell in cells {
+            if cell.resolution() != child_res
+                || cell.parent(parent.resolution()) != Some(parent)
+            {
+                return Err(H3ErrorCodes::EResMismatch.into());
+            }
+            let position = cell
+                .child_position(parent.resolution())
+                .and_then(|position| usize::try_from(position).ok())
+                .ok_or(H3ErrorCodes::EFailed)?;
+            bitset[position / 8] |= 1 << (position % 8);
+        }
+        Ok(())
+    }
+
+    inner(parent, childRes, cells, n, bitset, bitsetCap)
+        .err()
+        .unwrap_or_else(|| H3ErrorCodes::ESuccess.into())
+}
+
+/// Decodes a bitset built by cellsToChildBitset into the cells it contains.
+///
+/// Cells are written contiguously at the start of `out`, in child position
+/// order.
+///
+/// @param parent    The common parent
+/// @param childRes  The resolution of the cells
+/// @param bitset    Input bitset
+/// @param bitsetCap Size of `bitset`, in bytes
+/// @param out       Output array for the cells
+/// @param cap       Capacity of `out`
+/// @param count     Set to the number of cells written in `out`
+/// @return          E_RES_MISMATCH if `childRes` is coarser than `parent`,
+///                  E_DOMAIN if a bit past the last child is set,
+///                  E_MEMORY_BOUNDS if `bitset` is smaller than
+///                  `ceil(children / 8)` bytes or `out` is too small.
+///
+/// # Safety
+///
+/// `bitset` must points to an array of at least `bitsetCap` bytes, and `out`
+/// to an array of at least `cap` elements.
+#[no_mangle]
+pub unsafe extern "C" fn childBitsetToCells(
+    parent: H3Index,
+    childRes: c_int,
+    bitset: *const u8,
+    bitsetCap: usize,
+    out: *mut H3Index,
+    cap: i64,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        parent: H3Index,
+        childRes: c_int,
+        bitset: *const u8,
+        bitsetCap: usize,
+        out: *mut H3Index,
+        cap: i64,
+    ) -> Result<i64, H3Error> {
+        let (parent, child_res, len) = bitset_layout(parent, childRes)?;
+        let cap = usize::try_from(cap).map_err(|_| H3ErrorCodes::EDomain)?;
+        if bitsetCap < len {
+            return Err(H3ErrorCodes::EMemoryBounds.into());
+        }
+
+        let bitset = std::slice::from_raw_parts(bitset, len);
+        let out = std::slice::from_raw_parts_mut(out, cap);
+        let mut count = 0;
+        for (i, &byte) in bitset.iter().enumerate() {
+            for bit in (0..8).filter(|bit| byte & (1 << bit) != 0) {
+                let child = u64::try_from(i * 8 + bit)
+                    .ok()
+                    .and_then(|position| parent.child_at(position, child_res))
+                    .ok_or(H3ErrorCodes::EDomain)?;
+                *out.get_mut(count).ok_or(H3ErrorCodes::EMemoryBounds)? =
+                    child.into();
+                count += 1;
+            }
+        }
+        Ok(convert::checked_int(count)?)
+    }
+
+    delegate_inner!(inner(parent, childRes, bitset, bitsetCap, out, cap), count)
+}
+
+// -----------------------------------------------------------------------------
+
+/// Validates the parent and child resolution of a bitset, and returns them
+/// with the size of the bitset, in bytes.
+fn bitset_layout(
+    parent: H3Index,
+    childRes: c_int,
+) -> Result<(CellIndex, Resolution, usize), H3Error> {
+    let parent = CellIndex::try_from(parent)?;
+    let child_res = convert::h3res_to_resolution(childRes)?;
+    if child_res < parent.resolution() {
+        return Err(H3ErrorCodes::EResMismatch.into());
+    }
+    let len = usize::try_from(parent.children_count(child_res).div_ceil(8))
+        .map_err(|_| H3ErrorCodes::EMemoryBounds)?;
+
+    Ok((parent, child_res, len))
+}
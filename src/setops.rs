@@ -0,0 +1,327 @@
+use crate::{convert, delegate_inner, H3Error, H3ErrorCodes, H3Index};
+use h3o::CellIndex;
+use std::{collections::HashSet, ffi::c_int};
+
+/// Computes the cells of `a` that are not in `b`.
+///
+/// Both sets must be at the same resolution. The result is deduplicated and
+/// written in order of first appearance in `a`.
+///
+/// @param a     First set of cells
+/// @param aLen  Number of cells in `a`
+/// @param b     Second set of cells
+/// @param bLen  Number of cells in `b`
+/// @param out   Output array for the difference
+/// @param count Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// - `a` must points to an array of at least `aLen` elements.
+/// - `b` must points to an array of at least `bLen` elements.
+/// - `out` must points to an array of at least `aLen` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetDifference(
+    a: *const H3Index,
+    aLen: i64,
+    b: *const H3Index,
+    bLen: i64,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        a: *const H3Index,
+        aLen: i64,
+        b: *const H3Index,
+        bLen: i64,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let (a, b) = cell_sets(a, aLen, b, bLen)?;
+        let b = b.iter().copied().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let cells = a
+            .iter()
+            .copied()
+            .filter(|cell| !b.contains(cell) && seen.insert(*cell));
+
+        write_cells(out, a.len(), cells)
+    }
+
+    delegate_inner!(inner(a, aLen, b, bLen, out), count)
+}
+
+/// Computes the cells present in both `a` and `b`.
+///
+/// Both sets must be at the same resolution. The result is deduplicated and
+/// written in order of first appearance in `a`.
+///
+/// @param a     First set of cells
+/// @param aLen  Number of cells in `a`
+/// @param b     Second set of cells
+/// @param bLen  Number of cells in `b`
+/// @param out   Output array for the intersection
+/// @param count Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// - `a` must points to an array of at least `aLen` elements.
+/// - `b` must points to an array of at least `bLen` elements.
+/// - `out` must points to an array of at least `min(aLen, bLen)` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetIntersection(
+    a: *const H3Index,
+    aLen: i64,
+    b: *const H3Index,
+    bLen: i64,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        a: *const H3Index,
+        aLen: i64,
+        b: *const H3Index,
+        bLen: i64,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let (a, b) = cell_sets(a, aLen, b, bLen)?;
+        let len = a.len().min(b.len());
+        let b = b.iter().copied().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let cells = a
+            .iter()
+            .copied()
+            .filter(|cell| b.contains(cell) && seen.insert(*cell));
+
+        write_cells(out, len, cells)
+    }
+
+    delegate_inner!(inner(a, aLen, b, bLen, out), count)
+}
+
+/// Computes the cells present in `a` or `b`.
+///
+/// Both sets must be at the same resolution. The result is deduplicated and
+/// written in order of first appearance in `a`, then `b`.
+///
+/// @param a     First set of cells
+/// @param aLen  Number of cells in `a`
+/// @param b     Second set of cells
+/// @param bLen  Number of cells in `b`
+/// @param out   Output array for the union
+/// @param count Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// - `a` must points to an array of at least `aLen` elements.
+/// - `b` must points to an array of at least `bLen` elements.
+/// - `out` must points to an array of at least `aLen + bLen` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetUnion(
+    a: *const H3Index,
+    aLen: i64,
+    b: *const H3Index,
+    bLen: i64,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        a: *const H3Index,
+        aLen: i64,
+        b: *const H3Index,
+        bLen: i64,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let (a, b) = cell_sets(a, aLen, b, bLen)?;
+        let mut seen = HashSet::new();
+        let cells = a
+            .iter()
+            .chain(b.iter())
+            .copied()
+            .filter(|cell| seen.insert(*cell));
+
+        write_cells(out, a.len() + b.len(), cells)
+    }
+
+    delegate_inner!(inner(a, aLen, b, bLen, out), count)
+}
+
+/// Checks whether `a` and `b` contain the same cells.
+///
+/// Inputs are treated as sets: order and duplicates are ignored. Both sets
+/// must be at the same resolution.
+///
+/// @param a     First set of cells
+/// @param aLen  Number of cells in `a`
+/// @param b     Second set of cells
+/// @param bLen  Number of cells in `b`
+/// @param out   Set to 1 if the sets are equal, 0 otherwise
+///
+/// # Safety
+///
+/// - `a` must points to an array of at least `aLen` elements.
+/// - `b` must points to an array of at least `bLen` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetsEqual(
+    a: *const H3Index,
+    aLen: i64,
+    b: *const H3Index,
+    bLen: i64,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        a: *const H3Index,
+        aLen: i64,
+        b: *const H3Index,
+        bLen: i64,
+    ) -> Result<c_int, H3Error> {
+        let (a, b) = cell_sets(a, aLen, b, bLen)?;
+        let a = a.iter().copied().collect::<HashSet<_>>();
+        let b = b.iter().copied().collect::<HashSet<_>>();
+
+        Ok((a == b).into())
+    }
+
+    delegate_inner!(inner(a, aLen, b, bLen), out)
+}
+
+/// Checks whether every cell of `sub` is in `sup`.
+///
+/// Inputs are treated as sets: order and duplicates are ignored. Both sets
+/// must be at the same resolution.
+///
+/// @param sup     Set of cells that may contain `sub`
+/// @param supLen  Number of cells in `sup`
+/// @param sub     Set of cells that may be contained by `sup`
+/// @param subLen  Number of cells in `sub`
+/// @param out     Set to 1 if `sub` is a subset of `sup`, 0 otherwise
+///
+/// # Safety
+///
+/// - `sup` must points to an array of at least `supLen` elements.
+/// - `sub` must points to an array of at least `subLen` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetContains(
+    sup: *const H3Index,
+    supLen: i64,
+    sub: *const H3Index,
+    subLen: i64,
+    out: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        sup: *const H3Index,
+        supLen: i64,
+        sub: *const H3Index,
+        subLen: i64,
+    ) -> Result<c_int, H3Error> {
+        let (sup, sub) = cell_sets(sup, supLen, sub, subLen)?;
+        let sup = sup.iter().copied().collect::<HashSet<_>>();
+
+        Ok(sub.iter().all(|cell| sup.contains(cell)).into())
+    }
+
+    delegate_inner!(inner(sup, supLen, sub, subLen), out)
+}
+
+/// Finds the cells on the boundary of a set: the cells with at least one
+/// neighbor outside of the set.
+///
+/// The set must be at a single resolution. The result is deduplicated and
+/// written in order of first appearance.
+///
+/// @param cells Set of cells
+/// @param n     Number of cells in `cells`
+/// @param out   Output array for the boundary cells
+/// @param count Set to the number of cells written in `out`
+///
+/// # Safety
+///
+/// - `cells` must points to an array of at least `n` elements.
+/// - `out` must points to an array of at least `n` elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellSetBoundaryCells(
+    cells: *const H3Index,
+    n: i64,
+    out: *mut H3Index,
+    count: Option<&mut i64>,
+) -> H3Error {
+    unsafe fn inner(
+        cells: *const H3Index,
+        n: i64,
+        out: *mut H3Index,
+    ) -> Result<i64, H3Error> {
+        let cells = cell_set(cells, n)?;
+        check_resolution(cells.iter())?;
+        let set = cells.iter().copied().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let boundary = cells.iter().copied().filter(|cell| {
+            seen.insert(*cell)
+                && cell
+                    .grid_disk::<Vec<_>>(1)
+                    .iter()
+                    .any(|neighbor| !set.contains(neighbor))
+        });
+
+        write_cells(out, cells.len(), boundary)
+    }
+
+    delegate_inner!(inner(cells, n, out), count)
+}
+
+// -----------------------------------------------------------------------------
+
+/// Validates a pair of cell sets, that must share a single resolution.
+unsafe fn cell_sets<'a>(
+    a: *const H3Index,
+    aLen: i64,
+    b: *const H3Index,
+    bLen: i64,
+) -> Result<(&'a [CellIndex], &'a [CellIndex]), H3Error> {
+    let a = cell_set(a, aLen)?;
+    let b = cell_set(b, bLen)?;
+    check_resolution(a.iter().chain(b.iter()))?;
+
+    Ok((a, b))
+}
+
+/// Checks that the cells share a single resolution.
+fn check_resolution<'a>(
+    mut cells: impl Iterator<Item = &'a CellIndex>,
+) -> Result<(), H3Error> {
+    if let Some(first) = cells.next() {
+        let resolution = first.resolution();
+        if cells.any(|cell| cell.resolution() != resolution) {
+            return Err(H3ErrorCodes::EResMismatch.into());
+        }
+    }
+    Ok(())
+}
+
+/// Validates a set of cells, which may be empty (and null).
+unsafe fn cell_set<'a>(
+    ptr: *const H3Index,
+    len: i64,
+) -> Result<&'a [CellIndex], H3Error> {
+    match len {
+        ..=-1 => Err(H3ErrorCodes::EDomain.into()),
+        0 => Ok(&[]),
+        _ => convert::h3ptr_to_h3oslice(ptr, len),
+    }
+}
+
+/// Writes the cells contiguously at the start of `out`.
+unsafe fn write_cells(
+    out: *mut H3Index,
+    len: usize,
+    cells: impl Iterator<Item = CellIndex>,
+) -> Result<i64, H3Error> {
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let out = std::slice::from_raw_parts_mut(out, len);
+    let mut count = 0;
+    for cell in cells {
+        out[count] = cell.into();
+        count += 1;
+    }
+    Ok(convert::checked_int(count)?)
+}
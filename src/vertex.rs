@@ -1,7 +1,17 @@
-use crate::{delegate_inner, H3Error, H3ErrorCodes, H3Index, LatLng};
+use crate::{bits, delegate_inner, H3Error, H3ErrorCodes, H3Index, LatLng};
 use h3o::{CellIndex, VertexIndex};
 use std::ffi::c_int;
 
+/// An H3 index paired with a coordinate.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct H3IndexLatLng {
+    /// The H3 index.
+    pub vertex: H3Index,
+    /// Coordinate of the index, in degrees.
+    pub coord: LatLng,
+}
+
 /// Get a single vertex for a given cell, as an H3 index, or
 /// H3_NULL if the vertex is invalid
 ///
@@ -25,6 +35,52 @@ pub extern "C" fn cellToVertex(
     delegate_inner!(inner(origin, vertexNum), out)
 }
 
+/// Get the vertex at the start of the cell edge facing the given direction, as
+/// an H3 index.
+///
+/// Unlike `vertexNum`, the direction doesn't depend on the cell orientation:
+/// it's the H3 neighbor direction minus one (0 = K axis, 1 = J axis, 2 = JK
+/// axis, 3 = I axis, 4 = IK axis, 5 = IJ axis).
+///
+/// @param cell      Cell to get the vertex for
+/// @param direction Direction (0-5) of the edge starting at the vertex
+#[no_mangle]
+pub extern "C" fn cellToVertexByDirection(
+    origin: H3Index,
+    direction: c_int,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(origin: H3Index, direction: c_int) -> Result<H3Index, H3Error> {
+        let index = CellIndex::try_from(origin)?;
+        let direction = u8::try_from(direction)
+            .ok()
+            .filter(|&direction| direction < 6)
+            .ok_or(H3ErrorCodes::EDomain)?;
+
+        // Edges are numbered from 1 (K axis) to 6 (IJ axis), and there is no
+        // edge along the deleted K axis of a pentagon.
+        let edge = index
+            .edges()
+            .find(|edge| u8::from(edge.edge()) == direction + 1)
+            .ok_or(H3ErrorCodes::EPentagon)?;
+        let start = edge.boundary()[0];
+
+        // Match the start of the edge against the cell vertexes.
+        let vertex = index
+            .vertexes()
+            .min_by(|&a, &b| {
+                let dist_a = start.distance_rads(a.into());
+                let dist_b = start.distance_rads(b.into());
+                dist_a.total_cmp(&dist_b)
+            })
+            .expect("at least 5 vertexes");
+
+        Ok(vertex.into())
+    }
+
+    delegate_inner!(inner(origin, direction), out)
+}
+
 /// Get all vertexes for the given cell
 ///
 /// @param cell      Cell to get the vertexes for
@@ -57,6 +113,48 @@ pub unsafe extern "C" fn cellToVertexes(
     }
 }
 
+/// Get all vertexes for the given cell, along with their coordinates (in
+/// degrees)
+///
+/// @param cell      Cell to get the vertexes for
+/// @param out       Array to hold vertex output.
+/// @param count     Set to the number of vertexes (5 for pentagons, 6
+///                  otherwise)
+///
+/// # Safety
+///
+/// `out` must points to an array of at least 6 elements.
+#[no_mangle]
+pub unsafe extern "C" fn cellToVertexesWithCoords(
+    cell: H3Index,
+    out: *mut H3IndexLatLng,
+    count: Option<&mut c_int>,
+) -> H3Error {
+    unsafe fn inner(
+        cell: H3Index,
+        out: *mut H3IndexLatLng,
+    ) -> Result<c_int, H3Error> {
+        let cell = CellIndex::try_from(cell)?;
+        let len = if cell.is_pentagon() { 5 } else { 6 };
+
+        let out = std::slice::from_raw_parts_mut(out, len);
+        for (i, vertex) in cell.vertexes().enumerate() {
+            let ll = h3o::LatLng::from(vertex);
+            out[i] = H3IndexLatLng {
+                vertex: vertex.into(),
+                coord: LatLng {
+                    lat: ll.lat(),
+                    lng: ll.lng(),
+                },
+            };
+        }
+
+        Ok(c_int::try_from(len).expect("5 or 6"))
+    }
+
+    delegate_inner!(inner(cell, out), count)
+}
+
 /// Whether the input is a valid H3 vertex
 ///
 /// @param  vertex H3 index possibly describing a vertex
@@ -81,3 +179,61 @@ pub extern "C" fn vertexToLatLng(
 
     delegate_inner!(inner(vertex), point)
 }
+
+/// Get the cell owning an H3 vertex
+///
+/// Every vertex is shared by up to 3 cells, but its index is always built from
+/// the same (canonical) owner.
+///
+/// @param vertex H3 index describing a vertex
+/// @param out    Output owner cell
+#[no_mangle]
+pub extern "C" fn vertexToOwnerCell(
+    vertex: H3Index,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(vertex: H3Index) -> Result<H3Index, H3Error> {
+        Ok(VertexIndex::try_from(vertex)?.owner().into())
+    }
+
+    delegate_inner!(inner(vertex), out)
+}
+
+/// Get the canonical index of an H3 vertex.
+///
+/// Every vertex is shared by up to 3 cells, and each of them can describe it
+/// with its own vertex number. This returns the index built from the owner
+/// cell, so that shared vertexes can be deduplicated across cells.
+///
+/// @param vertex H3 index describing a vertex, from any of the cells sharing it
+/// @param out    Output canonical vertex
+#[no_mangle]
+pub extern "C" fn canonicalVertex(
+    vertex: H3Index,
+    out: Option<&mut H3Index>,
+) -> H3Error {
+    fn inner(vertex: H3Index) -> Result<H3Index, H3Error> {
+        let mode = (vertex & bits::MODE_MASK) >> bits::MODE_OFFSET;
+        if mode != bits::VERTEX_MODE {
+            return Err(H3ErrorCodes::EVertexInvalid.into());
+        }
+        // The vertex number is stored in the mode-dependent bits.
+        let cell = (vertex & !bits::MODE_MASK & !bits::RESERVED_MASK)
+            | (bits::CELL_MODE << bits::MODE_OFFSET);
+        let cell = CellIndex::try_from(cell)
+            .map_err(|_| H3ErrorCodes::EVertexInvalid)?;
+        let vertexNum = u8::try_from(
+            (vertex & bits::RESERVED_MASK) >> bits::RESERVED_OFFSET,
+        )
+        .expect("3-bit vertex number")
+        .try_into()
+        .map_err(|_| H3ErrorCodes::EVertexInvalid)?;
+
+        Ok(cell
+            .vertex(vertexNum)
+            .ok_or(H3ErrorCodes::EVertexInvalid)?
+            .into())
+    }
+
+    delegate_inner!(inner(vertex), out)
+}